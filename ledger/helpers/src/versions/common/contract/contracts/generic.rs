@@ -0,0 +1,199 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "can-panic")]
+
+use std::{any::Any, borrow::Cow, sync::Arc};
+
+use super::super::{
+	AlignedValue, Contract, ContractAddress, ContractCallPrototype, ContractOperation, DB,
+	EntryPointBuf, GatherEvent, KeyLocation, LedgerContext, LedgerState, Op, PreTranscript,
+	QueryContext, Resolver, ResultModeGather, ResultModeVerify, Rng, Sp, StdRng, Transcripts,
+	partition_transcripts,
+};
+
+/// Values of a call to a contract entry point
+///
+/// The public transcript program is in gather mode: it is run against the contract state when the
+/// call is built, and the results of its reads are filled in from that state.
+#[derive(Clone)]
+pub struct CallDescription<D: DB> {
+	pub input: AlignedValue,
+	pub output: AlignedValue,
+	pub private_transcript_outputs: Vec<AlignedValue>,
+	pub program: Vec<Op<ResultModeGather, D>>,
+}
+
+/// Why the public transcript of a [`CallDescription`] can't run against a contract's state
+#[derive(Debug, thiserror::Error)]
+pub enum CallDescriptionError {
+	#[error("contract {0:?} does not exist")]
+	ContractNotPresent(ContractAddress),
+	#[error("public transcript rejected by the contract state: {0}")]
+	TranscriptRejected(String),
+	#[error("public transcript reads don't match the ops expecting a result")]
+	ReadsMismatch,
+}
+
+impl<D: DB + Clone + 'static> CallDescription<D> {
+	/// Runs the public transcript program against the state of the contract at `address`, and
+	/// returns the query context with the program in verify mode, its reads filled in
+	pub fn verify_program(
+		&self,
+		address: ContractAddress,
+		ledger_state: &LedgerState<D>,
+	) -> Result<(QueryContext<D>, Vec<Op<ResultModeVerify, D>>), CallDescriptionError> {
+		let contract_state = ledger_state
+			.index(address)
+			.ok_or(CallDescriptionError::ContractNotPresent(address))?;
+
+		let context = QueryContext::new(contract_state.data, address);
+		let mut reads = context
+			.query(&self.program, None, &ledger_state.parameters.cost_model.runtime_cost_model)
+			.map_err(|e| CallDescriptionError::TranscriptRejected(format!("{e:?}")))?
+			.events
+			.into_iter()
+			.filter_map(|event| match event {
+				GatherEvent::Read(value) => Some(value),
+				_ => None,
+			});
+
+		let mut missing_read = false;
+		let program = self
+			.program
+			.iter()
+			.map(|op| {
+				op.clone().translate(|()| {
+					reads.next().unwrap_or_else(|| {
+						missing_read = true;
+						().into()
+					})
+				})
+			})
+			.collect();
+		if missing_read || reads.next().is_some() {
+			return Err(CallDescriptionError::ReadsMismatch);
+		}
+		Ok((context, program))
+	}
+}
+
+/// Contract without a built-in transcript; calls are given as a [`CallDescription`]. It has no
+/// initial state, so unlike [`super::MerkleTreeContract`] it can't be deployed by the toolkit.
+pub struct GenericContract {
+	pub resolver: &'static Resolver,
+}
+
+impl GenericContract {
+	pub fn new(resolver: &'static Resolver) -> Self {
+		Self { resolver }
+	}
+
+	fn description<D: DB + Clone + 'static>(
+		input: &(dyn Any + Send + Sync),
+	) -> &CallDescription<D> {
+		input
+			.downcast_ref::<CallDescription<D>>()
+			.expect("Contract Call input should be a CallDescription")
+	}
+}
+
+impl<D: DB + Clone + 'static> Contract<D> for GenericContract {
+	fn resolver(&self) -> &'static Resolver {
+		self.resolver
+	}
+
+	fn transcript(
+		&self,
+		_key: &str,
+		input: &Box<dyn Any + Send + Sync>,
+		address: &ContractAddress,
+		context: Arc<LedgerContext<D>>,
+	) -> (AlignedValue, Vec<AlignedValue>, Vec<Transcripts<D>>) {
+		let description = Self::description::<D>(&**input);
+		context.with_ledger_state(|ledger_state| {
+			// Builders check the call with `CallDescription::verify_program` before adding it
+			let (context, program) = description
+				.verify_program(*address, ledger_state)
+				.unwrap_or_else(|e| panic!("Call to contract {:?} failed: {e}", *address));
+			let pre_transcript =
+				PreTranscript { context: &context, program: &program, comm_comm: None };
+			let transcripts = partition_transcripts(&[pre_transcript], &ledger_state.parameters)
+				.expect("Transcript arguments should be valid");
+
+			(description.input.clone(), description.private_transcript_outputs.clone(), transcripts)
+		})
+	}
+
+	fn operation(
+		&self,
+		key: &str,
+		address: &ContractAddress,
+		context: Arc<LedgerContext<D>>,
+	) -> Sp<ContractOperation, D> {
+		context.with_ledger_state(|ledger_state| {
+			let contract_state = ledger_state
+				.index(*address)
+				.unwrap_or_else(|| panic!("Contract with address {:?} does not exist", *address));
+
+			contract_state
+				.operations
+				.get(&EntryPointBuf(key.as_bytes().to_vec()))
+				.unwrap_or_else(|| panic!("Contract has no entry point {key}"))
+				.clone()
+		})
+	}
+
+	fn program_with_results(
+		prog: &[Op<ResultModeGather, D>],
+		results: &[AlignedValue],
+	) -> Vec<Op<ResultModeVerify, D>> {
+		let mut res_iter = results.iter();
+		prog.iter()
+			.map(|op| {
+				op.clone().translate(|()| {
+					res_iter
+						.next()
+						.expect("Public transcript should have a result per read")
+						.clone()
+				})
+			})
+			.collect()
+	}
+
+	fn contract_call(
+		&self,
+		address: &ContractAddress,
+		key: &'static str,
+		input: &Box<dyn Any + Send + Sync>,
+		rng: &mut StdRng,
+		context: Arc<LedgerContext<D>>,
+	) -> ContractCallPrototype<D> {
+		let output = Self::description::<D>(&**input).output.clone();
+		let (input, private_transcript_outputs, transcripts) =
+			self.transcript(key, input, address, context.clone());
+
+		ContractCallPrototype {
+			address: *address,
+			entry_point: key.as_bytes().into(),
+			op: (*self.operation(key, address, context)).clone(),
+			guaranteed_public_transcript: transcripts[0].0.clone(),
+			fallible_public_transcript: transcripts[0].1.clone(),
+			private_transcript_outputs,
+			input,
+			output,
+			communication_commitment_rand: rng.r#gen(),
+			key_location: KeyLocation(Cow::Borrowed(key)),
+		}
+	}
+}
@@ -19,11 +19,11 @@ use std::{any::Any, borrow::Cow, sync::Arc};
 
 use super::super::{
 	AlignedValue, ChargedState, Contract, ContractAddress, ContractCallPrototype, ContractDeploy,
-	ContractMaintenanceAuthority, ContractOperation, ContractState, DB, EntryPointBuf,
-	HashMapStorage as HashMap, HistoricMerkleTree_check_root, HistoricMerkleTree_insert, Key,
-	KeyLocation, LedgerContext, MerkleTree, Op, PreTranscript, QueryContext, Resolver,
-	ResultModeGather, ResultModeVerify, Rng, Sp, StateValue, StdRng, Transcripts,
-	ValueReprAlignedValue, VerifyingKey, key, leaf_hash, partition_transcripts, stval,
+	ContractMaintenanceAuthority, ContractOperation, ContractState, DB, DeployContract,
+	EntryPointBuf, HashMapStorage as HashMap, HistoricMerkleTree_check_root,
+	HistoricMerkleTree_insert, Key, KeyLocation, LedgerContext, MerkleTree, Op, PreTranscript,
+	QueryContext, Resolver, ResultModeGather, ResultModeVerify, Rng, Sp, StateValue, StdRng,
+	Transcripts, ValueReprAlignedValue, VerifyingKey, key, leaf_hash, partition_transcripts, stval,
 	verifier_key,
 };
 
@@ -70,7 +70,7 @@ impl Default for MerkleTreeContract {
 }
 
 #[async_trait]
-impl<D: DB + Clone> Contract<D> for MerkleTreeContract {
+impl<D: DB + Clone> DeployContract<D> for MerkleTreeContract {
 	async fn deploy(
 		&self,
 		commitee: &[VerifyingKey],
@@ -96,7 +96,9 @@ impl<D: DB + Clone> Contract<D> for MerkleTreeContract {
 
 		ContractDeploy::new(rng, contract)
 	}
+}
 
+impl<D: DB + Clone> Contract<D> for MerkleTreeContract {
 	fn resolver(&self) -> &'static Resolver {
 		self.resolver
 	}
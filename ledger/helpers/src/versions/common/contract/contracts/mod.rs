@@ -11,6 +11,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod generic;
 mod merkle_tree;
 
+pub use generic::*;
 pub use merkle_tree::*;
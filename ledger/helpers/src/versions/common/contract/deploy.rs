@@ -15,11 +15,11 @@ use async_trait::async_trait;
 use std::{marker::PhantomData, sync::Arc};
 
 use super::super::{
-	BuildContractAction, Contract, DB, Intent, LedgerContext, PedersenRandomness,
+	BuildContractAction, DB, DeployContract, Intent, LedgerContext, PedersenRandomness,
 	ProofPreimageMarker, Signature, StdRng, VerifyingKey,
 };
 
-pub struct ContractDeployInfo<C: Contract<D>, D: DB + Clone> {
+pub struct ContractDeployInfo<C: DeployContract<D>, D: DB + Clone> {
 	pub type_: C,
 	pub committee: Vec<VerifyingKey>,
 	pub committee_threshold: u32,
@@ -27,7 +27,7 @@ pub struct ContractDeployInfo<C: Contract<D>, D: DB + Clone> {
 }
 
 #[async_trait]
-impl<C: Contract<D>, D: DB + Clone> BuildContractAction<D> for ContractDeployInfo<C, D> {
+impl<C: DeployContract<D>, D: DB + Clone> BuildContractAction<D> for ContractDeployInfo<C, D> {
 	async fn build(
 		&mut self,
 		rng: &mut StdRng,
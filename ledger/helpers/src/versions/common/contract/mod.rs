@@ -23,7 +23,7 @@ use super::super::{
 // Re-export types needed by submodules
 pub use super::super::{
 	ChargedState, ContractMaintenanceAuthority, ContractState, DUST_EXPECTED_FILES, DustResolver,
-	EntryPointBuf, FetchMode, HashMapStorage, HistoricMerkleTree_check_root,
+	EntryPointBuf, FetchMode, GatherEvent, HashMapStorage, HistoricMerkleTree_check_root,
 	HistoricMerkleTree_insert, Key, KeyLocation, MerkleTree, MidnightDataProvider, OutputMode,
 	PUBLIC_PARAMS, PreTranscript, QueryContext, Rng, StateValue, ValueReprAlignedValue,
 	VerifyingKey, key, leaf_hash, partition_transcripts, stval, verifier_key,
@@ -42,15 +42,7 @@ pub use contracts::*;
 pub use deploy::*;
 pub use maintenance::*;
 
-#[async_trait]
 pub trait Contract<D: DB + Clone>: Send + Sync {
-	async fn deploy(
-		&self,
-		commitee: &[VerifyingKey],
-		commitee_threshold: u32,
-		rng: &mut StdRng,
-	) -> ContractDeploy<D>;
-
 	fn resolver(&self) -> &'static Resolver;
 
 	fn transcript(
@@ -83,6 +75,17 @@ pub trait Contract<D: DB + Clone>: Send + Sync {
 	) -> ContractCallPrototype<D>;
 }
 
+/// Contract with a built-in initial state, so it can be deployed without an intent
+#[async_trait]
+pub trait DeployContract<D: DB + Clone>: Contract<D> {
+	async fn deploy(
+		&self,
+		commitee: &[VerifyingKey],
+		commitee_threshold: u32,
+		rng: &mut StdRng,
+	) -> ContractDeploy<D>;
+}
+
 #[async_trait]
 pub trait BuildContractAction<D: DB + Clone>: Send + Sync {
 	async fn build(
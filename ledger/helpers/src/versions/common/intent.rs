@@ -169,52 +169,56 @@ impl<D: DB + Clone> IntentCustom<D> {
 				DUST_EXPECTED_FILES.to_owned(),
 			)?),
 			Box::new(move |KeyLocation(loc)| {
-				let artifact_dirs = artifact_dirs.to_vec();
-				let sync_block = move || {
-					let read_file = |dir, ext| {
-						for parent_dir in &artifact_dirs {
-							let path = format!("{parent_dir}/{dir}/{loc}.{ext}");
-							match std::fs::read(&path) {
-								Err(e) if e.kind() == io::ErrorKind::NotFound => {
-									println!("Resolver: missing key at path {path}");
-									continue;
-								},
-								Err(e) => {
-									println!("Resolver: error reading key at path {path}: {e}");
-									return Err(e);
-								},
-								Ok(v) => {
-									println!("Resolver: found key at path {path}");
-									return Ok(Some(v));
-								},
-							}
-						}
-						Ok(None)
-					};
-					let Some(prover_key) = read_file("keys", "prover")? else {
-						println!("WARN: prover key not created");
-						return Ok(None);
-					};
-					let Some(verifier_key) = read_file("keys", "verifier")? else {
-						println!("WARN: verifier key not created");
-						return Ok(None);
-					};
-					let Some(ir_source) = read_file("zkir", "bzkir")? else {
-						println!("WARN:  ir source not created");
-						return Ok(None);
-					};
-
-					println!("Creating Proving Key Material...");
-
-					Ok(Some(ProvingKeyMaterial { prover_key, verifier_key, ir_source }))
-				};
-				let res = sync_block();
+				let res = read_key_material(&artifact_dirs, &loc);
 				Box::pin(std::future::ready(res))
 			}),
 		))
 	}
 }
 
+/// Reads the proving key material of circuit `loc` from the first artifact directory holding it
+pub fn read_key_material(
+	artifact_dirs: &[String],
+	loc: &str,
+) -> Result<Option<ProvingKeyMaterial>, std::io::Error> {
+	let read_file = |dir, ext| {
+		for parent_dir in artifact_dirs {
+			let path = format!("{parent_dir}/{dir}/{loc}.{ext}");
+			match std::fs::read(&path) {
+				Err(e) if e.kind() == io::ErrorKind::NotFound => {
+					println!("Resolver: missing key at path {path}");
+					continue;
+				},
+				Err(e) => {
+					println!("Resolver: error reading key at path {path}: {e}");
+					return Err(e);
+				},
+				Ok(v) => {
+					println!("Resolver: found key at path {path}");
+					return Ok(Some(v));
+				},
+			}
+		}
+		Ok(None)
+	};
+	let Some(prover_key) = read_file("keys", "prover")? else {
+		println!("WARN: prover key not created");
+		return Ok(None);
+	};
+	let Some(verifier_key) = read_file("keys", "verifier")? else {
+		println!("WARN: verifier key not created");
+		return Ok(None);
+	};
+	let Some(ir_source) = read_file("zkir", "bzkir")? else {
+		println!("WARN:  ir source not created");
+		return Ok(None);
+	};
+
+	println!("Creating Proving Key Material...");
+
+	Ok(Some(ProvingKeyMaterial { prover_key, verifier_key, ir_source }))
+}
+
 #[async_trait]
 impl<D: DB + Clone> BuildIntent<D> for IntentCustom<D> {
 	async fn build(
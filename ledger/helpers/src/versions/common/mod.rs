@@ -74,7 +74,7 @@ pub use super::{
 		cost_model::CostModel,
 		error::TranscriptRejected,
 		ops::{Key, Op, key},
-		result_mode::{ResultModeGather, ResultModeVerify},
+		result_mode::{GatherEvent, ResultModeGather, ResultModeVerify},
 		state::{
 			ChargedState, ContractMaintenanceAuthority, ContractOperation, ContractState,
 			EntryPointBuf, StateValue, stval,
@@ -89,7 +89,7 @@ pub use super::{
 		merkle_tree::{MerklePath, MerkleTree, leaf_hash},
 		proofs::{
 			KeyLocation, ParamsProver, ParamsProverProvider, ProofPreimage, ProverKey,
-			ProvingKeyMaterial, Resolver as ResolverTrait, VerifierKey,
+			ProvingKeyMaterial, ProvingProvider, Resolver as ResolverTrait, VerifierKey,
 		},
 	},
 	zkir::{IrSource, LocalProvingProvider},
@@ -190,17 +190,27 @@ pub fn token_type_decode(input: &str) -> TokenType {
 	TokenType::Shielded(ShieldedTokenType(HashOutput(tt_bytes)))
 }
 
-#[cfg(all(feature = "std", feature = "can-panic"))]
-pub fn extract_info_from_tx_with_context(bytes: &[u8]) -> (Vec<u8>, BlockContext) {
+/// Splits a serialized `TransactionWithContext` into the serialized transaction and its block
+/// context
+#[cfg(feature = "std")]
+pub fn try_extract_info_from_tx_with_context(
+	bytes: &[u8],
+) -> Result<(Vec<u8>, BlockContext), std::io::Error> {
 	let tx_with_context: TransactionWithContext<Signature, ProofMarker, DefaultDB> =
-		deserialize(bytes)
-			.unwrap_or_else(|err| panic!("Can't deserialize `TransactionWithContext: {err}"));
+		deserialize(bytes)?;
 	let SerdeTransaction::Midnight(tx) = tx_with_context.tx else {
-		panic!("expected test to run against midnight transaction");
+		return Err(std::io::Error::new(
+			std::io::ErrorKind::InvalidData,
+			"expected a midnight transaction",
+		));
 	};
-	let block_context = tx_with_context.block_context;
-	let serialized_tx =
-		serialize(&tx).unwrap_or_else(|err| panic!("Can't serialize `Transaction`: {err}"));
+	let serialized_tx = serialize(&tx)?;
 
-	(serialized_tx, block_context)
+	Ok((serialized_tx, tx_with_context.block_context))
+}
+
+#[cfg(all(feature = "std", feature = "can-panic"))]
+pub fn extract_info_from_tx_with_context(bytes: &[u8]) -> (Vec<u8>, BlockContext) {
+	try_extract_info_from_tx_with_context(bytes)
+		.unwrap_or_else(|err| panic!("Can't extract `TransactionWithContext`: {err}"))
 }
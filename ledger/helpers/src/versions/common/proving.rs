@@ -57,23 +57,25 @@ impl<D: DB + Clone> ProofProvider<D> for LocalProofServer {
 		cost_model: &CostModel,
 	) -> Transaction<Signature, ProofMarker, PedersenRandomness, D> {
 		println!("Ensuring zswap key material is available...");
-		{
-			let ks =
-				futures::future::join_all((10..=15).map(|k| resolver.zswap_resolver.0.fetch_k(k)));
-			let keys = futures::future::join_all(
-				["midnight/zswap/spend", "midnight/zswap/output", "midnight/zswap/sign"]
-					.into_iter()
-					.map(|k| resolver.zswap_resolver.resolve_key(KeyLocation(k.into()))),
-			);
-			let (ks, keys) = futures::future::join(ks, keys).await;
-			ks.into_iter().collect::<Result<Vec<_>, _>>().expect("failed to get keys 'ks'");
-			keys.into_iter()
-				.collect::<Result<Vec<_>, _>>()
-				.expect("failed to get keys 'keys'");
-		}
+		fetch_zswap_keys(resolver).await;
 
 		let pp = LocalProvingProvider { rng, resolver, params: &*PUBLIC_PARAMS };
 
 		tx.prove(pp, cost_model).await.expect("Tx should be provable")
 	}
 }
+
+/// Fetches the public parameters and zswap keys needed to prove transactions, if not cached yet
+pub async fn fetch_zswap_keys(resolver: &Resolver) {
+	let ks = futures::future::join_all((10..=15).map(|k| resolver.zswap_resolver.0.fetch_k(k)));
+	let keys = futures::future::join_all(
+		["midnight/zswap/spend", "midnight/zswap/output", "midnight/zswap/sign"]
+			.into_iter()
+			.map(|k| resolver.zswap_resolver.resolve_key(KeyLocation(k.into()))),
+	);
+	let (ks, keys) = futures::future::join(ks, keys).await;
+	ks.into_iter().collect::<Result<Vec<_>, _>>().expect("failed to get keys 'ks'");
+	keys.into_iter()
+		.collect::<Result<Vec<_>, _>>()
+		.expect("failed to get keys 'keys'");
+}
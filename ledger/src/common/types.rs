@@ -54,6 +54,9 @@ pub struct TransactionAppliedStateRoot {
 	pub claim_rewards: Vec<u128>,
 	pub unshielded_utxos_created: Vec<UtxoInfo>,
 	pub unshielded_utxos_spent: Vec<UtxoInfo>,
+	/// Costs of the transaction against the state it was applied to
+	pub storage_cost: StorageCost,
+	pub gas_cost: GasCost,
 }
 
 #[derive(Encode, Decode, DecodeWithMemTracking)]
@@ -98,6 +101,13 @@ impl Default for BlockContext {
 pub type StorageCost = u128;
 pub type GasCost = u64;
 
+/// DUST fees of a transaction: what it pays, and the minimum required by the ledger parameters
+#[derive(Encode, Decode, DecodeWithMemTracking, Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub struct TransactionFees {
+	pub paid: u128,
+	pub required: u128,
+}
+
 #[derive(Encode, Decode, DecodeWithMemTracking, Default, Debug, Eq, PartialEq, Clone)]
 pub struct GuaranteedCoinsDetails {
 	inputs_num: u32,
@@ -293,3 +303,56 @@ pub struct UtxoInfo {
 	pub value: u128,
 	pub output_no: u32,
 }
+
+/// Transaction pool tags of a transaction, derived from the unshielded UTXOs and zswap coins it
+/// consumes and creates
+#[derive(Encode, Decode, DecodeWithMemTracking, Debug, Default, Clone, Eq, PartialEq)]
+pub struct TransactionTags {
+	/// Created UTXOs, spent UTXOs, zswap nullifiers and commitments, and DUST nullifiers. Two
+	/// transactions providing the same tag conflict
+	pub provides: Vec<Vec<u8>>,
+	/// UTXOs spent by the transaction that aren't in the ledger state yet, so must be created
+	/// by another transaction first
+	pub requires: Vec<Vec<u8>>,
+}
+
+impl TransactionTags {
+	pub const UTXO_PREFIX: &'static [u8] = b"utxo";
+	pub const UTXO_SPEND_PREFIX: &'static [u8] = b"utxo_spend";
+	pub const NULLIFIER_PREFIX: &'static [u8] = b"nullifier";
+	pub const COMMITMENT_PREFIX: &'static [u8] = b"commitment";
+	pub const DUST_NULLIFIER_PREFIX: &'static [u8] = b"dust_nullifier";
+
+	pub fn utxo(intent_hash: &Hash, output_no: u32) -> Vec<u8> {
+		[Self::UTXO_PREFIX, intent_hash, &output_no.to_le_bytes()].concat()
+	}
+
+	pub fn utxo_spend(intent_hash: &Hash, output_no: u32) -> Vec<u8> {
+		[Self::UTXO_SPEND_PREFIX, intent_hash, &output_no.to_le_bytes()].concat()
+	}
+
+	pub fn nullifier(nullifier: &Hash) -> Vec<u8> {
+		[Self::NULLIFIER_PREFIX, nullifier].concat()
+	}
+
+	pub fn commitment(commitment: &Hash) -> Vec<u8> {
+		[Self::COMMITMENT_PREFIX, commitment].concat()
+	}
+
+	pub fn dust_nullifier(nullifier: &[u8]) -> Vec<u8> {
+		[Self::DUST_NULLIFIER_PREFIX, nullifier].concat()
+	}
+}
+
+/// A transaction validated for the transaction pool, with what the pool needs to order it, from a
+/// single decoding of the transaction
+#[derive(Encode, Decode, DecodeWithMemTracking, Debug, Clone, Eq, PartialEq)]
+pub struct PoolValidatedTransaction {
+	/// Hash of the transaction validation
+	pub tx_hash: Hash,
+	pub tags: TransactionTags,
+	pub fees: TransactionFees,
+	/// Costs of the transaction against the state it was validated against
+	pub storage_cost: StorageCost,
+	pub gas_cost: GasCost,
+}
@@ -13,8 +13,9 @@
 
 use crate::{
 	common::types::{
-		BlockContext, GasCost, Hash, StorageCost, SystemTransactionAppliedStateRoot,
-		TransactionAppliedStateRoot, TransactionDetails, Tx,
+		BlockContext, GasCost, Hash, PoolValidatedTransaction, StorageCost,
+		SystemTransactionAppliedStateRoot, TransactionAppliedStateRoot, TransactionDetails,
+		TransactionFees, TransactionTags, Tx,
 	},
 	hard_fork_test, latest,
 };
@@ -25,13 +26,23 @@ use sp_runtime_interface::pass_by::{
 use sp_runtime_interface::runtime_interface;
 use sp_std::vec::Vec;
 
+#[cfg(feature = "std")]
+use {
+	midnight_primitives_ledger::{LedgerStorageBackend, LedgerStorageExt},
+	sp_externalities::{Externalities, ExternalitiesExt},
+};
+
 #[cfg(feature = "std")]
 type Database = ledger_storage::db::ParityDb;
+#[cfg(feature = "std")]
+type InMemoryDatabase = ledger_storage::db::InMemoryDB;
 
 // TODO: hard fork
 #[cfg(feature = "std")]
 // type DatabaseHF = ledger_storage::db::ParityDb;
 type DatabaseHF = ledger_storage_hf::db::ParityDb;
+#[cfg(feature = "std")]
+type InMemoryDatabaseHF = ledger_storage_hf::db::InMemoryDB;
 
 #[cfg(feature = "std")]
 type Signature = base_crypto::signatures::Signature;
@@ -41,6 +52,40 @@ type Signature = base_crypto::signatures::Signature;
 // type SignatureHF = base_crypto::signatures::Signature;
 type SignatureHF = base_crypto_hf::signatures::Signature;
 
+/// Storage backend of the ledger arena: the one carried by `LedgerStorageExt` when the host
+/// function has externalities, otherwise the one the node initialized its storage with
+#[cfg(feature = "std")]
+fn selected_storage_backend(externalities: Option<&mut dyn Externalities>) -> LedgerStorageBackend {
+	externalities
+		.and_then(|mut ext| ext.extension::<LedgerStorageExt>().map(|storage| storage.0.backend))
+		.unwrap_or_else(crate::storage_backend)
+}
+
+/// Calls a `Bridge` function of a ledger version with the database of the selected backend
+#[cfg(feature = "std")]
+macro_rules! bridge {
+	(latest, $ext:expr, $f:ident($($arg:expr),* $(,)?)) => {
+		match selected_storage_backend($ext) {
+			LedgerStorageBackend::ParityDb => {
+				latest::Bridge::<Signature, Database>::$f($($arg),*)
+			},
+			LedgerStorageBackend::InMemory => {
+				latest::Bridge::<Signature, InMemoryDatabase>::$f($($arg),*)
+			},
+		}
+	};
+	(hard_fork_test, $ext:expr, $f:ident($($arg:expr),* $(,)?)) => {
+		match selected_storage_backend($ext) {
+			LedgerStorageBackend::ParityDb => {
+				hard_fork_test::Bridge::<SignatureHF, DatabaseHF>::$f($($arg),*)
+			},
+			LedgerStorageBackend::InMemory => {
+				hard_fork_test::Bridge::<SignatureHF, InMemoryDatabaseHF>::$f($($arg),*)
+			},
+		}
+	};
+}
+
 #[runtime_interface]
 pub trait LedgerBridge {
 	fn set_default_storage(&mut self) {
@@ -54,14 +99,14 @@ pub trait LedgerBridge {
 	}
 
 	fn flush_storage(&mut self) {
-		latest::Bridge::<Signature, Database>::flush_storage(*self)
+		bridge!(latest, Some(&mut **self), flush_storage(*self))
 	}
 
 	fn pre_fetch_storage(
 		&mut self,
 		state_key: PassFatPointerAndRead<&[u8]>,
 	) -> AllocateAndReturnByCodec<Result<(), latest::types::LedgerApiError>> {
-		latest::Bridge::<Signature, Database>::pre_fetch_storage(*self, state_key)
+		bridge!(latest, Some(&mut **self), pre_fetch_storage(*self, state_key))
 	}
 
 	fn post_block_update(
@@ -69,7 +114,7 @@ pub trait LedgerBridge {
 		state_key: PassFatPointerAndRead<&[u8]>,
 		block_context: PassFatPointerAndDecode<BlockContext>,
 	) -> AllocateAndReturnByCodec<Result<Vec<u8>, latest::types::LedgerApiError>> {
-		latest::Bridge::<Signature, Database>::post_block_update(*self, state_key, block_context)
+		bridge!(latest, Some(&mut **self), post_block_update(*self, state_key, block_context))
 	}
 
 	// Current Enabled Version
@@ -88,12 +133,7 @@ pub trait LedgerBridge {
 		_runtime_version: u32,
 	) -> AllocateAndReturnByCodec<Result<TransactionAppliedStateRoot, latest::types::LedgerApiError>>
 	{
-		latest::Bridge::<Signature, Database>::apply_transaction(
-			*self,
-			state_key,
-			tx,
-			block_context,
-		)
+		bridge!(latest, Some(&mut **self), apply_transaction(*self, state_key, tx, block_context))
 	}
 
 	fn apply_system_transaction(
@@ -105,11 +145,10 @@ pub trait LedgerBridge {
 	) -> AllocateAndReturnByCodec<
 		Result<SystemTransactionAppliedStateRoot, latest::types::LedgerApiError>,
 	> {
-		latest::Bridge::<Signature, Database>::apply_system_transaction(
-			*self,
-			state_key,
-			tx,
-			block_context,
+		bridge!(
+			latest,
+			Some(&mut **self),
+			apply_system_transaction(*self, state_key, tx, block_context)
 		)
 	}
 
@@ -125,12 +164,29 @@ pub trait LedgerBridge {
 		runtime_version: u32,
 	) -> AllocateAndReturnByCodec<Result<(Hash, TransactionDetails), latest::types::LedgerApiError>>
 	{
-		latest::Bridge::<Signature, Database>::validate_transaction(
-			*self,
-			state_key,
-			tx,
-			block_context,
-			runtime_version,
+		bridge!(
+			latest,
+			Some(&mut **self),
+			validate_transaction(*self, state_key, tx, block_context, runtime_version)
+		)
+	}
+
+	/*
+	 * Validates a transaction for the transaction pool, and returns its pool tags, fees and costs
+	 */
+	// Current Enabled Version
+	fn validate_pool_transaction(
+		&mut self,
+		state_key: PassFatPointerAndRead<&[u8]>,
+		tx: PassFatPointerAndRead<&[u8]>,
+		block_context: PassFatPointerAndDecode<BlockContext>,
+		runtime_version: u32,
+	) -> AllocateAndReturnByCodec<Result<PoolValidatedTransaction, latest::types::LedgerApiError>>
+	{
+		bridge!(
+			latest,
+			Some(&mut **self),
+			validate_pool_transaction(*self, state_key, tx, block_context, runtime_version)
 		)
 	}
 
@@ -143,7 +199,7 @@ pub trait LedgerBridge {
 		state_key: PassFatPointerAndRead<&[u8]>,
 		contract_address: PassFatPointerAndRead<&[u8]>,
 	) -> AllocateAndReturnByCodec<Result<Vec<u8>, latest::types::LedgerApiError>> {
-		latest::Bridge::<Signature, Database>::get_contract_state(state_key, contract_address)
+		bridge!(latest, Some(&mut **self), get_contract_state(state_key, contract_address))
 	}
 
 	/*
@@ -153,7 +209,7 @@ pub trait LedgerBridge {
 	fn get_decoded_transaction(
 		transaction_bytes: PassFatPointerAndRead<&[u8]>,
 	) -> AllocateAndReturnByCodec<Result<Tx, latest::types::LedgerApiError>> {
-		latest::Bridge::<Signature, Database>::get_decoded_transaction(transaction_bytes)
+		bridge!(latest, None, get_decoded_transaction(transaction_bytes))
 	}
 
 	/*
@@ -165,7 +221,7 @@ pub trait LedgerBridge {
 		state_key: PassFatPointerAndRead<&[u8]>,
 		contract_address: PassFatPointerAndRead<&[u8]>,
 	) -> AllocateAndReturnByCodec<Result<Vec<u8>, latest::types::LedgerApiError>> {
-		latest::Bridge::<Signature, Database>::get_zswap_chain_state(state_key, contract_address)
+		bridge!(latest, Some(&mut **self), get_zswap_chain_state(state_key, contract_address))
 	}
 
 	/*
@@ -179,12 +235,7 @@ pub trait LedgerBridge {
 		receiver: PassFatPointerAndRead<&[u8]>,
 		block_context: PassFatPointerAndDecode<BlockContext>,
 	) -> AllocateAndReturnByCodec<Result<Vec<u8>, latest::types::LedgerApiError>> {
-		latest::Bridge::<Signature, Database>::mint_coins(
-			state_key,
-			amount,
-			receiver,
-			block_context,
-		)
+		bridge!(latest, Some(&mut **self), mint_coins(state_key, amount, receiver, block_context))
 	}
 
 	/*
@@ -196,7 +247,7 @@ pub trait LedgerBridge {
 		state_key: PassFatPointerAndRead<&[u8]>,
 		beneficiary: PassFatPointerAndRead<&[u8]>,
 	) -> AllocateAndReturnByCodec<Result<u128, latest::types::LedgerApiError>> {
-		latest::Bridge::<Signature, Database>::get_unclaimed_amount(state_key, beneficiary)
+		bridge!(latest, Some(&mut **self), get_unclaimed_amount(state_key, beneficiary))
 	}
 
 	/*
@@ -207,7 +258,7 @@ pub trait LedgerBridge {
 		&mut self,
 		state_key: PassFatPointerAndRead<&[u8]>,
 	) -> AllocateAndReturnByCodec<Result<Vec<u8>, latest::types::LedgerApiError>> {
-		latest::Bridge::<Signature, Database>::get_ledger_parameters(state_key)
+		bridge!(latest, Some(&mut **self), get_ledger_parameters(state_key))
 	}
 
 	/*
@@ -219,7 +270,32 @@ pub trait LedgerBridge {
 		tx: PassFatPointerAndRead<&[u8]>,
 		block_context: PassFatPointerAndDecode<BlockContext>,
 	) -> AllocateAndReturnByCodec<Result<(StorageCost, GasCost), latest::types::LedgerApiError>> {
-		latest::Bridge::<Signature, Database>::get_transaction_cost(state_key, tx, &block_context)
+		bridge!(latest, Some(&mut **self), get_transaction_cost(state_key, tx, &block_context))
+	}
+
+	/*
+	 * Returns the DUST fee paid by a transaction, and the fee required by the ledger parameters
+	 */
+	// Current Enabled Version
+	fn get_transaction_fees(
+		&mut self,
+		state_key: PassFatPointerAndRead<&[u8]>,
+		tx: PassFatPointerAndRead<&[u8]>,
+	) -> AllocateAndReturnByCodec<Result<TransactionFees, latest::types::LedgerApiError>> {
+		bridge!(latest, Some(&mut **self), get_transaction_fees(state_key, tx))
+	}
+
+	/*
+	 * Returns the transaction pool tags of a transaction, from the UTXOs and coins it spends and
+	 * creates
+	 */
+	// Current Enabled Version
+	fn get_transaction_tags(
+		&mut self,
+		state_key: PassFatPointerAndRead<&[u8]>,
+		tx: PassFatPointerAndRead<&[u8]>,
+	) -> AllocateAndReturnByCodec<Result<TransactionTags, latest::types::LedgerApiError>> {
+		bridge!(latest, Some(&mut **self), get_transaction_tags(*self, state_key, tx))
 	}
 
 	/*
@@ -230,7 +306,7 @@ pub trait LedgerBridge {
 		&mut self,
 		state_key: PassFatPointerAndRead<&[u8]>,
 	) -> AllocateAndReturnByCodec<Result<Vec<u8>, latest::types::LedgerApiError>> {
-		latest::Bridge::<Signature, Database>::get_zswap_state_root(state_key)
+		bridge!(latest, Some(&mut **self), get_zswap_state_root(state_key))
 	}
 
 	fn construct_cnight_generates_dust_event(
@@ -240,15 +316,28 @@ pub trait LedgerBridge {
 		action: u8,
 		nonce: PassFatPointerAndDecode<[u8; 32]>,
 	) -> AllocateAndReturnByCodec<Result<Vec<u8>, latest::types::LedgerApiError>> {
-		latest::Bridge::<Signature, Database>::construct_cnight_generates_dust_event(
-			value, owner, time, action, nonce,
+		bridge!(
+			latest,
+			None,
+			construct_cnight_generates_dust_event(value, owner, time, action, nonce)
 		)
 	}
 
 	fn construct_cnight_generates_dust_system_tx(
 		events: PassFatPointerAndDecode<Vec<Vec<u8>>>,
 	) -> AllocateAndReturnByCodec<Result<Vec<u8>, latest::types::LedgerApiError>> {
-		latest::Bridge::<Signature, Database>::construct_cnight_generates_dust_system_tx(events)
+		bridge!(latest, None, construct_cnight_generates_dust_system_tx(events))
+	}
+
+	/*
+	 * Splits a serialized transaction with context. Only available to runtime benchmarks
+	 */
+	// Current Enabled Version
+	#[cfg(feature = "runtime-benchmarks")]
+	fn get_transaction_with_context(
+		bytes: PassFatPointerAndRead<&[u8]>,
+	) -> AllocateAndReturnByCodec<Result<(Vec<u8>, BlockContext), latest::types::LedgerApiError>> {
+		bridge!(latest, None, get_transaction_with_context(bytes))
 	}
 }
 
@@ -260,36 +349,42 @@ pub trait LedgerBridgeHf {
 
 	fn drop_default_storage(&mut self) {
 		use ledger_storage::{
-			db::ParityDb,
+			db::{InMemoryDB, ParityDb},
 			storage::{try_get_default_storage, unsafe_drop_default_storage},
 		};
-		unsafe_drop_default_storage::<ParityDb>();
-
-		match try_get_default_storage::<ParityDb>() {
-			Some(_) => {
-				log::error!(
-					target: hard_fork_test::LOG_TARGET,
-					"Pre Hard-fork Default Storage wasn't successfully dropped, still exists",
-				);
+		let still_exists = match selected_storage_backend(Some(&mut **self)) {
+			LedgerStorageBackend::ParityDb => {
+				unsafe_drop_default_storage::<ParityDb>();
+				try_get_default_storage::<ParityDb>().is_some()
 			},
-			None => {
-				log::info!(
-					target: hard_fork_test::LOG_TARGET,
-					"Pre Hard-fork Default Storage was successfully dropped",
-				);
+			LedgerStorageBackend::InMemory => {
+				unsafe_drop_default_storage::<InMemoryDB>();
+				try_get_default_storage::<InMemoryDB>().is_some()
 			},
 		};
+
+		if still_exists {
+			log::error!(
+				target: hard_fork_test::LOG_TARGET,
+				"Pre Hard-fork Default Storage wasn't successfully dropped, still exists",
+			);
+		} else {
+			log::info!(
+				target: hard_fork_test::LOG_TARGET,
+				"Pre Hard-fork Default Storage was successfully dropped",
+			);
+		}
 	}
 
 	fn flush_storage(&mut self) {
-		hard_fork_test::Bridge::<SignatureHF, DatabaseHF>::flush_storage(*self)
+		bridge!(hard_fork_test, Some(&mut **self), flush_storage(*self))
 	}
 
 	fn pre_fetch_storage(
 		&mut self,
 		state_key: PassFatPointerAndRead<&[u8]>,
 	) -> AllocateAndReturnByCodec<Result<(), hard_fork_test::types::LedgerApiError>> {
-		hard_fork_test::Bridge::<SignatureHF, DatabaseHF>::pre_fetch_storage(*self, state_key)
+		bridge!(hard_fork_test, Some(&mut **self), pre_fetch_storage(*self, state_key))
 	}
 
 	fn post_block_update(
@@ -297,10 +392,10 @@ pub trait LedgerBridgeHf {
 		state_key: PassFatPointerAndRead<&[u8]>,
 		block_context: PassFatPointerAndDecode<BlockContext>,
 	) -> AllocateAndReturnByCodec<Result<Vec<u8>, hard_fork_test::types::LedgerApiError>> {
-		hard_fork_test::Bridge::<SignatureHF, DatabaseHF>::post_block_update(
-			*self,
-			state_key,
-			block_context,
+		bridge!(
+			hard_fork_test,
+			Some(&mut **self),
+			post_block_update(*self, state_key, block_context)
 		)
 	}
 
@@ -319,11 +414,10 @@ pub trait LedgerBridgeHf {
 	) -> AllocateAndReturnByCodec<
 		Result<TransactionAppliedStateRoot, hard_fork_test::types::LedgerApiError>,
 	> {
-		hard_fork_test::Bridge::<SignatureHF, DatabaseHF>::apply_transaction(
-			*self,
-			state_key,
-			tx,
-			block_context,
+		bridge!(
+			hard_fork_test,
+			Some(&mut **self),
+			apply_transaction(*self, state_key, tx, block_context)
 		)
 	}
 
@@ -336,11 +430,10 @@ pub trait LedgerBridgeHf {
 	) -> AllocateAndReturnByCodec<
 		Result<SystemTransactionAppliedStateRoot, hard_fork_test::types::LedgerApiError>,
 	> {
-		hard_fork_test::Bridge::<SignatureHF, DatabaseHF>::apply_system_transaction(
-			*self,
-			state_key,
-			tx,
-			block_context,
+		bridge!(
+			hard_fork_test,
+			Some(&mut **self),
+			apply_system_transaction(*self, state_key, tx, block_context)
 		)
 	}
 
@@ -354,12 +447,27 @@ pub trait LedgerBridgeHf {
 	) -> AllocateAndReturnByCodec<
 		Result<(Hash, TransactionDetails), hard_fork_test::types::LedgerApiError>,
 	> {
-		hard_fork_test::Bridge::<SignatureHF, DatabaseHF>::validate_transaction(
-			*self,
-			state_key,
-			tx,
-			block_context,
-			runtime_version,
+		bridge!(
+			hard_fork_test,
+			Some(&mut **self),
+			validate_transaction(*self, state_key, tx, block_context, runtime_version)
+		)
+	}
+
+	// Hard-fork Version
+	fn validate_pool_transaction(
+		&mut self,
+		state_key: PassFatPointerAndRead<&[u8]>,
+		tx: PassFatPointerAndRead<&[u8]>,
+		block_context: PassFatPointerAndDecode<BlockContext>,
+		runtime_version: u32,
+	) -> AllocateAndReturnByCodec<
+		Result<PoolValidatedTransaction, hard_fork_test::types::LedgerApiError>,
+	> {
+		bridge!(
+			hard_fork_test,
+			Some(&mut **self),
+			validate_pool_transaction(*self, state_key, tx, block_context, runtime_version)
 		)
 	}
 
@@ -369,19 +477,14 @@ pub trait LedgerBridgeHf {
 		state_key: PassFatPointerAndRead<&[u8]>,
 		contract_address: PassFatPointerAndRead<&[u8]>,
 	) -> AllocateAndReturnByCodec<Result<Vec<u8>, hard_fork_test::types::LedgerApiError>> {
-		hard_fork_test::Bridge::<SignatureHF, DatabaseHF>::get_contract_state(
-			state_key,
-			contract_address,
-		)
+		bridge!(hard_fork_test, Some(&mut **self), get_contract_state(state_key, contract_address))
 	}
 
 	// Hard-fork Version
 	fn get_decoded_transaction(
 		transaction_bytes: PassFatPointerAndRead<&[u8]>,
 	) -> AllocateAndReturnByCodec<Result<Tx, hard_fork_test::types::LedgerApiError>> {
-		hard_fork_test::Bridge::<SignatureHF, DatabaseHF>::get_decoded_transaction(
-			transaction_bytes,
-		)
+		bridge!(hard_fork_test, None, get_decoded_transaction(transaction_bytes))
 	}
 
 	// Hard-fork Version
@@ -390,9 +493,10 @@ pub trait LedgerBridgeHf {
 		state_key: PassFatPointerAndRead<&[u8]>,
 		contract_address: PassFatPointerAndRead<&[u8]>,
 	) -> AllocateAndReturnByCodec<Result<Vec<u8>, hard_fork_test::types::LedgerApiError>> {
-		hard_fork_test::Bridge::<SignatureHF, DatabaseHF>::get_zswap_chain_state(
-			state_key,
-			contract_address,
+		bridge!(
+			hard_fork_test,
+			Some(&mut **self),
+			get_zswap_chain_state(state_key, contract_address)
 		)
 	}
 
@@ -403,17 +507,17 @@ pub trait LedgerBridgeHf {
 		action: u8,
 		nonce: PassFatPointerAndDecode<[u8; 32]>,
 	) -> AllocateAndReturnByCodec<Result<Vec<u8>, hard_fork_test::types::LedgerApiError>> {
-		hard_fork_test::Bridge::<SignatureHF, DatabaseHF>::construct_cnight_generates_dust_event(
-			value, owner, time, action, nonce,
+		bridge!(
+			hard_fork_test,
+			None,
+			construct_cnight_generates_dust_event(value, owner, time, action, nonce)
 		)
 	}
 
 	fn construct_cnight_generates_dust_system_tx(
 		events: PassFatPointerAndDecode<Vec<Vec<u8>>>,
 	) -> AllocateAndReturnByCodec<Result<Vec<u8>, hard_fork_test::types::LedgerApiError>> {
-		hard_fork_test::Bridge::<SignatureHF, DatabaseHF>::construct_cnight_generates_dust_system_tx(
-			events,
-		)
+		bridge!(hard_fork_test, None, construct_cnight_generates_dust_system_tx(events))
 	}
 
 	// Hard-fork Version
@@ -424,11 +528,10 @@ pub trait LedgerBridgeHf {
 		receiver: PassFatPointerAndRead<&[u8]>,
 		block_context: PassFatPointerAndDecode<BlockContext>,
 	) -> AllocateAndReturnByCodec<Result<Vec<u8>, hard_fork_test::types::LedgerApiError>> {
-		hard_fork_test::Bridge::<SignatureHF, DatabaseHF>::mint_coins(
-			state_key,
-			amount,
-			receiver,
-			block_context,
+		bridge!(
+			hard_fork_test,
+			Some(&mut **self),
+			mint_coins(state_key, amount, receiver, block_context)
 		)
 	}
 
@@ -438,10 +541,7 @@ pub trait LedgerBridgeHf {
 		state_key: PassFatPointerAndRead<&[u8]>,
 		beneficiary: PassFatPointerAndRead<&[u8]>,
 	) -> AllocateAndReturnByCodec<Result<u128, hard_fork_test::types::LedgerApiError>> {
-		hard_fork_test::Bridge::<SignatureHF, DatabaseHF>::get_unclaimed_amount(
-			state_key,
-			beneficiary,
-		)
+		bridge!(hard_fork_test, Some(&mut **self), get_unclaimed_amount(state_key, beneficiary))
 	}
 
 	// Hard-fork Version
@@ -449,7 +549,7 @@ pub trait LedgerBridgeHf {
 		&mut self,
 		state_key: PassFatPointerAndRead<&[u8]>,
 	) -> AllocateAndReturnByCodec<Result<Vec<u8>, hard_fork_test::types::LedgerApiError>> {
-		hard_fork_test::Bridge::<SignatureHF, DatabaseHF>::get_ledger_parameters(state_key)
+		bridge!(hard_fork_test, Some(&mut **self), get_ledger_parameters(state_key))
 	}
 
 	// Hard-fork Version
@@ -461,18 +561,46 @@ pub trait LedgerBridgeHf {
 	) -> AllocateAndReturnByCodec<
 		Result<(StorageCost, GasCost), hard_fork_test::types::LedgerApiError>,
 	> {
-		hard_fork_test::Bridge::<SignatureHF, DatabaseHF>::get_transaction_cost(
-			state_key,
-			tx,
-			&block_context,
+		bridge!(
+			hard_fork_test,
+			Some(&mut **self),
+			get_transaction_cost(state_key, tx, &block_context)
 		)
 	}
 
+	// Hard-fork Version
+	fn get_transaction_fees(
+		&mut self,
+		state_key: PassFatPointerAndRead<&[u8]>,
+		tx: PassFatPointerAndRead<&[u8]>,
+	) -> AllocateAndReturnByCodec<Result<TransactionFees, hard_fork_test::types::LedgerApiError>> {
+		bridge!(hard_fork_test, Some(&mut **self), get_transaction_fees(state_key, tx))
+	}
+
+	// Hard-fork Version
+	fn get_transaction_tags(
+		&mut self,
+		state_key: PassFatPointerAndRead<&[u8]>,
+		tx: PassFatPointerAndRead<&[u8]>,
+	) -> AllocateAndReturnByCodec<Result<TransactionTags, hard_fork_test::types::LedgerApiError>> {
+		bridge!(hard_fork_test, Some(&mut **self), get_transaction_tags(*self, state_key, tx))
+	}
+
 	// Hard-fork Version
 	fn get_zswap_state_root(
 		&mut self,
 		state_key: PassFatPointerAndRead<&[u8]>,
 	) -> AllocateAndReturnByCodec<Result<Vec<u8>, hard_fork_test::types::LedgerApiError>> {
-		hard_fork_test::Bridge::<SignatureHF, DatabaseHF>::get_zswap_state_root(state_key)
+		bridge!(hard_fork_test, Some(&mut **self), get_zswap_state_root(state_key))
+	}
+
+	// Hard-fork Version
+	#[cfg(feature = "runtime-benchmarks")]
+	fn get_transaction_with_context(
+		bytes: PassFatPointerAndRead<&[u8]>,
+	) -> AllocateAndReturnByCodec<
+		Result<(Vec<u8>, BlockContext), hard_fork_test::types::LedgerApiError>,
+	> {
+		bridge!(hard_fork_test, None, get_transaction_with_context(bytes))
 	}
 }
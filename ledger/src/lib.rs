@@ -25,6 +25,8 @@ pub mod json;
 #[cfg(feature = "std")]
 mod storage;
 #[cfg(feature = "std")]
+pub mod telemetry;
+#[cfg(feature = "std")]
 mod utils;
 
 #[cfg(feature = "std")]
@@ -14,11 +14,16 @@
 #[cfg(feature = "std")]
 use {
 	ledger_storage::db::DB,
+	midnight_primitives_ledger::LedgerStorageBackend,
 	midnight_serialize::{self, Tagged},
 	mn_ledger::structure::{ProofMarker, SignatureKind, Transaction},
+	std::sync::OnceLock,
 	transient_crypto::commitment::PureGeneratorPedersen,
 };
 
+#[cfg(feature = "std")]
+static STORAGE_BACKEND: OnceLock<LedgerStorageBackend> = OnceLock::new();
+
 pub fn get_root(state: &[u8]) -> Vec<u8> {
 	// Get empty state key
 	use super::latest::api::Ledger;
@@ -59,22 +64,128 @@ pub fn init_storage_paritydb(
 	dir: &std::path::Path,
 	genesis_state: &[u8],
 	cache_size: usize,
+) -> Vec<u8> {
+	init_storage(LedgerStorageBackend::ParityDb, dir, genesis_state, cache_size)
+}
+
+/// Sets the default storage of the latest ledger to the given backend, and allocates the genesis
+/// state in it. `dir` is only used by the ParityDB backend.
+#[cfg(feature = "std")]
+pub fn init_storage(
+	backend: LedgerStorageBackend,
+	dir: &std::path::Path,
+	genesis_state: &[u8],
+	cache_size: usize,
 ) -> Vec<u8> {
 	use base_crypto::signatures::Signature;
-	use ledger_storage::{Storage, db::ParityDb, storage::set_default_storage};
+	use ledger_storage::{
+		Storage,
+		db::{InMemoryDB, ParityDb},
+		storage::set_default_storage,
+	};
+
+	if STORAGE_BACKEND.set(backend).is_err() && storage_backend() != backend {
+		log::warn!("Warning: Ledger storage already initialized with {:?}", storage_backend());
+	}
+
+	match backend {
+		LedgerStorageBackend::ParityDb => {
+			let res = set_default_storage(|| {
+				std::fs::create_dir_all(dir)
+					.unwrap_or_else(|_| panic!("Failed to create dir {}", dir.display()));
 
-	let res = set_default_storage(|| {
-		std::fs::create_dir_all(dir)
-			.unwrap_or_else(|_| panic!("Failed to create dir {}", dir.display()));
+				let db = ParityDb::<sha2::Sha256>::open(dir);
+				Storage::new(cache_size, db)
+			});
+			if res.is_err() {
+				log::warn!("Warning: Failed to set default storage: {res:?}");
+			}
 
-		let db = ParityDb::<sha2::Sha256>::open(dir);
-		Storage::new(cache_size, db)
-	});
-	if res.is_err() {
-		log::warn!("Warning: Failed to set default storage: {res:?}");
+			alloc_with_initial_state::<Signature, ParityDb>(genesis_state)
+		},
+		LedgerStorageBackend::InMemory => {
+			let res = set_default_storage(|| Storage::new(cache_size, InMemoryDB::default()));
+			if res.is_err() {
+				log::warn!("Warning: Failed to set default storage: {res:?}");
+			}
+
+			alloc_with_initial_state::<Signature, InMemoryDB>(genesis_state)
+		},
 	}
+}
 
-	alloc_with_initial_state::<Signature, ParityDb>(genesis_state)
+/// Backend the ledger storage was initialized with by [`init_storage`], ParityDB otherwise
+#[cfg(feature = "std")]
+pub fn storage_backend() -> LedgerStorageBackend {
+	STORAGE_BACKEND.get().copied().unwrap_or_default()
+}
+
+/// Why [`check_state_complete`] couldn't confirm that a ledger state is present
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum StateCheckError {
+	/// The state key isn't a root of the latest ledger version: it is malformed, or belongs to
+	/// another ledger version. Says nothing about the ledger database
+	InvalidKey(std::io::Error),
+	/// The root node of the state is missing from the ledger database
+	MissingRoot(std::io::Error),
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Display for StateCheckError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::InvalidKey(e) => write!(f, "not a ledger state key of the latest version: {e}"),
+			Self::MissingRoot(e) => write!(f, "ledger state root is missing: {e}"),
+		}
+	}
+}
+
+/// Checks that the ledger state with root `state_key` is present in the default storage.
+///
+/// Only the root node is loaded, so the check doesn't depend on the size of the state. Pending
+/// changes are flushed to the database in a single atomic batch, after the nodes they reference,
+/// so a root that can be loaded implies that the nodes reachable from it were flushed too.
+#[cfg(feature = "std")]
+pub fn check_state_complete(state_key: &[u8]) -> Result<(), StateCheckError> {
+	use ledger_storage::db::{InMemoryDB, ParityDb};
+
+	match storage_backend() {
+		LedgerStorageBackend::ParityDb => check_state_complete_in::<ParityDb>(state_key),
+		LedgerStorageBackend::InMemory => check_state_complete_in::<InMemoryDB>(state_key),
+	}
+}
+
+#[cfg(feature = "std")]
+fn check_state_complete_in<D: DB>(state_key: &[u8]) -> Result<(), StateCheckError> {
+	use super::latest::api::Ledger;
+	use ledger_storage::{arena::TypedArenaKey, storage::default_storage};
+
+	let key: TypedArenaKey<Ledger<D>, D::Hasher> =
+		midnight_serialize::tagged_deserialize(state_key).map_err(StateCheckError::InvalidKey)?;
+	// `get_lazy` only loads the root node, its children are loaded on access
+	default_storage::<D>()
+		.arena
+		.get_lazy(&key)
+		.map(|_| ())
+		.map_err(StateCheckError::MissingRoot)
+}
+
+/// Flushes pending ledger changes in the default storage to its database
+#[cfg(feature = "std")]
+pub fn flush_storage() {
+	use ledger_storage::{
+		db::{InMemoryDB, ParityDb},
+		storage::default_storage,
+	};
+
+	match storage_backend() {
+		LedgerStorageBackend::ParityDb => {
+			default_storage::<ParityDb>().with_backend(|backend| backend.flush_all_changes_to_db())
+		},
+		LedgerStorageBackend::InMemory => default_storage::<InMemoryDB>()
+			.with_backend(|backend| backend.flush_all_changes_to_db()),
+	}
 }
 
 #[cfg(test)]
@@ -123,4 +234,26 @@ mod tests {
 
 		assert_ok!(res);
 	}
+
+	#[test]
+	fn check_state_complete_tells_missing_roots_from_invalid_keys() {
+		use super::{StateCheckError, check_state_complete, init_storage};
+		use midnight_node_res::networks::{MidnightNetwork, UndeployedNetwork};
+		use midnight_primitives_ledger::LedgerStorageBackend;
+
+		let state_key = init_storage(
+			LedgerStorageBackend::InMemory,
+			std::path::Path::new(""),
+			UndeployedNetwork.genesis_state(),
+			1024,
+		);
+		assert_ok!(check_state_complete(&state_key));
+
+		// A well-formed key of a root that was never stored
+		let mut missing_key = state_key.clone();
+		*missing_key.last_mut().unwrap() ^= 0xff;
+		assert!(matches!(check_state_complete(&missing_key), Err(StateCheckError::MissingRoot(_))));
+
+		assert!(matches!(check_state_complete(&[1, 2, 3]), Err(StateCheckError::InvalidKey(_))));
+	}
 }
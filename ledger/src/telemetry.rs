@@ -0,0 +1,41 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! OpenTelemetry spans around ledger execution in the host functions.
+//!
+//! Spans are sent to the global tracer provider, which does nothing unless the node installed an
+//! exporter. Host functions run synchronously on the thread executing the runtime, so their spans
+//! are children of whatever span is current there, e.g. the node's block import span.
+
+use opentelemetry::{
+	ContextGuard, KeyValue, Value, global,
+	trace::{Status, Tracer as _, get_active_span, mark_span_as_active},
+};
+
+/// Name of the tracer used for all node and ledger spans
+pub const TRACER_NAME: &str = "midnight-node";
+
+/// Starts a span as a child of the current one, and makes it current until the guard is dropped
+pub fn span(name: &'static str) -> ContextGuard {
+	mark_span_as_active(global::tracer(TRACER_NAME).start(name))
+}
+
+/// Sets an attribute on the current span
+pub fn set_attribute(key: &'static str, value: impl Into<Value>) {
+	get_active_span(|span| span.set_attribute(KeyValue::new(key, value)));
+}
+
+/// Marks the current span as failed
+pub fn set_error(error: &impl core::fmt::Display) {
+	get_active_span(|span| span.set_status(Status::error(error.to_string())));
+}
@@ -38,6 +38,8 @@ use std::{borrow::Borrow, collections::HashMap};
 use transient_crypto_local::merkle_tree::MerkleTreeDigest;
 use zswap_local::ledger::State as ZswapLedgerState;
 
+use crate::telemetry;
+
 use super::{
 	Api, ContractAddress, ContractState, DeserializableError, LOG_TARGET, SerializableError,
 	SystemTransaction, Transaction, TransactionInvalid, UserAddress, ZswapState,
@@ -115,6 +117,11 @@ impl<D: DB> Ledger<D> {
 		state
 	}
 
+	/// Number of commitments inserted in the zswap commitment tree
+	pub(crate) fn zswap_tree_size(&self) -> u64 {
+		self.state.zswap.first_free
+	}
+
 	pub(crate) fn get_zswap_state_root(&self) -> MerkleTreeDigest {
 		let state = Self::get_zswap_state(self, None);
 		// TODO: is this rehash necessary?
@@ -138,13 +145,15 @@ impl<D: DB> Ledger<D> {
 		let tx_cost =
 			tx.0.cost(&sp.state.parameters, true)
 				.map_err(|_| LedgerApiError::FeeCalculationError)?;
-		let valid_tx =
+		let valid_tx = {
+			let _span = telemetry::span("ledger.verify_transaction");
 			tx.0.well_formed(
 				&ctx.ref_state,
 				mn_ledger_local::verify::WellFormedStrictness::default(),
 				ctx.block_context.tblock,
 			)
-			.map_err(|e| LedgerApiError::Transaction(TransactionError::Malformed(e.into())))?;
+			.map_err(|e| LedgerApiError::Transaction(TransactionError::Malformed(e.into())))?
+		};
 		let (next_state, result) = sp.state.apply(&valid_tx, ctx);
 		let next_block_fullness = tx_cost + sp.block_fullness.clone().into();
 		let new_sp = default_storage::<D>()
@@ -18,9 +18,9 @@ use super::{
 	mn_ledger_local, transient_crypto_local,
 };
 
-use base_crypto_local::{hash::HashOutput, time::Timestamp};
+use base_crypto_local::{cost_model::SyntheticCost, hash::HashOutput, time::Timestamp};
 use ledger_storage_local::db::DB;
-use midnight_serialize_local::{Deserializable, Tagged};
+use midnight_serialize_local::{Deserializable, Serializable, Tagged};
 use transient_crypto_local::commitment::PureGeneratorPedersen;
 
 use coin_structure_local::coin::{UnshieldedTokenType, UserAddress};
@@ -40,7 +40,8 @@ use super::{
 	types::{DeserializationError, LedgerApiError, SerializationError, TransactionError},
 };
 use crate::{
-	common::types::{BlockContext, Hash, SegmentId, UtxoInfo},
+	common::types::{BlockContext, Hash, SegmentId, TransactionTags, UtxoInfo},
+	telemetry,
 	types::PERSISTENT_HASH_BYTES,
 };
 
@@ -121,6 +122,16 @@ fn from_utxo_spend(spend: UtxoSpend) -> UtxoInfo {
 	}
 }
 
+fn utxo_from_info(utxo_info: &UtxoInfo) -> Utxo {
+	Utxo {
+		value: utxo_info.value,
+		owner: UserAddress(HashOutput(utxo_info.address)),
+		type_: UnshieldedTokenType(HashOutput(utxo_info.token_type)),
+		intent_hash: IntentHash(HashOutput(utxo_info.intent_hash)),
+		output_no: utxo_info.output_no,
+	}
+}
+
 #[derive(Default, Debug)]
 pub struct UnshieldedUtxos {
 	pub outputs: HashMap<SegmentId, Vec<UtxoInfo>>,
@@ -163,13 +174,7 @@ impl UnshieldedUtxos {
 	) -> Result<(Vec<UtxoInfo>, Vec<UtxoInfo>), LedgerApiError> {
 		// Check returned utxo outputs exist in the state
 		for utxo_info in self.outputs.values().flatten() {
-			let utxo = Utxo {
-				value: utxo_info.value,
-				owner: UserAddress(HashOutput(utxo_info.address)),
-				type_: UnshieldedTokenType(HashOutput(utxo_info.token_type)),
-				intent_hash: IntentHash(HashOutput(utxo_info.intent_hash)),
-				output_no: utxo_info.output_no,
-			};
+			let utxo = utxo_from_info(utxo_info);
 
 			if !state.state.utxo.utxos.contains_key(&utxo) {
 				log::error!(target: LOG_TARGET, "Returned UTXO output {utxo:?} should be present in the Ledger state");
@@ -179,13 +184,7 @@ impl UnshieldedUtxos {
 
 		// Check returned utxo inputs do not exist in the state anymore
 		for utxo_info in self.inputs.values().flatten() {
-			let utxo = Utxo {
-				value: utxo_info.value,
-				owner: UserAddress(HashOutput(utxo_info.address)),
-				type_: UnshieldedTokenType(HashOutput(utxo_info.token_type)),
-				intent_hash: IntentHash(HashOutput(utxo_info.intent_hash)),
-				output_no: utxo_info.output_no,
-			};
+			let utxo = utxo_from_info(utxo_info);
 
 			if state.state.utxo.utxos.contains_key(&utxo) {
 				log::error!(target: LOG_TARGET, "Returned UTXO input {utxo:?} should NOT be present in the Ledger state");
@@ -224,6 +223,7 @@ impl<S: SignatureKind<D>, D: DB> Transaction<S, D> {
 		ledger: &Ledger<D>,
 		block_context: &BlockContext,
 	) -> Result<(), LedgerApiError> {
+		let _span = telemetry::span("ledger.verify_transaction");
 		self.0
 			.well_formed(
 				<Ledger<D> as Borrow<LedgerState<D>>>::borrow(ledger),
@@ -298,7 +298,6 @@ impl<S: SignatureKind<D>, D: DB> Transaction<S, D> {
 		}
 	}
 
-	#[allow(dead_code)]
 	pub(crate) fn fee(&self, params: &LedgerParameters) -> Result<u128, LedgerApiError> {
 		self.0.fees(params, false).map_err(|e| {
 			log::error!(target: LOG_TARGET, "Error getting the transaction fee: {e:?}");
@@ -308,6 +307,32 @@ impl<S: SignatureKind<D>, D: DB> Transaction<S, D> {
 		})
 	}
 
+	/// Cost of the transaction according to the ledger cost model
+	pub(crate) fn cost(&self, params: &LedgerParameters) -> Result<SyntheticCost, LedgerApiError> {
+		self.0.cost(params, false).map_err(|e| {
+			log::error!(target: LOG_TARGET, "Error getting the transaction cost: {e:?}");
+			LedgerApiError::FeeCalculationError
+		})
+	}
+
+	/// Sum of the DUST spent on fees by all intents of the transaction
+	pub(crate) fn dust_fees_paid(&self) -> u128 {
+		match &self.0 {
+			Tx::Standard(tx) => {
+				let mut paid = 0u128;
+				for intent in tx.intents.values() {
+					if let Some(dust_actions) = &intent.dust_actions {
+						for spend in dust_actions.spends.iter() {
+							paid = paid.saturating_add(spend.v_fee);
+						}
+					}
+				}
+				paid
+			},
+			_ => 0,
+		}
+	}
+
 	pub(crate) fn unshielded_utxos(&self) -> UnshieldedUtxos {
 		let mut outputs: HashMap<u16, Vec<UtxoInfo>> = HashMap::new();
 		let mut inputs: HashMap<u16, Vec<UtxoInfo>> = HashMap::new();
@@ -372,6 +397,51 @@ impl<S: SignatureKind<D>, D: DB> Transaction<S, D> {
 		utxos.unwrap_or_default()
 	}
 
+	/// Transaction pool tags: the transaction provides the UTXOs it creates and spends, its zswap
+	/// nullifiers and commitments and its DUST nullifiers, and requires the UTXOs it spends that
+	/// aren't in the ledger state yet
+	pub(crate) fn tags(&self, ledger: &Ledger<D>) -> TransactionTags {
+		let mut tags = TransactionTags::default();
+
+		let utxos = self.unshielded_utxos();
+		for utxo_info in utxos.outputs.values().flatten() {
+			tags.provides
+				.push(TransactionTags::utxo(&utxo_info.intent_hash, utxo_info.output_no));
+		}
+		for utxo_info in utxos.inputs.values().flatten() {
+			tags.provides
+				.push(TransactionTags::utxo_spend(&utxo_info.intent_hash, utxo_info.output_no));
+			if !ledger.state.utxo.utxos.contains_key(&utxo_from_info(utxo_info)) {
+				tags.requires
+					.push(TransactionTags::utxo(&utxo_info.intent_hash, utxo_info.output_no));
+			}
+		}
+
+		if let Tx::Standard(tx) = &self.0 {
+			for input in tx.guaranteed_inputs().chain(tx.fallible_inputs()) {
+				tags.provides.push(TransactionTags::nullifier(&input.nullifier.0.0));
+			}
+			for output in tx.guaranteed_outputs().chain(tx.fallible_outputs()) {
+				tags.provides.push(TransactionTags::commitment(&output.coin_com.0.0));
+			}
+			for transient in tx.guaranteed_transients().chain(tx.fallible_transients()) {
+				tags.provides.push(TransactionTags::nullifier(&transient.nullifier.0.0));
+				tags.provides.push(TransactionTags::commitment(&transient.coin_com.0.0));
+			}
+			for intent in tx.intents.values() {
+				let Some(dust_actions) = &intent.dust_actions else { continue };
+				for spend in dust_actions.spends.iter() {
+					let mut nullifier = Vec::new();
+					if spend.old_nullifier.serialize(&mut nullifier).is_ok() {
+						tags.provides.push(TransactionTags::dust_nullifier(&nullifier));
+					}
+				}
+			}
+		}
+
+		tags
+	}
+
 	pub(crate) fn utxos_info_from_output(
 		outputs: Vec<UtxoOutput>,
 		intent_hash: [u8; PERSISTENT_HASH_BYTES],
@@ -499,5 +569,39 @@ mod tests {
 		assert!(fee.unwrap() > 0);
 		assert!(parameters.c_to_m_bridge_min_amount > 0);
 	}
+
+	#[test]
+	fn should_get_dust_fees_paid() {
+		if CRATE_NAME != crate::latest::CRATE_NAME {
+			println!("This test should only be run with ledger latest");
+			return;
+		}
+		let api = api::new();
+		let ledger = prepare_ledger();
+		let (tx, _block_context) = prepare_transaction(&api, DEPLOY);
+
+		let required = tx.fee(&ledger.get_parameters()).unwrap();
+
+		assert!(tx.dust_fees_paid() >= required);
+	}
+
+	#[test]
+	fn should_get_tags() {
+		if CRATE_NAME != crate::latest::CRATE_NAME {
+			println!("This test should only be run with ledger latest");
+			return;
+		}
+		let api = api::new();
+		let ledger = prepare_ledger();
+		let (tx, _block_context) = prepare_transaction(&api, DEPLOY);
+
+		let tags = tx.tags(&ledger);
+		let set: std::collections::BTreeSet<&Vec<u8>> = tags.provides.iter().collect();
+
+		assert!(!tags.provides.is_empty());
+		assert_eq!(set.len(), tags.provides.len(), "provided tags are not unique");
+		// Everything the transaction spends is already in the genesis state
+		assert!(tags.requires.is_empty());
+	}
 }
 // grcov-excl-stop
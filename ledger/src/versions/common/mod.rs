@@ -38,6 +38,7 @@ pub mod conversions;
 
 #[cfg(feature = "std")]
 use {
+	crate::telemetry,
 	api::{
 		ContractAddress, ContractState, Ledger, LedgerParameters, SystemTransaction, Transaction,
 		TransactionAppliedStage, TransactionOperation,
@@ -49,10 +50,12 @@ use {
 	ledger_storage_local::{
 		Storage,
 		arena::{ArenaKey, Sp, TypedArenaKey},
-		db::{DB, ParityDb},
+		db::{DB, InMemoryDB, ParityDb},
 		storage::{Map, default_storage, set_default_storage},
 	},
-	midnight_primitives_ledger::{LedgerMetricsExt, LedgerStorageExt},
+	midnight_primitives_ledger::{
+		LedgerMetricsExt, LedgerStorage, LedgerStorageBackend, LedgerStorageExt,
+	},
 	mn_ledger_local::{
 		dust::InitialNonce,
 		semantics::TransactionContext,
@@ -70,8 +73,9 @@ use {
 
 use crate::common::types::{
 	BlockContext, ContractCallsDetails, FallibleCoinsDetails, GasCost, GuaranteedCoinsDetails,
-	Hash, Op, StorageCost, SystemTransactionAppliedStateRoot, TransactionAppliedStateRoot,
-	TransactionDetails, TransactionValidationWasCached, Tx, WrappedHash,
+	Hash, Op, PoolValidatedTransaction, StorageCost, SystemTransactionAppliedStateRoot,
+	TransactionAppliedStateRoot, TransactionDetails, TransactionFees, TransactionTags,
+	TransactionValidationWasCached, Tx, WrappedHash,
 };
 
 #[cfg(feature = "std")]
@@ -98,12 +102,20 @@ where
 	pub fn set_default_storage(mut externalities: &mut dyn Externalities) {
 		let maybe_storage = externalities.extension::<LedgerStorageExt>();
 		if let Some(storage) = maybe_storage {
-			let res = set_default_storage(|| {
-				let db = ParityDb::<sha2::Sha256>::open(storage.0.db_path.as_path());
-				Storage::new(storage.0.cache_size, db)
-			});
-			if res.is_err() {
-				log::warn!("Warning: Failed to set default storage: {res:?}");
+			let LedgerStorage { db_path, cache_size, backend } = storage.0.clone();
+			let res = match backend {
+				LedgerStorageBackend::ParityDb => set_default_storage(|| {
+					let db = ParityDb::<sha2::Sha256>::open(db_path.as_path());
+					Storage::new(cache_size, db)
+				})
+				.map_err(|e| format!("{e:?}")),
+				LedgerStorageBackend::InMemory => {
+					set_default_storage(|| Storage::new(cache_size, InMemoryDB::default()))
+						.map_err(|e| format!("{e:?}"))
+				},
+			};
+			if let Err(e) = res {
+				log::warn!("Warning: Failed to set default storage: {e}");
 			}
 		} else {
 			log::error!(
@@ -133,6 +145,7 @@ where
 	}
 
 	pub fn flush_storage(mut externalities: &mut dyn Externalities) {
+		let _span = telemetry::span("ledger.flush_storage");
 		let now = std::time::Instant::now();
 		default_storage::<D>().with_backend(|backend| backend.flush_all_changes_to_db());
 		let elapsed = now.elapsed().as_secs_f64();
@@ -144,10 +157,11 @@ where
 	}
 
 	pub fn post_block_update(
-		mut _externalities: &mut dyn Externalities,
+		mut externalities: &mut dyn Externalities,
 		state_key: &[u8],
 		block_context: BlockContext,
 	) -> Result<Vec<u8>, LedgerApiError> {
+		let _span = telemetry::span("ledger.post_block_update");
 		let api = api::new();
 		let ledger = Self::get_ledger(&api, state_key)?;
 
@@ -160,10 +174,16 @@ where
 		})?;
 
 		let state_root = api.tagged_serialize(&ledger.hash())?;
+		let zswap_tree_size = ledger.zswap_tree_size();
 
 		// Only update state after no errors
 		ledger.persist();
 
+		let maybe_metrics = externalities.extension::<LedgerMetricsExt>();
+		if let Some(metrics) = maybe_metrics {
+			metrics.observe_zswap_tree_size(zswap_tree_size);
+		}
+
 		Ok(state_root)
 	}
 
@@ -172,6 +192,17 @@ where
 	}
 
 	pub fn apply_transaction(
+		externalities: &mut dyn Externalities,
+		state_key: &[u8],
+		tx_serialized: &[u8],
+		block_context: BlockContext,
+	) -> Result<TransactionAppliedStateRoot, LedgerApiError> {
+		let _span = telemetry::span("ledger.apply_transaction");
+		Self::do_apply_transaction(externalities, state_key, tx_serialized, block_context)
+			.inspect_err(telemetry::set_error)
+	}
+
+	fn do_apply_transaction(
 		mut externalities: &mut dyn Externalities,
 		state_key: &[u8],
 		tx_serialized: &[u8],
@@ -188,9 +219,14 @@ where
 			"⚙️  Processing Tx {tx:?}"
 		);
 		let tx_hash = tx.hash();
+		telemetry::set_attribute("tx_hash", hex::encode(tx_hash));
+		telemetry::set_attribute("tx_type", Self::get_tx_type(&tx));
 		let ledger = Self::get_ledger(&api, state_key)?;
 		let initial_utxos_size = ledger.state.utxo.utxos.size();
 
+		// Costs are computed against the state before the transaction is applied
+		let (storage_cost, gas_cost) = Self::transaction_cost(&tx, &ledger)?;
+
 		let tx_ctx = ledger.get_transaction_context(block_context.clone());
 		let (ledger, applied_stage) = Ledger::apply_transaction(ledger, &api, &tx, &tx_ctx)?;
 
@@ -216,6 +252,8 @@ where
 			claim_rewards: vec![],
 			unshielded_utxos_created: utxo_outputs,
 			unshielded_utxos_spent: utxo_inputs,
+			storage_cost,
+			gas_cost,
 		};
 
 		for op in tx.calls_and_deploys() {
@@ -261,6 +299,7 @@ where
 		let start_system_tx_processing_time = Instant::now();
 		let tx_size = tx_serialized.len();
 
+		let _span = telemetry::span("ledger.apply_system_transaction");
 		let api = api::new();
 		let tx = api.tagged_deserialize::<SystemTransaction>(tx_serialized)?;
 		let tx_type = Self::get_system_tx_type(&tx);
@@ -269,10 +308,13 @@ where
 			"⚙️  Processing SystemTx {tx:?}"
 		);
 		let tx_hash = tx.transaction_hash().0.0;
+		telemetry::set_attribute("tx_hash", hex::encode(tx_hash));
+		telemetry::set_attribute("tx_type", tx_type);
 		let ledger = Self::get_ledger(&api, state_key)?;
 
 		let ledger =
-			Ledger::apply_system_tx(ledger, &tx, Timestamp::from_secs(block_context.tblock))?;
+			Ledger::apply_system_tx(ledger, &tx, Timestamp::from_secs(block_context.tblock))
+				.inspect_err(telemetry::set_error)?;
 
 		let event = SystemTransactionAppliedStateRoot {
 			state_root: api.tagged_serialize(&ledger.hash())?,
@@ -296,7 +338,27 @@ where
 	}
 
 	pub fn validate_transaction(
-		mut externalities: &mut dyn Externalities,
+		externalities: &mut dyn Externalities,
+		state_key: &[u8],
+		tx_serialized: &[u8],
+		block_context: BlockContext,
+		runtime_version: u32,
+	) -> Result<(Hash, TransactionDetails), LedgerApiError> {
+		let result = Self::try_validate_transaction(
+			externalities,
+			state_key,
+			tx_serialized,
+			block_context,
+			runtime_version,
+		);
+		if let Err(e) = &result {
+			Self::observe_validation_rejected(externalities, e);
+		}
+		result
+	}
+
+	fn try_validate_transaction(
+		externalities: &mut dyn Externalities,
 		state_key: &[u8],
 		tx_serialized: &[u8],
 		block_context: BlockContext,
@@ -305,23 +367,106 @@ where
 		// Gather metrics for Prometheus
 		let start_tx_validation_time = Instant::now();
 
+		let _span = telemetry::span("ledger.validate_transaction");
 		let api = api::new();
 		let tx = api.tagged_deserialize::<Transaction<S, D>>(tx_serialized)?;
+		telemetry::set_attribute("tx_hash", hex::encode(tx.hash()));
+		telemetry::set_attribute("tx_type", Self::get_tx_type(&tx));
 		let ledger = Self::get_ledger(&api, state_key)?;
 
+		Self::validate_decoded_transaction(
+			externalities,
+			&ledger,
+			&tx,
+			tx_serialized,
+			&block_context,
+			runtime_version,
+			start_tx_validation_time,
+		)
+	}
+
+	/// Validates a transaction for the transaction pool, and returns its pool tags, fees and
+	/// costs. The transaction is only decoded once
+	pub fn validate_pool_transaction(
+		externalities: &mut dyn Externalities,
+		state_key: &[u8],
+		tx_serialized: &[u8],
+		block_context: BlockContext,
+		runtime_version: u32,
+	) -> Result<PoolValidatedTransaction, LedgerApiError> {
+		let result = Self::try_validate_pool_transaction(
+			externalities,
+			state_key,
+			tx_serialized,
+			block_context,
+			runtime_version,
+		);
+		if let Err(e) = &result {
+			Self::observe_validation_rejected(externalities, e);
+		}
+		result
+	}
+
+	fn try_validate_pool_transaction(
+		externalities: &mut dyn Externalities,
+		state_key: &[u8],
+		tx_serialized: &[u8],
+		block_context: BlockContext,
+		runtime_version: u32,
+	) -> Result<PoolValidatedTransaction, LedgerApiError> {
+		// Gather metrics for Prometheus
+		let start_tx_validation_time = Instant::now();
+
+		let _span = telemetry::span("ledger.validate_pool_transaction");
+		let api = api::new();
+		let tx = api.tagged_deserialize::<Transaction<S, D>>(tx_serialized)?;
+		telemetry::set_attribute("tx_hash", hex::encode(tx.hash()));
+		telemetry::set_attribute("tx_type", Self::get_tx_type(&tx));
+		let ledger = Self::get_ledger(&api, state_key)?;
+
+		let tags = tx.tags(&ledger);
+		let ledger_parameters = Self::get_deserialized_ledger_parameters(&ledger);
+		let fees =
+			TransactionFees { paid: tx.dust_fees_paid(), required: tx.fee(&ledger_parameters)? };
+		let (storage_cost, gas_cost) = Self::transaction_cost(&tx, &ledger)?;
+
+		// Verifying the proofs and signatures is the most expensive step, so it comes last
+		let (tx_hash, _) = Self::validate_decoded_transaction(
+			externalities,
+			&ledger,
+			&tx,
+			tx_serialized,
+			&block_context,
+			runtime_version,
+			start_tx_validation_time,
+		)?;
+
+		Ok(PoolValidatedTransaction { tx_hash, tags, fees, storage_cost, gas_cost })
+	}
+
+	fn validate_decoded_transaction(
+		mut externalities: &mut dyn Externalities,
+		ledger: &Ledger<D>,
+		tx: &Transaction<S, D>,
+		tx_serialized: &[u8],
+		block_context: &BlockContext,
+		runtime_version: u32,
+		start_tx_validation_time: Instant,
+	) -> Result<(Hash, TransactionDetails), LedgerApiError> {
 		let wrapped_cache_key = Self::tx_validation_cache_key(runtime_version, tx_serialized);
 
 		let was_cached =
-			Self::do_validate_transaction(&ledger, &tx, &block_context, &wrapped_cache_key)?;
+			Self::do_validate_transaction(ledger, tx, block_context, &wrapped_cache_key)
+				.inspect_err(telemetry::set_error)?;
 
-		let tx_details = Self::get_transaction_details(&tx, &ledger)?;
+		let tx_details = Self::get_transaction_details(tx, ledger)?;
 
 		// We only want to record the metric once
 		if let TransactionValidationWasCached::No = was_cached {
 			// Write Prometheus metrics
 			let maybe_metrics = externalities.extension::<LedgerMetricsExt>();
 			if let Some(metrics) = maybe_metrics {
-				let tx_type = Self::get_tx_type(&tx);
+				let tx_type = Self::get_tx_type(tx);
 				let elapsed_time = start_tx_validation_time.elapsed().as_secs_f64();
 
 				metrics.observe_txs_validating_time(elapsed_time, tx_type);
@@ -366,6 +511,21 @@ where
 		})
 	}
 
+	/// Splits a serialized `TransactionWithContext`, as generated by the toolkit, into the
+	/// serialized transaction and the block context it was generated for.
+	/// Only meant to set up runtime benchmarks.
+	#[cfg(feature = "runtime-benchmarks")]
+	pub fn get_transaction_with_context(
+		bytes: &[u8],
+	) -> Result<(Vec<u8>, BlockContext), LedgerApiError> {
+		let (tx, block_context) = helpers_local::try_extract_info_from_tx_with_context(bytes)
+			.map_err(|e| {
+				log::error!(target: LOG_TARGET, "Error extracting transaction with context: {e:?}");
+				LedgerApiError::Deserialization(api::DeserializationError::Transaction)
+			})?;
+		Ok((tx, block_context.into()))
+	}
+
 	fn do_get_contract_state<F>(
 		api: &api::Api,
 		state_key: &[u8],
@@ -453,14 +613,74 @@ where
 		api.tagged_serialize(&ledger_parameters)
 	}
 
-	// TODO COST MODEL: Needs to be redone with the new ledger cost model
-	#[allow(unused_variables)]
+	/// Returns the costs of a transaction according to the ledger cost model: the storage cost is
+	/// the number of bytes it writes to the ledger state, and the gas cost its estimated read and
+	/// compute time, in picoseconds
 	pub fn get_transaction_cost(
 		state_key: &[u8],
-		tx: &[u8],
-		block_context: &BlockContext,
+		tx_serialized: &[u8],
+		_block_context: &BlockContext,
 	) -> Result<(StorageCost, GasCost), LedgerApiError> {
-		Ok((0, 0))
+		let api = api::new();
+		let tx = api.tagged_deserialize::<Transaction<S, D>>(tx_serialized)?;
+		let ledger = Self::get_ledger(&api, state_key)?;
+
+		Self::transaction_cost(&tx, &ledger)
+	}
+
+	fn transaction_cost(
+		tx: &Transaction<S, D>,
+		ledger: &Ledger<D>,
+	) -> Result<(StorageCost, GasCost), LedgerApiError> {
+		let ledger_parameters = Self::get_deserialized_ledger_parameters(ledger);
+
+		let cost = tx.cost(&ledger_parameters)?;
+		let gas_cost = cost
+			.read_time
+			.into_picoseconds()
+			.saturating_add(cost.compute_time.into_picoseconds());
+
+		Ok((cost.bytes_written.into(), gas_cost))
+	}
+
+	pub fn get_transaction_fees(
+		state_key: &[u8],
+		tx_serialized: &[u8],
+	) -> Result<TransactionFees, LedgerApiError> {
+		let api = api::new();
+		let tx = api.tagged_deserialize::<Transaction<S, D>>(tx_serialized)?;
+		let ledger = Self::get_ledger(&api, state_key)?;
+		let ledger_parameters = Self::get_deserialized_ledger_parameters(&ledger);
+
+		Ok(TransactionFees { paid: tx.dust_fees_paid(), required: tx.fee(&ledger_parameters)? })
+	}
+
+	pub fn get_transaction_tags(
+		externalities: &mut dyn Externalities,
+		state_key: &[u8],
+		tx_serialized: &[u8],
+	) -> Result<TransactionTags, LedgerApiError> {
+		let api = api::new();
+		let tags = api.tagged_deserialize::<Transaction<S, D>>(tx_serialized).and_then(|tx| {
+			let ledger = Self::get_ledger(&api, state_key)?;
+			Ok(tx.tags(&ledger))
+		});
+		// The tags are computed before the transaction is validated, so malformed transactions
+		// are rejected here
+		if let Err(e) = &tags {
+			Self::observe_validation_rejected(externalities, e);
+		}
+		tags
+	}
+
+	/// Counts a transaction rejected by validation. Imported blocks only contain valid
+	/// transactions, so rejections come from the transaction pool and from block authoring. A
+	/// transaction is counted each time it's submitted or revalidated
+	fn observe_validation_rejected(mut externalities: &mut dyn Externalities, e: &LedgerApiError) {
+		let maybe_metrics = externalities.extension::<LedgerMetricsExt>();
+		if let Some(metrics) = maybe_metrics {
+			metrics.observe_tx_rejected("validation", e.kind());
+		}
 	}
 
 	// TODO COST MODEL: Needs to be redone with the new ledger cost model
@@ -674,3 +894,20 @@ fn create_nonce(separator: &[u8], block_hash: &[u8], output_number: u8) -> Nonce
 
 	Nonce(HashOutput(h256.0))
 }
+
+/// Actions (`create` or `destroy`) of the DUST generation events of a serialized system
+/// transaction. Other system transactions have none
+#[cfg(feature = "std")]
+pub fn dust_generation_actions(tx_serialized: &[u8]) -> Result<Vec<&'static str>, LedgerApiError> {
+	let tx = api::new().tagged_deserialize::<SystemTransaction>(tx_serialized)?;
+	let SystemTransaction::CNightGeneratesDustUpdate { events } = tx else {
+		return Ok(Vec::new());
+	};
+	Ok(events
+		.iter()
+		.map(|event| match event.action {
+			CNightGeneratesDustActionType::Create => "create",
+			CNightGeneratesDustActionType::Destroy => "destroy",
+		})
+		.collect())
+}
@@ -228,6 +228,28 @@ impl core::fmt::Display for LedgerApiError {
 	}
 }
 
+impl LedgerApiError {
+	/// Coarse kind of the error, used as a bounded metrics label
+	pub fn kind(&self) -> &'static str {
+		match self {
+			LedgerApiError::Deserialization(_) => "deserialization",
+			LedgerApiError::Serialization(_) => "serialization",
+			LedgerApiError::Transaction(TransactionError::Invalid(_)) => "invalid",
+			LedgerApiError::Transaction(TransactionError::Malformed(_)) => "malformed",
+			LedgerApiError::Transaction(TransactionError::SystemTransaction(_)) => {
+				"system_transaction"
+			},
+			LedgerApiError::LedgerCacheError => "ledger_cache",
+			LedgerApiError::NoLedgerState => "no_ledger_state",
+			LedgerApiError::LedgerStateScaleDecodingError => "ledger_state_decoding",
+			LedgerApiError::ContractCallCostError => "contract_call_cost",
+			LedgerApiError::BlockLimitExceededError => "block_limit_exceeded",
+			LedgerApiError::FeeCalculationError => "fee_calculation",
+			LedgerApiError::HostApiError => "host_api",
+		}
+	}
+}
+
 impl From<LedgerApiError> for u8 {
 	fn from(value: LedgerApiError) -> Self {
 		match value {
@@ -12,3 +12,7 @@ pub mod midnight_metadata_0_18_0 {}
 pub mod midnight_metadata_0_18_1 {}
 
 pub use midnight_metadata_0_18_1 as midnight_metadata_latest;
+
+/// SCALE-encoded metadata `midnight_metadata_latest` is generated from
+pub const MIDNIGHT_METADATA_LATEST: &[u8] =
+	include_bytes!("../static/midnight_metadata_0.18.1.scale");
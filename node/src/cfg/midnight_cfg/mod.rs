@@ -12,6 +12,7 @@
 // limitations under the License.
 
 use documented::{Documented, DocumentedFields as _};
+use midnight_primitives_ledger::LedgerStorageBackend;
 use serde::{Deserialize, Serialize};
 use serde_valid::{Validate, validation};
 use sidechain_domain::mainchain_epoch::MainchainEpochConfig;
@@ -83,6 +84,15 @@ pub struct MidnightCfg {
 
 	/// Size of ledger storage cache (number of nodes)
 	pub storage_cache_size: usize,
+
+	/// Database backend of the ledger storage: "parity-db" (under the base path) or "in-memory".
+	/// "in-memory" keeps nothing across restarts, so the node only accepts it with `--tmp` or
+	/// `--dev`, for tests and benchmarking
+	pub ledger_storage_backend: LedgerStorageBackend,
+
+	/// OTLP gRPC endpoint of an OpenTelemetry collector, e.g. "http://localhost:4317".
+	/// If set, block imports and ledger execution are traced and exported there
+	pub otlp_endpoint: Option<String>,
 }
 
 fn main_chain_follower_vars(cfg: &MidnightCfg) -> Result<(), validation::Error> {
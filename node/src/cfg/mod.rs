@@ -458,6 +458,27 @@ mod tests {
 		let _run_cmd: sc_cli::RunCmd = cfg.substrate_cfg.try_into().unwrap();
 	}
 
+	#[test]
+	fn sealing_arg_parses_from_args() {
+		use crate::cli::{RunMidnight, Sealing};
+
+		let sealing = |args: &[&str]| {
+			let substrate_cfg = SubstrateCfg {
+				args: args.iter().map(|a| a.to_string()).collect(),
+				..Default::default()
+			};
+			let run_midnight: RunMidnight = substrate_cfg.try_into().unwrap();
+			run_midnight.sealing
+		};
+
+		assert_eq!(sealing(&["--dev"]), None);
+		assert_eq!(sealing(&["--dev", "--sealing", "instant"]), Some(Sealing::Instant));
+		assert_eq!(sealing(&["--dev", "--sealing", "manual"]), Some(Sealing::Manual));
+		assert_eq!(sealing(&["--dev", "--sealing=interval=500"]), Some(Sealing::Interval(500)));
+		assert!("interval=0".parse::<Sealing>().is_err());
+		assert!("aura".parse::<Sealing>().is_err());
+	}
+
 	fn get_unused(preset_keys: &[String]) -> Vec<String> {
 		*midnight_node_res::CFG_ROOT.lock().unwrap() = Some("../".to_string());
 		let cfg_keys = [
@@ -12,6 +12,7 @@
 // limitations under the License.
 
 use super::{CfgHelp, HelpField, cfg_help, error::CfgError, util::get_keys};
+use crate::cli::RunMidnight;
 use clap::Parser;
 use documented::{Documented, DocumentedFields as _};
 use sc_cli::RunCmd;
@@ -61,10 +62,18 @@ impl CfgHelp for SubstrateCfg {
 impl TryFrom<SubstrateCfg> for RunCmd {
 	type Error = sc_cli::Error;
 
+	fn try_from(value: SubstrateCfg) -> Result<Self, Self::Error> {
+		RunMidnight::try_from(value).map(|run_midnight| run_midnight.run)
+	}
+}
+
+impl TryFrom<SubstrateCfg> for RunMidnight {
+	type Error = sc_cli::Error;
+
 	fn try_from(value: SubstrateCfg) -> Result<Self, Self::Error> {
 		let default_run_cmd = RunCmd::parse_from(&["midnight-node".to_string()]);
 
-		let mut run_cmd = RunCmd::parse_from(value.argv());
+		let RunMidnight { run: mut run_cmd, sealing } = RunMidnight::parse_from(value.argv());
 		if run_cmd.shared_params.base_path.is_none() && value.base_path.is_some() {
 			run_cmd.shared_params.base_path = value.base_path.map(|p| p.into());
 		}
@@ -99,6 +108,6 @@ impl TryFrom<SubstrateCfg> for RunCmd {
 		{
 			run_cmd.import_params.trie_cache_size = trie_cache_size;
 		}
-		Ok(run_cmd)
+		Ok(RunMidnight { run: run_cmd, sealing })
 	}
 }
@@ -26,7 +26,44 @@ use sidechain_domain::McBlockHash;
 #[derive(Debug, Clone, clap::Parser)]
 pub struct RunMidnight {
 	#[clap(flatten)]
-	run: sc_cli::RunCmd,
+	pub run: sc_cli::RunCmd,
+
+	/// Development mode: seal blocks with manual-seal instead of AURA/GRANDPA.
+	/// One of `instant` (a block per transaction), `manual` (blocks are only sealed by the
+	/// `engine_createBlock` RPC) or `interval=<ms>` (a block every <ms> milliseconds).
+	/// `engine_createBlock` is available in every mode.
+	/// Block timestamps are at least one slot apart, so sealing faster than once per slot moves the
+	/// chain time ahead of the wall clock by up to one slot per block
+	#[arg(long)]
+	pub sealing: Option<Sealing>,
+}
+
+/// How blocks are sealed in the development mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sealing {
+	/// Seal a block as soon as a transaction enters the pool
+	Instant,
+	/// Only seal blocks on `engine_createBlock` RPC calls
+	Manual,
+	/// Seal a block every given number of milliseconds
+	Interval(u64),
+}
+
+impl FromStr for Sealing {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"instant" => Ok(Self::Instant),
+			"manual" => Ok(Self::Manual),
+			_ => match s.strip_prefix("interval=").map(str::parse::<u64>) {
+				Some(Ok(millis)) if millis > 0 => Ok(Self::Interval(millis)),
+				_ => Err(format!(
+					"invalid sealing mode {s:?}: expected `instant`, `manual` or `interval=<ms>`"
+				)),
+			},
+		}
+	}
 }
 
 #[derive(Debug, clap::Parser)]
@@ -49,6 +86,13 @@ pub struct CNightGenesisCmd {
 	pub output: std::path::PathBuf,
 }
 
+#[derive(Debug, Parser)]
+pub struct DoctorCmd {
+	/// Don't connect to db-sync
+	#[arg(long)]
+	pub skip_db_sync: bool,
+}
+
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, clap::Subcommand)]
 pub enum Subcommand {
@@ -91,6 +135,9 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Check the configuration, chain spec, keys and db-sync connection, and print a report.
+	Doctor(DoctorCmd),
 }
 
 #[derive(Clone, Debug)]
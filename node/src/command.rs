@@ -15,7 +15,7 @@
 
 use crate::cfg::Cfg;
 use crate::{
-	cli::{self, Cli, Subcommand},
+	cli::{self, Cli, RunMidnight, Subcommand},
 	cnight_genesis::generate_cnight_genesis,
 	service::{self, StorageInit},
 };
@@ -23,7 +23,8 @@ use clap::Parser;
 use midnight_node_res::networks::MidnightNetwork as _;
 use midnight_node_runtime::Block;
 use midnight_primitives_cnight_observation::CNightAddresses;
-use sc_cli::{CliConfiguration, LoggerBuilder, RunCmd, SubstrateCli};
+use midnight_primitives_ledger::LedgerStorageBackend;
+use sc_cli::{CliConfiguration, LoggerBuilder, SubstrateCli};
 use sc_keystore::LocalKeystore;
 use sc_service::{BasePath, PartialComponents, config::KeystoreConfig};
 use sidechain_domain::mainchain_epoch::MainchainEpochConfig;
@@ -58,11 +59,13 @@ pub fn run() -> sc_cli::Result<()> {
 			// Only show current config settings for main command.
 			if !subcommand_used {
 				if std::env::args().any(|a| a == "--help") {
-					let _ =
-						RunCmd::try_parse_from(["midnight-node", "--help"]).unwrap_err().print();
+					let _ = RunMidnight::try_parse_from(["midnight-node", "--help"])
+						.unwrap_err()
+						.print();
 					Cfg::help();
 				} else {
-					let _ = RunCmd::try_parse_from(["midnight-node", "-h"]).unwrap_err().print();
+					let _ =
+						RunMidnight::try_parse_from(["midnight-node", "-h"]).unwrap_err().print();
 				}
 			}
 			let _ = e.print();
@@ -105,8 +108,36 @@ fn get_cfg(validate: bool) -> sc_cli::Result<Cfg> {
 	Ok(cfg)
 }
 
+/// Reads the secret seed in `seed_file` and derives the `name` key pair from it
+pub(crate) fn read_seed_file<P: Pair>(seed_file: &str, name: &str) -> sc_cli::Result<(P, String)> {
+	let seed = std::fs::read_to_string(seed_file).map_err(|e| {
+		sc_cli::Error::Input(format!(
+			"error when reading {name} seed file at {seed_file}. Error: {e}"
+		))
+	})?;
+	let seed = seed.trim().to_string();
+	let (keypair, _) = P::from_string_with_seed(&seed, None)
+		.map_err(|e| sc_cli::Error::Input(format!("Invalid {name} seed: {e}")))?;
+	Ok((keypair, seed))
+}
+
 fn run_node(cfg: Cfg) -> sc_cli::Result<()> {
-	let run_cmd: RunCmd = cfg.substrate_cfg.clone().try_into()?;
+	let RunMidnight { run: run_cmd, sealing } = cfg.substrate_cfg.clone().try_into()?;
+	if sealing.is_some() && !cfg.midnight_cfg.use_main_chain_follower_mock {
+		return Err(sc_cli::Error::Input(
+			"--sealing is a development mode and requires use_main_chain_follower_mock".to_string(),
+		));
+	}
+	if cfg.midnight_cfg.ledger_storage_backend == LedgerStorageBackend::InMemory
+		&& !run_cmd.tmp
+		&& !run_cmd.shared_params().is_dev()
+	{
+		return Err(sc_cli::Error::Input(
+			"the in-memory ledger storage backend loses the ledger state on restart, it requires \
+			 a chain that is wiped too: use it with --tmp or --dev"
+				.to_string(),
+		));
+	}
 	if cfg.midnight_cfg.wipe_chain_state
 		&& let Some(base_path) = run_cmd.base_path()?
 	{
@@ -126,8 +157,11 @@ fn run_node(cfg: Cfg) -> sc_cli::Result<()> {
 	let properties = chain_spec.properties();
 	let genesis_state_hex = properties.get("genesis_state").unwrap().as_str().unwrap();
 	let genesis_state = hex::decode(genesis_state_hex).unwrap();
-	let storage_config =
-		StorageInit { genesis_state, cache_size: cfg.midnight_cfg.storage_cache_size };
+	let storage_config = StorageInit {
+		genesis_state,
+		cache_size: cfg.midnight_cfg.storage_cache_size,
+		backend: cfg.midnight_cfg.ledger_storage_backend,
+	};
 
 	let keystore: KeystorePtr = {
 		let res = run_cmd.keystore_params().unwrap().keystore_config(&config_dir)?;
@@ -139,51 +173,37 @@ fn run_node(cfg: Cfg) -> sc_cli::Result<()> {
 	};
 
 	if let Some(seed_file) = &cfg.midnight_cfg.aura_seed_file {
-		let seed = std::fs::read_to_string(seed_file).map_err(|e| {
-			sc_cli::Error::Input(format!(
-				"error when reading AURA seed file at {seed_file}. Error: {e}"
-			))
-		})?;
-		let seed = seed.trim();
-		let (keypair, _) = sp_core::sr25519::Pair::from_string_with_seed(seed, None)
-			.map_err(|e| sc_cli::Error::Input(format!("Invalid AURA seed: {e}")))?;
+		let (keypair, seed) = read_seed_file::<sp_core::sr25519::Pair>(seed_file, "AURA")?;
 		keystore
-			.insert(KeyTypeId(*b"aura"), seed, &keypair.public().to_raw_vec())
+			.insert(KeyTypeId(*b"aura"), &seed, &keypair.public().to_raw_vec())
 			.unwrap();
 		log::info!("AURA pubkey: {}", &keypair.public())
 	}
 
 	if let Some(seed_file) = &cfg.midnight_cfg.grandpa_seed_file {
-		let seed = std::fs::read_to_string(seed_file).map_err(|e| {
-			sc_cli::Error::Input(format!(
-				"error when reading GRANDPA seed file at {seed_file}. Error: {e}"
-			))
-		})?;
-		let seed = seed.trim();
-		let (keypair, _) = sp_core::ed25519::Pair::from_string_with_seed(seed, None)
-			.map_err(|e| sc_cli::Error::Input(format!("Invalid GRANDPA seed: {e}")))?;
+		let (keypair, seed) = read_seed_file::<sp_core::ed25519::Pair>(seed_file, "GRANDPA")?;
 		keystore
-			.insert(KeyTypeId(*b"gran"), seed, &keypair.public().to_raw_vec())
+			.insert(KeyTypeId(*b"gran"), &seed, &keypair.public().to_raw_vec())
 			.unwrap();
 		log::info!("GRANDPA pubkey: {}", &keypair.public())
 	}
 
 	if let Some(seed_file) = &cfg.midnight_cfg.cross_chain_seed_file {
-		let seed = std::fs::read_to_string(seed_file).map_err(|e| {
-			sc_cli::Error::Input(format!(
-				"error when reading CROSS_CHAIN seed file at {seed_file}. Error: {e}"
-			))
-		})?;
-		let seed = seed.trim();
-		let (keypair, _) = sp_core::ecdsa::Pair::from_string_with_seed(seed, None)
-			.map_err(|e| sc_cli::Error::Input(format!("Invalid CROSS_CHAIN seed: {e}")))?;
+		let (keypair, seed) = read_seed_file::<sp_core::ecdsa::Pair>(seed_file, "CROSS_CHAIN")?;
 		keystore
-			.insert(KeyTypeId(*b"crch"), seed, &keypair.public().to_raw_vec())
+			.insert(KeyTypeId(*b"crch"), &seed, &keypair.public().to_raw_vec())
 			.unwrap();
 		log::info!("CROSS_CHAIN pubkey: {}", &keypair.public())
 	}
 
 	runner.run_node_until_exit(|config| async move {
+		let otlp_guard = match &cfg.midnight_cfg.otlp_endpoint {
+			Some(endpoint) => Some(crate::otlp::init(endpoint).map_err(|e| {
+				sc_cli::Error::Input(format!("Failed to set up OpenTelemetry export: {e}"))
+			})?),
+			None => None,
+		};
+
 		let epoch_config: MainchainEpochConfig = cfg.midnight_cfg.clone().into();
 
 		// TODO: Add metrics
@@ -194,15 +214,22 @@ fn run_node(cfg: Cfg) -> sc_cli::Result<()> {
 			)
 			.await?;
 		//For litep2p use `sc_network::Litep2pNetworkBackend<_, _>``
-		service::new_full::<sc_network::NetworkWorker<_, _>>(
+		let mut task_manager = service::new_full::<sc_network::NetworkWorker<_, _>>(
 			config,
 			epoch_config,
 			data_sources,
 			cfg.storage_monitor_params_cfg.into(),
 			storage_config,
+			sealing,
 		)
 		.await
-		.map_err(sc_cli::Error::Service)
+		.map_err(sc_cli::Error::Service)?;
+		// The runner drops the task manager before shutting the Tokio runtime down, so the
+		// spans are flushed while the exporter still runs
+		if let Some(otlp_guard) = otlp_guard {
+			task_manager.keep_alive(otlp_guard);
+		}
+		Ok(task_manager)
 	})
 }
 
@@ -212,6 +239,7 @@ fn run_subcommand(subcommand: Subcommand, cfg: Cfg) -> sc_cli::Result<()> {
 	let storage_config = StorageInit {
 		genesis_state: midnight_node_res::networks::UndeployedNetwork.genesis_state().to_vec(),
 		cache_size: cfg.midnight_cfg.storage_cache_size,
+		backend: cfg.midnight_cfg.ledger_storage_backend,
 	};
 
 	match subcommand {
@@ -441,6 +469,7 @@ fn run_subcommand(subcommand: Subcommand, cfg: Cfg) -> sc_cli::Result<()> {
 			let runner = cfg.create_runner(cmd)?;
 			runner.sync_run(|config| cmd.run::<Block>(&config))
 		},
+		Subcommand::Doctor(ref cmd) => crate::doctor::run(cmd, &cfg),
 		Subcommand::GenerateCNightGenesis(ref cmd) => {
 			// Init logging
 			LoggerBuilder::new(std::env::var("RUST_LOG").unwrap_or("".to_string())).init()?;
@@ -0,0 +1,378 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `doctor` subcommand: checks the node configuration and its external dependencies without
+//! starting the node, and prints a pass/fail report.
+
+use std::fmt::Display;
+
+use check_report::{Report, Status};
+use frame_support::storage::storage_prefix;
+use midnight_primitives_federated_authority_observation::FederatedAuthorityObservationConfig;
+use parity_scale_codec::Decode;
+use partner_chains_mock_data_sources::MockRegistrationsConfig;
+use sc_cli::{CliConfiguration as _, SubstrateCli as _};
+use sc_keystore::LocalKeystore;
+use sc_service::{BasePath, ChainSpec, config::KeystoreConfig};
+use serde_valid::Validate as _;
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_consensus_grandpa::{AuthorityId as GrandpaId, AuthorityWeight};
+use sp_core::{ByteArray, Pair, crypto::KeyTypeId, storage::Storage};
+use sp_keystore::Keystore as _;
+
+use crate::{
+	cfg::{Cfg, midnight_cfg::MidnightCfg},
+	cli::{DoctorCmd, RunMidnight},
+	command::read_seed_file,
+	main_chain_follower::get_connection,
+};
+
+/// Tables of the db-sync schema read by the main chain follower
+const DB_SYNC_TABLES: &[&str] = &["block", "tx", "tx_out", "tx_in", "multi_asset", "ma_tx_out"];
+
+/// Index created by the node on start-up, see `create_cached_data_sources`
+const DB_SYNC_INDEX: &str = "idx_multi_asset_policy_name_hex";
+
+pub fn run(cmd: &DoctorCmd, cfg: &Cfg) -> sc_cli::Result<()> {
+	let mut report = Report::default();
+
+	check_config(&mut report, cfg);
+	check_epoch_params(&mut report, &cfg.midnight_cfg);
+	check_files(&mut report, &cfg.midnight_cfg);
+
+	let run_cmd =
+		report.check("node arguments", RunMidnight::try_from(cfg.substrate_cfg.clone()), |_| {
+			"parsed".to_string()
+		});
+	if let Some(RunMidnight { run: run_cmd, .. }) = run_cmd {
+		let chain_spec = check_chain_spec(&mut report, cfg, &run_cmd);
+		if let Some(chain_spec) = chain_spec {
+			check_keys(&mut report, cfg, &run_cmd, chain_spec.as_ref());
+		}
+	}
+
+	if cmd.skip_db_sync {
+		report.add(Status::Skip, "db-sync", "skipped with --skip-db-sync");
+	} else if cfg.midnight_cfg.use_main_chain_follower_mock {
+		report.add(Status::Skip, "db-sync", "not used with use_main_chain_follower_mock");
+	} else {
+		let runtime = sc_cli::build_runtime()?;
+		runtime.block_on(check_db_sync(&mut report, &cfg.midnight_cfg));
+	}
+
+	report.print();
+	match report.count(Status::Fail) {
+		0 => Ok(()),
+		failed => Err(sc_cli::Error::Input(format!("{failed} check(s) failed"))),
+	}
+}
+
+fn check_config(report: &mut Report, cfg: &Cfg) {
+	report.check("ChainSpecCfg", cfg.chain_spec_cfg.validate(), |_| "valid".to_string());
+	report.check("MetaCfg", cfg.meta_cfg.validate(), |_| "valid".to_string());
+	report.check("MidnightCfg", cfg.midnight_cfg.validate(), |_| "valid".to_string());
+	report.check("StorageMonitorParamsCfg", cfg.storage_monitor_params_cfg.validate(), |_| {
+		"valid".to_string()
+	});
+	report.check("SubstrateCfg", cfg.substrate_cfg.validate(), |_| "valid".to_string());
+}
+
+fn check_epoch_params(report: &mut Report, cfg: &MidnightCfg) {
+	let epoch = cfg.mc_epoch_duration_millis;
+	let slot = cfg.mc_slot_duration_millis;
+	let result = if epoch == 0 || slot == 0 {
+		Err("mc__epoch_duration_millis and mc__slot_duration_millis must be positive".to_string())
+	} else if epoch % slot != 0 {
+		Err(format!(
+			"mc__epoch_duration_millis ({epoch}) is not a multiple of mc__slot_duration_millis \
+			 ({slot})"
+		))
+	} else {
+		Ok(epoch / slot)
+	};
+	report.check("Cardano epoch parameters", result, |slots| format!("{slots} slots per epoch"));
+
+	if let Some(coeff) = cfg.cardano_active_slots_coeff {
+		let result = if coeff > 0.0 && coeff <= 1.0 {
+			Ok(coeff)
+		} else {
+			Err(format!("cardano_active_slots_coeff ({coeff}) must be in (0, 1]"))
+		};
+		report.check("Cardano active slots coefficient", result, |coeff| coeff.to_string());
+	}
+	if let Some(k) = cfg.cardano_security_parameter {
+		let result = if k > 0 { Ok(k) } else { Err("cardano_security_parameter must be positive") };
+		report.check("Cardano security parameter", result, |k| k.to_string());
+	}
+}
+
+fn check_files(report: &mut Report, cfg: &MidnightCfg) {
+	if let Some(seed_file) = &cfg.aura_seed_file {
+		report.check(
+			"AURA seed file",
+			read_seed_file::<sp_core::sr25519::Pair>(seed_file, "AURA"),
+			|(pair, _)| format!("public key {}", pair.public()),
+		);
+	}
+	if let Some(seed_file) = &cfg.grandpa_seed_file {
+		report.check(
+			"GRANDPA seed file",
+			read_seed_file::<sp_core::ed25519::Pair>(seed_file, "GRANDPA"),
+			|(pair, _)| format!("public key {}", pair.public()),
+		);
+	}
+	if let Some(seed_file) = &cfg.cross_chain_seed_file {
+		report.check(
+			"CROSS_CHAIN seed file",
+			read_seed_file::<sp_core::ecdsa::Pair>(seed_file, "CROSS_CHAIN"),
+			|(pair, _)| format!("public key {}", pair.public()),
+		);
+	}
+
+	if cfg.use_main_chain_follower_mock
+		&& let Some(file) = &cfg.mock_registrations_file
+	{
+		report.check(
+			"mock registrations file",
+			MockRegistrationsConfig::read_registrations(file),
+			|_| format!("{file} parsed"),
+		);
+	}
+
+	if let Some(file) = &cfg.federated_authority_config_file {
+		let result = std::fs::read_to_string(file).map_err(|e| e.to_string()).and_then(|json| {
+			serde_json::from_str::<FederatedAuthorityObservationConfig>(&json)
+				.map_err(|e| e.to_string())
+		});
+		report.check("federated authority config file", result, |_| format!("{file} parsed"));
+	}
+}
+
+fn check_chain_spec(
+	report: &mut Report,
+	cfg: &Cfg,
+	run_cmd: &sc_cli::RunCmd,
+) -> Option<Box<dyn ChainSpec>> {
+	let chain_id = run_cmd.shared_params().chain_id(run_cmd.shared_params().is_dev());
+	report.check("chain spec", cfg.load_spec(&chain_id), |spec| {
+		format!("loaded {} ({})", spec.name(), spec.id())
+	})
+}
+
+/// Raw public keys of the genesis authorities
+#[derive(Debug, PartialEq)]
+struct GenesisAuthorities {
+	aura: Vec<Vec<u8>>,
+	grandpa: Vec<Vec<u8>>,
+}
+
+/// Genesis authorities of `chain_spec`, read from its genesis storage so that raw chain specs
+/// work too. The session pallet sets the AURA and GRANDPA authorities from its initial validators
+/// when the genesis is built
+fn genesis_authorities(chain_spec: &dyn ChainSpec) -> Result<GenesisAuthorities, String> {
+	let storage = chain_spec.as_storage_builder().build_storage()?;
+	authorities_from_storage(&storage)
+}
+
+fn authorities_from_storage(storage: &Storage) -> Result<GenesisAuthorities, String> {
+	let read = |pallet: &str| {
+		storage
+			.top
+			.get(&storage_prefix(pallet.as_bytes(), b"Authorities")[..])
+			.ok_or_else(|| format!("no {pallet} authorities in the genesis storage"))
+	};
+
+	let aura = Vec::<AuraId>::decode(&mut &read("Aura")?[..])
+		.map_err(|e| format!("invalid Aura authorities: {e}"))?;
+	let grandpa = Vec::<(GrandpaId, AuthorityWeight)>::decode(&mut &read("Grandpa")?[..])
+		.map_err(|e| format!("invalid Grandpa authorities: {e}"))?;
+	Ok(GenesisAuthorities {
+		aura: aura.iter().map(|key| key.to_raw_vec()).collect(),
+		grandpa: grandpa.iter().map(|(key, _)| key.to_raw_vec()).collect(),
+	})
+}
+
+fn check_keys(
+	report: &mut Report,
+	cfg: &Cfg,
+	run_cmd: &sc_cli::RunCmd,
+	chain_spec: &dyn ChainSpec,
+) {
+	let Some(authorities) =
+		report.check("genesis authorities", genesis_authorities(chain_spec), |authorities| {
+			format!("{} AURA, {} GRANDPA", authorities.aura.len(), authorities.grandpa.len())
+		})
+	else {
+		return;
+	};
+
+	let base_path = match run_cmd.shared_params().base_path() {
+		Ok(base_path) => {
+			base_path.unwrap_or_else(|| BasePath::from_project("", "", "midnight-node"))
+		},
+		Err(e) => {
+			report.add(Status::Fail, "keystore", e.to_string());
+			return;
+		},
+	};
+	let config_dir = base_path.config_dir(chain_spec.id());
+	let keystore = match run_cmd.keystore_params().map(|p| p.keystore_config(&config_dir)) {
+		Some(Ok(KeystoreConfig::Path { path, password })) if path.exists() => {
+			match LocalKeystore::open(&path, password) {
+				Ok(keystore) => Some(keystore),
+				Err(e) => {
+					report.add(Status::Fail, "keystore", format!("{}: {e}", path.display()));
+					return;
+				},
+			}
+		},
+		Some(Ok(KeystoreConfig::Path { path, .. })) => {
+			report.add(Status::Warn, "keystore", format!("{} does not exist", path.display()));
+			None
+		},
+		Some(Err(e)) => {
+			report.add(Status::Fail, "keystore", e.to_string());
+			return;
+		},
+		_ => None,
+	};
+
+	let mut aura_keys: Vec<_> = keystore
+		.as_ref()
+		.map(|k| k.sr25519_public_keys(KeyTypeId(*b"aura")))
+		.unwrap_or_default();
+	if let Some(seed_file) = &cfg.midnight_cfg.aura_seed_file
+		&& let Ok((pair, _)) = read_seed_file::<sp_core::sr25519::Pair>(seed_file, "AURA")
+	{
+		aura_keys.push(pair.public());
+	}
+	check_authority_keys(report, "AURA", run_cmd.validator, &aura_keys, &authorities.aura);
+
+	let mut grandpa_keys: Vec<_> = keystore
+		.as_ref()
+		.map(|k| k.ed25519_public_keys(KeyTypeId(*b"gran")))
+		.unwrap_or_default();
+	if let Some(seed_file) = &cfg.midnight_cfg.grandpa_seed_file
+		&& let Ok((pair, _)) = read_seed_file::<sp_core::ed25519::Pair>(seed_file, "GRANDPA")
+	{
+		grandpa_keys.push(pair.public());
+	}
+	check_authority_keys(report, "GRANDPA", run_cmd.validator, &grandpa_keys, &authorities.grandpa);
+}
+
+fn check_authority_keys<K: ByteArray + Display>(
+	report: &mut Report,
+	name: &str,
+	validator: bool,
+	local_keys: &[K],
+	authorities: &[Vec<u8>],
+) {
+	let check = format!("{name} keys");
+	if local_keys.is_empty() {
+		let status = if validator { Status::Fail } else { Status::Skip };
+		report.add(status, check, "no key in the keystore or seed file");
+		return;
+	}
+
+	let matching: Vec<_> =
+		local_keys.iter().filter(|k| authorities.contains(&k.to_raw_vec())).collect();
+	match matching.first() {
+		Some(key) => report.add(Status::Pass, check, format!("{key} is a genesis authority")),
+		None => report.add(
+			Status::Warn,
+			check,
+			format!(
+				"none of the {} local key(s) is a genesis authority, which is only expected if \
+				 the node registered as a committee candidate after genesis",
+				local_keys.len()
+			),
+		),
+	}
+}
+
+async fn check_db_sync(report: &mut Report, cfg: &MidnightCfg) {
+	let Some(connection_string) = &cfg.db_sync_postgres_connection_string else {
+		report.add(Status::Fail, "db-sync", "db_sync_postgres_connection_string is not set");
+		return;
+	};
+	let Some(pool) = report.check(
+		"db-sync connection",
+		get_connection(connection_string, std::time::Duration::from_secs(10)).await,
+		|_| "connected".to_string(),
+	) else {
+		return;
+	};
+
+	for table in DB_SYNC_TABLES {
+		let exists = sqlx::query_scalar::<_, bool>("SELECT to_regclass($1::text) IS NOT NULL")
+			.bind(*table)
+			.fetch_one(&pool)
+			.await;
+		match exists {
+			Ok(true) => report.add(Status::Pass, format!("db-sync table {table}"), "present"),
+			Ok(false) => report.add(Status::Fail, format!("db-sync table {table}"), "missing"),
+			Err(e) => report.add(Status::Fail, format!("db-sync table {table}"), e.to_string()),
+		}
+	}
+
+	let index = sqlx::query_scalar::<_, bool>(
+		"SELECT EXISTS (SELECT 1 FROM pg_indexes WHERE indexname = $1)",
+	)
+	.bind(DB_SYNC_INDEX)
+	.fetch_one(&pool)
+	.await;
+	match index {
+		Ok(true) => report.add(Status::Pass, format!("db-sync index {DB_SYNC_INDEX}"), "present"),
+		Ok(false) => report.add(
+			Status::Warn,
+			format!("db-sync index {DB_SYNC_INDEX}"),
+			"missing: the node creates it on start-up, which may take a while",
+		),
+		Err(e) => report.add(Status::Fail, format!("db-sync index {DB_SYNC_INDEX}"), e.to_string()),
+	}
+
+	let tip = sqlx::query_scalar::<_, Option<i32>>("SELECT max(block_no) FROM block")
+		.fetch_one(&pool)
+		.await;
+	match tip {
+		Ok(Some(block_no)) => report.add(Status::Pass, "db-sync tip", format!("block {block_no}")),
+		Ok(None) => report.add(Status::Fail, "db-sync tip", "no blocks synced yet"),
+		Err(e) => report.add(Status::Fail, "db-sync tip", e.to_string()),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::chain_spec::{self, chain_config};
+	use midnight_node_res::networks::UndeployedNetwork;
+
+	#[test]
+	fn genesis_authorities_are_read_from_raw_chain_specs() {
+		let chain_spec = chain_config(UndeployedNetwork).unwrap();
+		let authorities = genesis_authorities(&chain_spec).unwrap();
+		assert!(!authorities.aura.is_empty());
+		assert_eq!(authorities.aura.len(), authorities.grandpa.len());
+
+		let raw =
+			chain_spec::ChainSpec::from_json_bytes(chain_spec.as_json(true).unwrap().into_bytes())
+				.unwrap();
+		assert_eq!(genesis_authorities(&raw).unwrap(), authorities);
+	}
+
+	#[test]
+	fn missing_authorities_are_reported() {
+		let error = authorities_from_storage(&Storage::default()).unwrap_err();
+		assert_eq!(error, "no Aura authorities in the genesis storage");
+	}
+}
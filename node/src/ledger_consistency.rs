@@ -0,0 +1,132 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Startup consistency check between the Substrate state and the ledger database.
+//!
+//! The ledger arena lives in its own database (by default a ParityDB under `ledger_storage` in the
+//! base path), flushed in `pallet_midnight`'s `on_finalize` independently of the Substrate
+//! database commit. A node that dies between the two can restart with a `StateKey` referencing
+//! arena nodes that were never flushed. Before the node starts importing or authoring, it checks
+//! that the ledger roots of the finalized and best blocks are present. If one is missing, the
+//! blocks since its last complete ancestor are re-executed, which writes their ledger changes
+//! again.
+
+use crate::service::FullClient;
+use midnight_node_ledger::StateCheckError;
+use midnight_node_runtime::{Runtime, opaque::Block};
+use parity_scale_codec::Decode;
+use sc_client_api::{BlockBackend, StorageProvider};
+use sc_service::error::Error as ServiceError;
+use sp_api::{Core, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::storage::StorageKey;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, Zero};
+
+type Hash = <Block as BlockT>::Hash;
+
+/// Checks the ledger state of the finalized and best blocks, recovering any that is incomplete
+pub fn ensure_ledger_consistency(client: &FullClient) -> Result<(), ServiceError> {
+	let info = client.chain_info();
+	for (name, hash) in [("finalized", info.finalized_hash), ("best", info.best_hash)] {
+		recover_ledger_state(client, name, hash)?;
+	}
+	Ok(())
+}
+
+/// Re-executes the blocks between the last ancestor of `target` with a complete ledger state and
+/// `target` itself
+fn recover_ledger_state(client: &FullClient, name: &str, target: Hash) -> Result<(), ServiceError> {
+	let mut to_execute = Vec::new();
+	let mut hash = target;
+	while !is_ledger_state_complete(client, hash)? {
+		let header = client
+			.header(hash)?
+			.ok_or_else(|| ServiceError::Other(format!("missing header of block {hash}")))?;
+		if header.number().is_zero() {
+			return Err(ServiceError::Other(
+				"ledger state of the genesis block is incomplete".to_string(),
+			));
+		}
+		to_execute.push(hash);
+		hash = *header.parent_hash();
+	}
+
+	if to_execute.is_empty() {
+		log::debug!("Ledger state of the {name} block {target} is complete");
+		return Ok(());
+	}
+
+	log::warn!(
+		"Ledger state of the {name} block {target} is incomplete: re-executing {} block(s) from \
+		 the last complete state at {hash}",
+		to_execute.len()
+	);
+	for hash in to_execute.into_iter().rev() {
+		reexecute_block(client, hash)?;
+	}
+	midnight_node_ledger::flush_storage();
+
+	if !is_ledger_state_complete(client, target)? {
+		return Err(ServiceError::Other(format!(
+			"ledger state of the {name} block {target} is still incomplete after re-execution"
+		)));
+	}
+	log::info!("Recovered the ledger state of the {name} block {target}");
+	Ok(())
+}
+
+fn reexecute_block(client: &FullClient, hash: Hash) -> Result<(), ServiceError> {
+	let block = client
+		.block(hash)?
+		.ok_or_else(|| ServiceError::Other(format!("missing body of block {hash}")))?
+		.block;
+	let (mut header, extrinsics) = block.deconstruct();
+	// Seals are added after execution, and are removed by the import queue before it
+	header.digest_mut().logs.retain(|item| item.as_seal().is_none());
+	let parent_hash = *header.parent_hash();
+
+	log::info!("Re-executing block #{} ({hash})", header.number());
+	client
+		.runtime_api()
+		.execute_block(parent_hash, Block::new(header, extrinsics))
+		.map_err(|e| {
+			ServiceError::Other(format!(
+				"failed to re-execute block {hash}: {e}. If the state of its parent was pruned, \
+				 resync the node with `wipe_chain_state`"
+			))
+		})
+}
+
+/// Checks that the root of the ledger state referenced by `StateKey` at `hash` is present
+fn is_ledger_state_complete(client: &FullClient, hash: Hash) -> Result<bool, ServiceError> {
+	let key = StorageKey(pallet_midnight::StateKey::<Runtime>::hashed_key().to_vec());
+	let Some(data) = client.storage(hash, &key)? else {
+		// `StateKey` is set at genesis, so this is a chain without a ledger
+		return Ok(true);
+	};
+	let state_key = Vec::<u8>::decode(&mut &data.0[..])
+		.map_err(|e| ServiceError::Other(format!("invalid StateKey at block {hash}: {e}")))?;
+
+	match midnight_node_ledger::check_state_complete(&state_key) {
+		Ok(()) => Ok(true),
+		Err(e @ StateCheckError::MissingRoot(_)) => {
+			log::debug!("Ledger state at block {hash} is incomplete: {e}");
+			Ok(false)
+		},
+		// Re-executing blocks can't fix a key this node can't read, it is left to block import
+		Err(e @ StateCheckError::InvalidKey(_)) => {
+			log::warn!("Unable to check the ledger state at block {hash}: {e}");
+			Ok(true)
+		},
+	}
+}
@@ -0,0 +1,210 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Ledger business metrics counted from the events of finalized blocks.
+//!
+//! The ledger host functions also run for dry runs, for blocks that are authored and then
+//! imported, and for blocks of abandoned forks, so counters updated there would be inflated.
+//! Finalized blocks are notified once, and their events record what the ledger applied. Blocks
+//! whose events can't be read, for example because their state was pruned or they use events of
+//! an older runtime, are skipped.
+
+use crate::service::FullClient;
+use futures::StreamExt;
+use midnight_node_ledger::types::active_version::TransactionError;
+use midnight_node_runtime::{Runtime, RuntimeEvent, opaque::Block};
+use midnight_primitives_ledger::LedgerMetrics;
+use pallet_midnight::Error as MidnightError;
+use parity_scale_codec::Decode;
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use sp_core::storage::StorageKey;
+use sp_runtime::{DispatchError, traits::Block as BlockT};
+use std::sync::Arc;
+
+type Hash = <Block as BlockT>::Hash;
+type EventRecord = frame_system::EventRecord<RuntimeEvent, Hash>;
+
+/// Updates the ledger counters with the events of every finalized block
+pub async fn count_finalized_blocks(client: Arc<FullClient>, metrics: LedgerMetrics) {
+	let mut finality = client.finality_notification_stream();
+	while let Some(notification) = finality.next().await {
+		// `tree_route` holds the blocks implicitly finalized before `hash`, oldest first
+		for hash in notification.tree_route.iter().chain([&notification.hash]) {
+			if let Err(e) = observe_block(&client, &metrics, *hash) {
+				log::debug!(
+					target: "prometheus",
+					"Failed to read the ledger metrics of block {hash}: {e}"
+				);
+			}
+		}
+	}
+}
+
+fn observe_block(client: &FullClient, metrics: &LedgerMetrics, hash: Hash) -> Result<(), String> {
+	let key = StorageKey(frame_system::Events::<Runtime>::hashed_key().to_vec());
+	let Some(data) = client.storage(hash, &key).map_err(|e| e.to_string())? else {
+		return Ok(());
+	};
+	let events = Vec::<EventRecord>::decode(&mut &data.0[..]).map_err(|e| e.to_string())?;
+	for record in events {
+		observe_event(metrics, record.event);
+	}
+	Ok(())
+}
+
+fn observe_event(metrics: &LedgerMetrics, event: RuntimeEvent) {
+	use pallet_midnight::Event as Midnight;
+
+	match event {
+		RuntimeEvent::Midnight(Midnight::TxApplied(_)) => {
+			metrics.txs_outcome.with_label_values(&["regular", "applied"]).inc();
+		},
+		RuntimeEvent::Midnight(Midnight::TxPartialSuccess(_)) => {
+			metrics.txs_outcome.with_label_values(&["regular", "partial"]).inc();
+		},
+		RuntimeEvent::Midnight(Midnight::ContractDeploy(_)) => {
+			metrics.contract_operations.with_label_values(&["deploy"]).inc();
+		},
+		RuntimeEvent::Midnight(Midnight::ContractCall(_)) => {
+			metrics.contract_operations.with_label_values(&["call"]).inc();
+		},
+		RuntimeEvent::Midnight(Midnight::ContractMaintain(_)) => {
+			metrics.contract_operations.with_label_values(&["maintain"]).inc();
+		},
+		RuntimeEvent::Midnight(Midnight::UnshieldedTokens(details)) => {
+			metrics
+				.unshielded_utxos
+				.with_label_values(&["created"])
+				.inc_by(details.created.len() as u64);
+			metrics
+				.unshielded_utxos
+				.with_label_values(&["spent"])
+				.inc_by(details.spent.len() as u64);
+		},
+		RuntimeEvent::Midnight(Midnight::PayoutMinted(details)) => {
+			metrics.block_rewards_minted.inc_by(details.amount as f64);
+		},
+		RuntimeEvent::MidnightSystem(pallet_midnight_system::Event::SystemTransactionApplied(
+			applied,
+		)) => {
+			metrics.txs_outcome.with_label_values(&["system", "applied"]).inc();
+			match midnight_node_ledger::latest::dust_generation_actions(
+				&applied.serialized_system_transaction,
+			) {
+				Ok(actions) => {
+					for action in actions {
+						metrics.dust_generation_events.with_label_values(&[action]).inc();
+					}
+				},
+				Err(e) => log::debug!(
+					target: "prometheus",
+					"Failed to decode the system transaction {:?}: {e}",
+					applied.hash
+				),
+			}
+		},
+		RuntimeEvent::System(frame_system::Event::ExtrinsicFailed {
+			dispatch_error: DispatchError::Module(error),
+			..
+		}) if error.index == midnight_pallet_index() => {
+			let reason = MidnightError::<Runtime>::decode(&mut &error.error[..])
+				.map_or("unknown", rejection_reason);
+			metrics.txs_rejected.with_label_values(&["block", reason]).inc();
+		},
+		_ => {},
+	}
+}
+
+fn midnight_pallet_index() -> u8 {
+	use frame_support::traits::PalletInfoAccess;
+
+	midnight_node_runtime::Midnight::index() as u8
+}
+
+/// Same labels as `LedgerApiError::kind`, used for the transactions rejected by validation
+fn rejection_reason(error: MidnightError<Runtime>) -> &'static str {
+	match error {
+		MidnightError::Deserialization(_) => "deserialization",
+		MidnightError::Serialization(_) => "serialization",
+		MidnightError::Transaction(TransactionError::Invalid(_)) => "invalid",
+		MidnightError::Transaction(TransactionError::Malformed(_)) => "malformed",
+		MidnightError::Transaction(TransactionError::SystemTransaction(_)) => "system_transaction",
+		MidnightError::LedgerCacheError => "ledger_cache",
+		MidnightError::NoLedgerState => "no_ledger_state",
+		MidnightError::LedgerStateScaleDecodingError => "ledger_state_decoding",
+		MidnightError::ContractCallCostError => "contract_call_cost",
+		MidnightError::BlockLimitExceededError => "block_limit_exceeded",
+		MidnightError::FeeCalculationError => "fee_calculation",
+		MidnightError::HostApiError => "host_api",
+		_ => "other",
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pallet_midnight::PayoutDetails;
+	use parity_scale_codec::Encode;
+	use prometheus_endpoint::Registry;
+	use sp_runtime::ModuleError;
+
+	fn failed_extrinsic(index: u8, error: MidnightError<Runtime>) -> RuntimeEvent {
+		let mut encoded = [0u8; 4];
+		let error = error.encode();
+		encoded[..error.len()].copy_from_slice(&error);
+		RuntimeEvent::System(frame_system::Event::ExtrinsicFailed {
+			dispatch_error: DispatchError::Module(ModuleError {
+				index,
+				error: encoded,
+				message: None,
+			}),
+			dispatch_info: Default::default(),
+		})
+	}
+
+	#[test]
+	fn block_rewards_above_u64_max_are_counted() {
+		let metrics = LedgerMetrics::register(&Registry::new()).unwrap();
+		let amount = u64::MAX as u128 * 4;
+
+		observe_event(
+			&metrics,
+			RuntimeEvent::Midnight(pallet_midnight::Event::PayoutMinted(PayoutDetails {
+				amount,
+				receiver: vec![],
+			})),
+		);
+
+		assert_eq!(metrics.block_rewards_minted.get(), amount as f64);
+	}
+
+	#[test]
+	fn failed_midnight_extrinsics_are_counted_by_reason() {
+		let metrics = LedgerMetrics::register(&Registry::new()).unwrap();
+
+		observe_event(
+			&metrics,
+			failed_extrinsic(midnight_pallet_index(), MidnightError::BlockLimitExceededError),
+		);
+		// Errors of other pallets aren't Midnight transactions
+		observe_event(
+			&metrics,
+			failed_extrinsic(midnight_pallet_index() + 1, MidnightError::BlockLimitExceededError),
+		);
+
+		assert_eq!(
+			metrics.txs_rejected.with_label_values(&["block", "block_limit_exceeded"]).get(),
+			1
+		);
+	}
+}
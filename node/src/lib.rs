@@ -18,9 +18,13 @@ pub mod chain_spec;
 pub mod cli;
 pub mod cnight_genesis;
 pub mod command;
+mod doctor;
 pub mod extensions;
 pub mod inherent_data;
+mod ledger_consistency;
+mod ledger_metrics;
 pub mod main_chain_follower;
+mod otlp;
 pub mod partner_chains;
 pub mod rpc;
 pub mod service;
@@ -200,7 +200,7 @@ pub async fn create_cnight_observation_data_source(
 }
 
 // Copied from internal utility in partner-chains-db-sync-data-sources
-async fn get_connection(
+pub(crate) async fn get_connection(
 	connection_string: &str,
 	acquire_timeout: std::time::Duration,
 ) -> Result<sqlx::PgPool, Box<dyn Error + Send + Sync + 'static>> {
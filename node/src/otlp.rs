@@ -0,0 +1,93 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Optional export of OpenTelemetry spans to an OTLP collector.
+//!
+//! Block imports are traced here; the ledger host functions add their own spans below them (see
+//! `midnight_node_ledger::telemetry`).
+
+use midnight_node_ledger::telemetry::TRACER_NAME;
+use midnight_node_runtime::opaque::Block;
+use opentelemetry::{
+	Context, KeyValue, global,
+	trace::{FutureExt as _, Span as _, TraceContextExt as _, TraceError, Tracer as _},
+};
+use opentelemetry_otlp::{SpanExporter, WithExportConfig as _};
+use opentelemetry_sdk::{Resource, runtime, trace::TracerProvider};
+use sc_consensus::{BlockCheckParams, BlockImport, BlockImportParams, ImportResult};
+use sp_runtime::traits::Header as _;
+
+/// Installs a global tracer provider exporting spans to the OTLP gRPC `endpoint`.
+///
+/// Must be called from within a Tokio runtime, which runs the batch exporter. The spans not yet
+/// exported are flushed when the returned guard is dropped.
+pub fn init(endpoint: &str) -> Result<ShutdownGuard, TraceError> {
+	let exporter = SpanExporter::builder().with_tonic().with_endpoint(endpoint).build()?;
+	let provider = TracerProvider::builder()
+		.with_batch_exporter(exporter, runtime::Tokio)
+		.with_resource(Resource::new([KeyValue::new("service.name", "midnight-node")]))
+		.build();
+	global::set_tracer_provider(provider);
+	log::info!("Exporting OpenTelemetry traces to {endpoint}");
+	Ok(ShutdownGuard)
+}
+
+/// Flushes the spans not yet exported and shuts the tracer provider down when dropped.
+///
+/// The batch exporter is a task of the Tokio runtime, so the guard must be dropped while the
+/// runtime is still running, and outside of its worker threads since the shutdown blocks until
+/// the exporter is done. The node keeps it alive in its `TaskManager`, which is dropped before
+/// the runtime shuts down.
+pub struct ShutdownGuard;
+
+impl Drop for ShutdownGuard {
+	fn drop(&mut self) {
+		global::shutdown_tracer_provider();
+	}
+}
+
+/// Wraps a block import in a span, the parent of the ledger spans of the block execution
+#[derive(Clone)]
+pub struct TracedBlockImport<I> {
+	inner: I,
+}
+
+impl<I> TracedBlockImport<I> {
+	pub fn new(inner: I) -> Self {
+		Self { inner }
+	}
+}
+
+#[async_trait::async_trait]
+impl<I: BlockImport<Block> + Send + Sync> BlockImport<Block> for TracedBlockImport<I> {
+	type Error = I::Error;
+
+	async fn check_block(
+		&self,
+		block: BlockCheckParams<Block>,
+	) -> Result<ImportResult, Self::Error> {
+		self.inner.check_block(block).await
+	}
+
+	async fn import_block(
+		&self,
+		block: BlockImportParams<Block>,
+	) -> Result<ImportResult, Self::Error> {
+		let mut span = global::tracer(TRACER_NAME).start("block_import");
+		span.set_attribute(KeyValue::new("block_number", i64::from(*block.header.number())));
+		span.set_attribute(KeyValue::new("block_hash", format!("{:?}", block.post_hash())));
+		span.set_attribute(KeyValue::new("origin", format!("{:?}", block.origin)));
+		let cx = Context::current_with_span(span);
+		self.inner.import_block(block).with_context(cx).await
+	}
+}
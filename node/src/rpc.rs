@@ -20,6 +20,7 @@
 
 use authority_selection_inherents::CommitteeMember;
 use authority_selection_inherents::{AuthoritySelectionInputs, CandidateValidationApi};
+use futures::channel::mpsc;
 use jsonrpsee::RpcModule;
 use midnight_node_runtime::{
 	AccountId, BlockNumber, CrossChainPublic, Hash, Nonce,
@@ -30,6 +31,10 @@ use sc_consensus_grandpa::{
 	FinalityProofProvider, GrandpaJustificationStream, SharedAuthoritySet, SharedVoterState,
 };
 use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
+use sc_consensus_manual_seal::{
+	EngineCommand,
+	rpc::{ManualSeal, ManualSealApiServer},
+};
 use sc_rpc::SubscriptionTaskExecutor;
 use sc_transaction_pool_api::TransactionPool;
 use sidechain_domain::ScEpochNumber;
@@ -44,6 +49,8 @@ use pallet_sidechain_rpc::*;
 use sidechain_domain::mainchain_epoch::MainchainEpochConfig;
 use time_source::TimeSource;
 
+use pallet_federated_authority::FederatedAuthorityApi;
+use pallet_federated_authority_rpc::{FederatedAuthority, FederatedAuthorityRpcApiServer};
 use pallet_midnight::MidnightRuntimeApi;
 use pallet_midnight_rpc::{Midnight, MidnightApiServer};
 use sc_consensus_beefy::communication::notification::{
@@ -95,6 +102,8 @@ pub struct FullDeps<C, P, B, T, AuthorityId: AuthorityIdBound> {
 	pub main_chain_epoch_config: MainchainEpochConfig,
 	/// Backend used by the node.
 	pub backend: Arc<B>,
+	/// Channel for `engine_*` seal commands, set only in the development sealing mode.
+	pub manual_seal_sink: Option<mpsc::Sender<EngineCommand<Hash>>>,
 }
 
 /// Instantiate all full RPC extensions.
@@ -111,6 +120,7 @@ where
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: BlockBuilder<Block>,
 	C::Api: MidnightRuntimeApi<Block>,
+	C::Api: FederatedAuthorityApi<Block, Hash, BlockNumber>,
 	C::Api: sp_consensus_aura::AuraApi<Block, sp_consensus_aura::sr25519::AuthorityId>,
 	C::Api: sp_consensus_beefy::BeefyApi<Block, AuthorityId>,
 	C::Api: mmr_rpc::MmrRuntimeApi<Block, <Block as sp_runtime::traits::Block>::Hash, BlockNumber>,
@@ -143,6 +153,7 @@ where
 		time_source,
 		main_chain_epoch_config,
 		backend,
+		manual_seal_sink,
 	} = deps;
 
 	module.merge(System::new(client.clone(), pool).into_rpc())?;
@@ -204,7 +215,20 @@ where
 		)))
 		.into_rpc(),
 	)?;
-	module.merge(Midnight::new(client).into_rpc())?;
+	module.merge(FederatedAuthority::new(client.clone()).into_rpc())?;
+	module.merge(
+		Midnight::new(
+			client,
+			backend
+				.offchain_storage()
+				.ok_or("Backend doesn't provide an offchain storage")?,
+		)
+		.into_rpc(),
+	)?;
+
+	if let Some(manual_seal_sink) = manual_seal_sink {
+		module.merge(ManualSeal::new(manual_seal_sink).into_rpc())?;
+	}
 
 	// Extend this RPC with a custom API by using the following syntax.
 	// `YourRpcStruct` should have a reference to a client, which is needed
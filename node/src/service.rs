@@ -14,21 +14,24 @@
 //! Service and ServiceFactory implementation. Specialized wrapper over substrate service.
 
 use crate::{
+	cli::Sealing,
 	extensions::ExtensionsFactory,
 	inherent_data::{CreateInherentDataConfig, ProposalCIDP, VerifierCIDP},
 	main_chain_follower::DataSources,
+	otlp::TracedBlockImport,
 	rpc::{BeefyDeps, GrandpaDeps},
 };
-use futures::FutureExt;
+use futures::{FutureExt, Stream, StreamExt};
 use midnight_node_runtime::storage::child::StateVersion;
 use midnight_node_runtime::{self, RuntimeApi, opaque::Block};
-use midnight_primitives_ledger::{LedgerMetrics, LedgerStorage};
+use midnight_primitives_ledger::{LedgerMetrics, LedgerStorage, LedgerStorageBackend};
 use parity_scale_codec::{Decode, Encode};
 use partner_chains_db_sync_data_sources::McFollowerMetrics;
 use partner_chains_db_sync_data_sources::register_metrics_warn_errors;
 use sc_client_api::{Backend, BlockImportOperation, ExecutorProvider};
 use sc_consensus_aura::{ImportQueueParams, SlotProportion, StartAuraParams};
 use sc_consensus_grandpa::SharedVoterState;
+use sc_consensus_manual_seal::{EngineCommand, consensus::aura::AuraConsensusDataProvider};
 use sc_consensus_slots::BackoffAuthoringOnFinalizedHeadLagging;
 use sc_executor::RuntimeVersionOf;
 use sc_partner_chains_consensus_aura::import_queue as partner_chains_aura_import_queue;
@@ -37,10 +40,12 @@ use sc_service::{
 	resolve_state_version_from_wasm,
 };
 use sc_telemetry::{Telemetry, TelemetryWorker};
-use sc_transaction_pool_api::OffchainTransactionPoolFactory;
+use sc_transaction_pool_api::{OffchainTransactionPoolFactory, TransactionPool};
 use sidechain_domain::mainchain_epoch::MainchainEpochConfig;
 use sidechain_mc_hash::McHashInherentDigest;
-use sp_consensus_aura::sr25519::AuthorityPair as AuraPair;
+use sidechain_slots::ScSlotConfig;
+use sp_blockchain::HeaderBackend;
+use sp_consensus_aura::sr25519::{AuthorityPair as AuraPair, AuthoritySignature as AuraSignature};
 use sp_consensus_beefy::ecdsa_crypto::AuthorityId as BeefyId;
 
 use mmr_gadget::MmrGadget;
@@ -54,14 +59,17 @@ use sp_runtime::{
 use sp_runtime::{Digest, DigestItem};
 use std::{
 	marker::PhantomData,
+	path::PathBuf,
+	pin::Pin,
 	sync::{Arc, Mutex},
 	time::Duration,
 };
-use time_source::SystemTimeSource;
+use time_source::{SystemTimeSource, TimeSource};
 
 pub struct StorageInit {
 	pub genesis_state: Vec<u8>,
 	pub cache_size: usize,
+	pub backend: LedgerStorageBackend,
 }
 
 pub struct GenesisBlockBuilder<Block: BlockT, B, E> {
@@ -211,12 +219,25 @@ pub fn new_partial(
 	epoch_config: MainchainEpochConfig,
 	data_sources: DataSources,
 	storage_config: StorageInit,
+) -> Result<MidnightService, ServiceError> {
+	new_partial_with_sealing(config, epoch_config, data_sources, storage_config, None)
+}
+
+/// As [`new_partial`], importing blocks sealed by manual-seal instead of AURA when `sealing` is set
+#[allow(clippy::result_large_err)]
+fn new_partial_with_sealing(
+	config: &Configuration,
+	epoch_config: MainchainEpochConfig,
+	data_sources: DataSources,
+	storage_config: StorageInit,
+	sealing: Option<Sealing>,
 ) -> Result<MidnightService, ServiceError> {
 	let _mc_follower_metrics = register_metrics_warn_errors(config.prometheus_registry());
 
 	// Init Ledger DB
 	let parity_db_path = config.base_path.path().join("ledger_storage");
-	midnight_node_ledger::init_storage_paritydb(
+	midnight_node_ledger::init_storage(
+		storage_config.backend,
 		&parity_db_path,
 		&storage_config.genesis_state,
 		storage_config.cache_size,
@@ -299,7 +320,26 @@ pub fn new_partial(
 				},
 			});
 
-	let ledger_storage = LedgerStorage::new(parity_db_path, storage_config.cache_size);
+	if let Some(metrics) = ledger_metrics.as_ref() {
+		task_manager.spawn_handle().spawn(
+			"ledger-finalized-metrics",
+			None,
+			crate::ledger_metrics::count_finalized_blocks(client.clone(), metrics.clone()),
+		);
+	}
+
+	if let (Some(metrics), LedgerStorageBackend::ParityDb) =
+		(ledger_metrics.as_ref(), storage_config.backend)
+	{
+		task_manager.spawn_handle().spawn(
+			"ledger-arena-disk-size",
+			None,
+			watch_arena_disk_size(metrics.clone(), parity_db_path.clone()),
+		);
+	}
+
+	let ledger_storage =
+		LedgerStorage::new(parity_db_path, storage_config.cache_size, storage_config.backend);
 
 	client
 		.execution_extensions()
@@ -347,33 +387,41 @@ pub fn new_partial(
 	let time_source = Arc::new(SystemTimeSource);
 	let inherent_config = CreateInherentDataConfig::new(epoch_config, sc_slot_config, time_source);
 
-	let import_queue = partner_chains_aura_import_queue::import_queue::<
-		AuraPair,
-		_,
-		_,
-		_,
-		_,
-		_,
-		McHashInherentDigest,
-	>(ImportQueueParams {
-		block_import: grandpa_block_import.clone(),
-		justification_import: Some(Box::new(grandpa_block_import.clone())),
-		client: client.clone(),
-		create_inherent_data_providers: VerifierCIDP::new(
-			inherent_config,
-			client.clone(),
-			data_sources.mc_hash.clone(),
-			data_sources.authority_selection.clone(),
-			data_sources.cnight_observation.clone(),
-			data_sources.governed_map.clone(),
-			data_sources.federated_authority_observation.clone(),
-		),
-		spawner: &task_manager.spawn_essential_handle(),
-		registry: config.prometheus_registry(),
-		check_for_equivocation: Default::default(),
-		telemetry: telemetry.as_ref().map(|x| x.handle()),
-		compatibility_mode: Default::default(),
-	})?;
+	let import_queue = if sealing.is_some() {
+		sc_consensus_manual_seal::import_queue(
+			Box::new(TracedBlockImport::new(client.clone())),
+			&task_manager.spawn_essential_handle(),
+			config.prometheus_registry(),
+		)
+	} else {
+		partner_chains_aura_import_queue::import_queue::<
+			AuraPair,
+			_,
+			_,
+			_,
+			_,
+			_,
+			McHashInherentDigest,
+		>(ImportQueueParams {
+			block_import: TracedBlockImport::new(grandpa_block_import.clone()),
+			justification_import: Some(Box::new(grandpa_block_import.clone())),
+			client: client.clone(),
+			create_inherent_data_providers: VerifierCIDP::new(
+				inherent_config,
+				client.clone(),
+				data_sources.mc_hash.clone(),
+				data_sources.authority_selection.clone(),
+				data_sources.cnight_observation.clone(),
+				data_sources.governed_map.clone(),
+				data_sources.federated_authority_observation.clone(),
+			),
+			spawner: &task_manager.spawn_essential_handle(),
+			registry: config.prometheus_registry(),
+			check_for_equivocation: Default::default(),
+			telemetry: telemetry.as_ref().map(|x| x.handle()),
+			compatibility_mode: Default::default(),
+		})?
+	};
 
 	let partial_components = sc_service::PartialComponents {
 		client: client.clone(),
@@ -404,10 +452,16 @@ pub async fn new_full<Network: sc_network::NetworkBackend<Block, <Block as Block
 	data_sources: DataSources,
 	storage_monitor_params: sc_storage_monitor::StorageMonitorParams,
 	storage_config: StorageInit,
+	sealing: Option<Sealing>,
 ) -> Result<TaskManager, ServiceError> {
 	let database_source = config.database.clone();
-	let new_partial_components =
-		new_partial(&config, epoch_config.clone(), data_sources.clone(), storage_config)?;
+	let new_partial_components = new_partial_with_sealing(
+		&config,
+		epoch_config.clone(),
+		data_sources.clone(),
+		storage_config,
+		sealing,
+	)?;
 
 	let sc_service::PartialComponents {
 		client,
@@ -429,6 +483,8 @@ pub async fn new_full<Network: sc_network::NetworkBackend<Block, <Block as Block
 			),
 	} = new_partial_components;
 
+	crate::ledger_consistency::ensure_ledger_consistency(&client)?;
+
 	let mut net_config = sc_network::config::FullNetworkConfiguration::<_, _, Network>::new(
 		&config.network,
 		config.prometheus_registry().cloned(),
@@ -527,6 +583,13 @@ pub async fn new_full<Network: sc_network::NetworkBackend<Block, <Block as Block
 	let prometheus_registry = config.prometheus_registry().cloned();
 	let shared_voter_state = SharedVoterState::empty();
 
+	let (manual_seal_sink, manual_seal_commands) = if sealing.is_some() {
+		let (sink, commands) = futures::channel::mpsc::channel(1024);
+		(Some(sink), Some(commands))
+	} else {
+		(None, None)
+	};
+
 	let rpc_extensions_builder = {
 		let client = client.clone();
 		let pool = transaction_pool.clone();
@@ -536,6 +599,7 @@ pub async fn new_full<Network: sc_network::NetworkBackend<Block, <Block as Block
 		let justification_stream = grandpa_link.justification_stream();
 		let main_chain_follower_data_sources = data_sources.clone();
 		let epoch_config = epoch_config.clone();
+		let manual_seal_sink = manual_seal_sink.clone();
 
 		move |subscription_executor: SubscriptionTaskExecutor| {
 			let grandpa = GrandpaDeps {
@@ -564,6 +628,7 @@ pub async fn new_full<Network: sc_network::NetworkBackend<Block, <Block as Block
 				time_source: Arc::new(SystemTimeSource),
 				main_chain_epoch_config: epoch_config.clone(),
 				backend: backend.clone(),
+				manual_seal_sink: manual_seal_sink.clone(),
 			};
 			crate::rpc::create_full(deps).map_err(Into::into)
 		}
@@ -584,7 +649,59 @@ pub async fn new_full<Network: sc_network::NetworkBackend<Block, <Block as Block
 		telemetry: telemetry.as_mut(),
 	})?;
 
-	if role.is_authority() {
+	if let Some(sealing) = sealing {
+		let basic_authorship_proposer_factory = sc_basic_authorship::ProposerFactory::new(
+			task_manager.spawn_handle(),
+			client.clone(),
+			transaction_pool.clone(),
+			prometheus_registry.as_ref(),
+			telemetry.as_ref().map(|x| x.handle()),
+		);
+		let proposer_factory: PartnerChainsProposerFactory<_, _, McHashInherentDigest> =
+			PartnerChainsProposerFactory::new(basic_authorship_proposer_factory);
+
+		let sc_slot_config = sidechain_slots::runtime_api_client::slot_config(&*client)
+			.map_err(sp_blockchain::Error::from)?;
+		let time_source = Arc::new(DevSealingTimeSource::new(&client, &sc_slot_config)?);
+		let inherent_config =
+			CreateInherentDataConfig::new(epoch_config, sc_slot_config, time_source);
+
+		let commands_stream = sealing_commands(
+			sealing,
+			manual_seal_commands.expect("manual seal channel is created when sealing is set"),
+			transaction_pool.import_notification_stream(),
+		);
+
+		log::info!("Sealing blocks in development mode: {sealing:?}");
+		let manual_seal =
+			sc_consensus_manual_seal::run_manual_seal(sc_consensus_manual_seal::ManualSealParams {
+				block_import: client.clone(),
+				env: proposer_factory,
+				client: client.clone(),
+				pool: transaction_pool.clone(),
+				commands_stream,
+				select_chain,
+				consensus_data_provider: Some(Box::new(AuraConsensusDataProvider::new(
+					client.clone(),
+				))),
+				create_inherent_data_providers: ProposalCIDP::new(
+					inherent_config,
+					client.clone(),
+					data_sources.mc_hash.clone(),
+					data_sources.authority_selection.clone(),
+					data_sources.cnight_observation.clone(),
+					data_sources.governed_map.clone(),
+					data_sources.federated_authority_observation.clone(),
+				),
+			});
+
+		// Sealed blocks are finalized by manual-seal, so it replaces both AURA and GRANDPA
+		task_manager.spawn_essential_handle().spawn_blocking(
+			"manual-seal",
+			Some("block-authoring"),
+			manual_seal,
+		);
+	} else if role.is_authority() {
 		let basic_authorship_proposer_factory = sc_basic_authorship::ProposerFactory::new(
 			task_manager.spawn_handle(),
 			client.clone(),
@@ -647,7 +764,7 @@ pub async fn new_full<Network: sc_network::NetworkBackend<Block, <Block as Block
 			.spawn_blocking("aura", Some("block-authoring"), aura);
 	}
 
-	if enable_grandpa {
+	if enable_grandpa && sealing.is_none() {
 		// if the node isn't actively participating in consensus then it doesn't
 		// need a keystore, regardless of which protocol we use below.
 		let keystore = if role.is_authority() { Some(keystore_container.keystore()) } else { None };
@@ -750,3 +867,146 @@ pub async fn new_full<Network: sc_network::NetworkBackend<Block, <Block as Block
 
 	Ok(task_manager)
 }
+
+/// Seal commands for the development sealing mode: `engine_createBlock` RPC calls, plus one per
+/// transaction pool import (`instant`) or per interval tick (`interval=<ms>`)
+fn sealing_commands<Imports: Stream + Send + 'static>(
+	sealing: Sealing,
+	rpc_commands: futures::channel::mpsc::Receiver<EngineCommand<<Block as BlockT>::Hash>>,
+	pool_imports: Imports,
+) -> Pin<Box<dyn Stream<Item = EngineCommand<<Block as BlockT>::Hash>> + Send>> {
+	let seal = |create_empty| EngineCommand::SealNewBlock {
+		create_empty,
+		finalize: true,
+		parent_hash: None,
+		sender: None,
+	};
+	match sealing {
+		Sealing::Instant => {
+			let imports = pool_imports.map(move |_| seal(false));
+			futures::stream::select(rpc_commands, imports).boxed()
+		},
+		Sealing::Manual => rpc_commands.boxed(),
+		Sealing::Interval(millis) => {
+			let ticks = futures::stream::unfold((), move |()| async move {
+				tokio::time::sleep(Duration::from_millis(millis)).await;
+				Some((seal(true), ()))
+			});
+			futures::stream::select(rpc_commands, ticks).boxed()
+		},
+	}
+}
+
+/// Time source for the development sealing mode.
+///
+/// Blocks can be sealed faster than once per slot, so every reading is at least one slot after the
+/// previous one. This keeps AURA slots and the ledger's block timestamps strictly increasing.
+/// Each sealed block reads the time once, so while blocks are sealed faster than once per slot the
+/// chain time runs ahead of the wall clock, by up to one slot per block. It only catches up once
+/// blocks are sealed slower than once per slot.
+struct DevSealingTimeSource {
+	slot_duration_millis: u64,
+	last_millis: Mutex<u64>,
+}
+
+impl DevSealingTimeSource {
+	/// Starts from the slot of the best block, which may be ahead of the wall clock after a
+	/// previous run
+	fn new(client: &FullClient, slot_config: &ScSlotConfig) -> Result<Self, ServiceError> {
+		let slot_duration_millis = slot_config.slot_duration.as_millis();
+		let best_hash = client.chain_info().best_hash;
+		let best_header = client
+			.header(best_hash)?
+			.ok_or_else(|| ServiceError::Other(format!("missing best block header {best_hash}")))?;
+		let best_slot = sc_consensus_aura::find_pre_digest::<Block, AuraSignature>(&best_header)
+			.map_err(|e| ServiceError::Other(format!("no AURA slot in best block: {e}")))?;
+		Ok(Self::starting_at(
+			slot_duration_millis,
+			u64::from(best_slot).saturating_mul(slot_duration_millis),
+		))
+	}
+
+	fn starting_at(slot_duration_millis: u64, last_millis: u64) -> Self {
+		Self { slot_duration_millis, last_millis: Mutex::new(last_millis) }
+	}
+}
+
+impl TimeSource for DevSealingTimeSource {
+	fn get_current_time_millis(&self) -> u64 {
+		let mut last_millis = self.last_millis.lock().expect("time source lock poisoned");
+		let now = SystemTimeSource
+			.get_current_time_millis()
+			.max(last_millis.saturating_add(self.slot_duration_millis));
+		*last_millis = now;
+		now
+	}
+}
+
+/// Interval between two measurements of the ledger arena database size
+const ARENA_DISK_SIZE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Periodically records the size of the ledger arena database under `path`
+async fn watch_arena_disk_size(metrics: LedgerMetrics, path: PathBuf) {
+	loop {
+		match midnight_primitives_ledger::disk_size(&path) {
+			Ok(size) => metrics.arena_disk_size.set(size),
+			Err(e) => {
+				log::debug!(target: "prometheus", "Failed to read the ledger arena size: {e}")
+			},
+		}
+		tokio::time::sleep(ARENA_DISK_SIZE_INTERVAL).await;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const SLOT_DURATION_MILLIS: u64 = 6000;
+
+	#[test]
+	fn dev_sealing_time_follows_the_wall_clock() {
+		let time_source = DevSealingTimeSource::starting_at(SLOT_DURATION_MILLIS, 0);
+
+		let before = SystemTimeSource.get_current_time_millis();
+		let now = time_source.get_current_time_millis();
+		assert!(now >= before);
+		assert!(now <= SystemTimeSource.get_current_time_millis());
+	}
+
+	#[test]
+	fn dev_sealing_time_is_monotonic() {
+		// Ahead of the wall clock, as after sealing blocks faster than once per slot
+		let start = SystemTimeSource.get_current_time_millis() + 10 * SLOT_DURATION_MILLIS;
+		let time_source = DevSealingTimeSource::starting_at(SLOT_DURATION_MILLIS, start);
+
+		let mut last = start;
+		for _ in 0..5 {
+			let now = time_source.get_current_time_millis();
+			assert_eq!(now, last + SLOT_DURATION_MILLIS);
+			last = now;
+		}
+	}
+
+	#[tokio::test]
+	async fn interval_sealing_seals_a_block_per_tick() {
+		let (_rpc_sender, rpc_commands) = futures::channel::mpsc::channel(1);
+		let mut commands =
+			sealing_commands(Sealing::Interval(10), rpc_commands, futures::stream::pending::<()>());
+
+		for _ in 0..3 {
+			let command = tokio::time::timeout(Duration::from_secs(1), commands.next())
+				.await
+				.expect("interval sealing should seal a block every 10ms");
+			assert!(matches!(
+				command,
+				Some(EngineCommand::SealNewBlock {
+					create_empty: true,
+					finalize: true,
+					parent_hash: None,
+					sender: None,
+				})
+			));
+		}
+	}
+}
@@ -101,6 +101,7 @@ impl Get<BlockReward> for LedgerBlockReward {
 impl pallet_midnight::Config for Test {
 	type BlockReward = LedgerBlockReward;
 	type SlotDuration = ConstU64<SLOT_DURATION>;
+	type WeightInfo = ();
 }
 
 impl pallet_midnight_system::Config for Test {
@@ -147,6 +148,7 @@ parameter_types! {
 
 impl pallet_cnight_observation::Config for Test {
 	type MidnightSystemTransactionExecutor = MidnightSystem;
+	type WeightInfo = ();
 }
 
 impl mock_pallet::Config for Test {}
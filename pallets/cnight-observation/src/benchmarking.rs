@@ -0,0 +1,101 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarking setup for pallet-cnight-observation
+
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+const MAPPING_VALIDATOR_ADDRESS: &str =
+	"addr_test1wp5ac30f95jxl6hj85d40h7rkyen6ksy3r8adqwcrr4xkncremdyk";
+const REDEMPTION_VALIDATOR_ADDRESS: &str =
+	"addr_test1wz3t0v4r0kwdfnh44m87z4rasp4nj0rcplfpmwxvhhrzhdgl45vx4";
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	/// Benchmark setting the mapping validator address
+	#[benchmark]
+	fn set_mapping_validator_contract_address() {
+		let address = MAPPING_VALIDATOR_ADDRESS.as_bytes().to_vec();
+
+		#[extrinsic_call]
+		set_mapping_validator_contract_address(RawOrigin::Root, address.clone());
+
+		// Verify the address was set
+		assert_eq!(MainChainMappingValidatorAddress::<T>::get().into_inner(), address);
+	}
+
+	/// Benchmark setting the redemption validator address
+	#[benchmark]
+	fn set_redemption_validator_contract_address() {
+		let address = REDEMPTION_VALIDATOR_ADDRESS.as_bytes().to_vec();
+
+		#[extrinsic_call]
+		set_redemption_validator_contract_address(RawOrigin::Root, address.clone());
+
+		// Verify the address was set
+		assert_eq!(MainChainRedemptionValidatorAddress::<T>::get().into_inner(), address);
+	}
+
+	/// Benchmark setting the auth token asset name (worst case: max length)
+	#[benchmark]
+	fn set_auth_token_asset_name() {
+		let asset_name = vec![b'a'; CARDANO_ASSET_NAME_MAX_LENGTH as usize];
+
+		#[extrinsic_call]
+		set_auth_token_asset_name(RawOrigin::Root, asset_name.clone());
+
+		// Verify the asset name was set
+		assert_eq!(MainChainAuthTokenAssetName::<T>::get().into_inner(), asset_name);
+	}
+
+	/// Benchmark setting the cNIGHT identifier (worst case: max length asset name)
+	#[benchmark]
+	fn set_cnight_identifier() {
+		let policy_id = vec![1u8; CARDANO_POLICY_ID_LENGTH as usize];
+		let asset_name = vec![b'a'; CARDANO_ASSET_NAME_MAX_LENGTH as usize];
+
+		#[extrinsic_call]
+		set_cnight_identifier(RawOrigin::Root, policy_id.clone(), asset_name.clone());
+
+		// Verify the identifier was set
+		let (stored_policy_id, stored_asset_name) = CNightIdentifier::<T>::get();
+		assert_eq!(stored_policy_id.into_inner(), policy_id);
+		assert_eq!(stored_asset_name.into_inner(), asset_name);
+	}
+
+	/// Benchmark setting the Cardano block window size
+	#[benchmark]
+	fn set_cardano_block_window_size() {
+		#[extrinsic_call]
+		set_cardano_block_window_size(RawOrigin::Root, 500);
+
+		// Verify the window size was set
+		assert_eq!(CardanoBlockWindowSize::<T>::get(), 500);
+	}
+
+	/// Benchmark setting the Cardano tx capacity per block
+	#[benchmark]
+	fn set_cardano_tx_capacity_per_block() {
+		#[extrinsic_call]
+		set_cardano_tx_capacity_per_block(RawOrigin::Root, 100);
+
+		// Verify the capacity was set
+		assert_eq!(CardanoTxCapacityPerBlock::<T>::get(), 100);
+	}
+}
@@ -27,7 +27,11 @@ pub use pallet::*;
 use serde::{Deserialize, Serialize};
 use sidechain_domain::McBlockHash;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 pub mod config;
+pub mod weights;
 
 /// Cardano-based Midnight System Transaction (CMST)  Header
 ///
@@ -56,6 +60,11 @@ pub enum UtxoActionType {
 pub const INITIAL_CARDANO_BLOCK_WINDOW_SIZE: u32 = 1000;
 pub const DEFAULT_CARDANO_TX_CAPACITY_PER_BLOCK: u32 = 200;
 
+/// Length of a Cardano minting policy id (blake2b-224 script hash)
+pub const CARDANO_POLICY_ID_LENGTH: u32 = 28;
+/// Max length of a Cardano native asset name
+pub const CARDANO_ASSET_NAME_MAX_LENGTH: u32 = 32;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::sp_runtime::traits::Hash;
@@ -76,12 +85,15 @@ pub mod pallet {
 	};
 
 	use crate::config::CNightGenesis;
+	use crate::weights::WeightInfo;
 
 	use super::*;
 
 	struct CNightGeneratesDustEventSerialized(Vec<u8>);
 
 	pub type BoundedCardanoAddress = BoundedVec<u8, ConstU32<CARDANO_BECH32_ADDRESS_MAX_LENGTH>>;
+	pub type BoundedPolicyId = BoundedVec<u8, ConstU32<CARDANO_POLICY_ID_LENGTH>>;
+	pub type BoundedAssetName = BoundedVec<u8, ConstU32<CARDANO_ASSET_NAME_MAX_LENGTH>>;
 
 	#[derive(
 		Debug,
@@ -130,6 +142,8 @@ pub mod pallet {
 	#[pallet::config]
 	pub trait Config: frame_system::Config<Hash = H256> {
 		type MidnightSystemTransactionExecutor: MidnightSystemTransactionExecutor;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::event]
@@ -140,6 +154,36 @@ pub mod pallet {
 		MappingAdded(MappingEntry),
 		MappingRemoved(MappingEntry),
 		SystemTransactionApplied(SystemTransactionApplied),
+		/// The mainchain address of the mapping validator contract was changed
+		MappingValidatorAddressChanged {
+			old: BoundedCardanoAddress,
+			new: BoundedCardanoAddress,
+		},
+		/// The mainchain address of the redemption validator contract was changed
+		RedemptionValidatorAddressChanged {
+			old: BoundedCardanoAddress,
+			new: BoundedCardanoAddress,
+		},
+		/// The asset name of the mapping validator auth token was changed
+		AuthTokenAssetNameChanged {
+			old: BoundedAssetName,
+			new: BoundedAssetName,
+		},
+		/// The (policy id, asset name) identifier of cNIGHT was changed
+		CNightIdentifierChanged {
+			old: (BoundedPolicyId, BoundedAssetName),
+			new: (BoundedPolicyId, BoundedAssetName),
+		},
+		/// The amount of Cardano blocks queried per inherent was changed
+		CardanoBlockWindowSizeChanged {
+			old: u32,
+			new: u32,
+		},
+		/// The max amount of Cardano transactions processed per block was changed
+		CardanoTxCapacityPerBlockChanged {
+			old: u32,
+			new: u32,
+		},
 	}
 
 	#[pallet::error]
@@ -148,6 +192,16 @@ pub mod pallet {
 		MaxCardanoAddrLengthExceeded,
 		MaxRegistrationsExceeded,
 		LedgerApiError(LedgerApiError),
+		/// A Cardano address was sent, but it is not a valid Bech32 Shelley address
+		InvalidCardanoAddress,
+		/// A Cardano policy id was sent, but it is not exactly 28 bytes long
+		InvalidPolicyIdLength,
+		/// A Cardano asset name was sent, but was longer than 32 bytes
+		MaxAssetNameLengthExceeded,
+		/// The Cardano block window size must be greater than zero
+		InvalidCardanoBlockWindowSize,
+		/// The Cardano transaction capacity per block must be greater than zero
+		InvalidCardanoTxCapacityPerBlock,
 	}
 
 	impl<T: Config> From<LedgerApiError> for Error<T> {
@@ -163,8 +217,7 @@ pub mod pallet {
 
 	#[pallet::storage]
 	// Asset name for auth token used in MappingValidator
-	pub type MainChainAuthTokenAssetName<T: Config> =
-		StorageValue<_, BoundedVec<u8, ConstU32<32>>, ValueQuery>;
+	pub type MainChainAuthTokenAssetName<T: Config> = StorageValue<_, BoundedAssetName, ValueQuery>;
 
 	#[pallet::storage]
 	// Script address for executing Glacier Drop redemptions on Cardano
@@ -190,9 +243,9 @@ pub mod pallet {
 		_,
 		(
 			// Policy ID
-			BoundedVec<u8, ConstU32<28>>,
+			BoundedPolicyId,
 			// Asset Name
-			BoundedVec<u8, ConstU32<32>>,
+			BoundedAssetName,
 		),
 		ValueQuery,
 	>;
@@ -330,6 +383,36 @@ pub mod pallet {
 				.expect("Token transfer data not encoded correctly")
 		}
 
+		/// Checks that `address` is a Bech32 encoded Shelley address (`addr` or `addr_test`)
+		/// which fits into storage
+		pub fn validate_cardano_address(address: &[u8]) -> Result<BoundedCardanoAddress, Error<T>> {
+			let bounded: BoundedCardanoAddress = address
+				.to_vec()
+				.try_into()
+				.map_err(|_| Error::<T>::MaxCardanoAddrLengthExceeded)?;
+			let address =
+				core::str::from_utf8(address).map_err(|_| Error::<T>::InvalidCardanoAddress)?;
+			let (hrp, _) =
+				bech32::decode(address).map_err(|_| Error::<T>::InvalidCardanoAddress)?;
+			ensure!(
+				matches!(hrp.as_str(), "addr" | "addr_test"),
+				Error::<T>::InvalidCardanoAddress
+			);
+			Ok(bounded)
+		}
+
+		fn validate_asset_name(asset_name: Vec<u8>) -> Result<BoundedAssetName, Error<T>> {
+			asset_name.try_into().map_err(|_| Error::<T>::MaxAssetNameLengthExceeded)
+		}
+
+		fn validate_policy_id(policy_id: Vec<u8>) -> Result<BoundedPolicyId, Error<T>> {
+			ensure!(
+				policy_id.len() == CARDANO_POLICY_ID_LENGTH as usize,
+				Error::<T>::InvalidPolicyIdLength
+			);
+			policy_id.try_into().map_err(|_| Error::<T>::InvalidPolicyIdLength)
+		}
+
 		pub fn get_registration(wallet: &CardanoRewardAddressBytes) -> Option<DustPublicKeyBytes> {
 			let mappings = Mappings::<T>::get(wallet);
 			if mappings.len() == 1 { Some(mappings[0].dust_public_key) } else { None }
@@ -671,19 +754,115 @@ pub mod pallet {
 		///
 		/// This extrinsic must be run either using `sudo` or some other chain governance mechanism.
 		#[pallet::call_index(2)]
-		#[pallet::weight((1, DispatchClass::Normal))]
+		#[pallet::weight((T::WeightInfo::set_mapping_validator_contract_address(), DispatchClass::Normal))]
 		pub fn set_mapping_validator_contract_address(
 			origin: OriginFor<T>,
 			address: Vec<u8>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
-			MainChainMappingValidatorAddress::<T>::set(
-				address
-					.clone()
-					.try_into()
-					.expect("Mainchain contract address longer than expected"),
-			);
+			let new = Self::validate_cardano_address(&address)?;
+			let old = MainChainMappingValidatorAddress::<T>::get();
+			MainChainMappingValidatorAddress::<T>::set(new.clone());
 
+			Self::deposit_event(Event::<T>::MappingValidatorAddressChanged { old, new });
+			Ok(())
+		}
+
+		/// Changes the mainchain address for the Glacier Drop redemption validator contract
+		///
+		/// This extrinsic must be run either using `sudo` or some other chain governance mechanism.
+		#[pallet::call_index(3)]
+		#[pallet::weight((T::WeightInfo::set_redemption_validator_contract_address(), DispatchClass::Normal))]
+		pub fn set_redemption_validator_contract_address(
+			origin: OriginFor<T>,
+			address: Vec<u8>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let new = Self::validate_cardano_address(&address)?;
+			let old = MainChainRedemptionValidatorAddress::<T>::get();
+			MainChainRedemptionValidatorAddress::<T>::set(new.clone());
+
+			Self::deposit_event(Event::<T>::RedemptionValidatorAddressChanged { old, new });
+			Ok(())
+		}
+
+		/// Changes the asset name of the auth token used by the mapping validator contract
+		///
+		/// This extrinsic must be run either using `sudo` or some other chain governance mechanism.
+		#[pallet::call_index(4)]
+		#[pallet::weight((T::WeightInfo::set_auth_token_asset_name(), DispatchClass::Normal))]
+		pub fn set_auth_token_asset_name(
+			origin: OriginFor<T>,
+			asset_name: Vec<u8>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let new = Self::validate_asset_name(asset_name)?;
+			let old = MainChainAuthTokenAssetName::<T>::get();
+			MainChainAuthTokenAssetName::<T>::set(new.clone());
+
+			Self::deposit_event(Event::<T>::AuthTokenAssetNameChanged { old, new });
+			Ok(())
+		}
+
+		/// Changes the (policy id, asset name) identifier of the cNIGHT token on Cardano
+		///
+		/// This extrinsic must be run either using `sudo` or some other chain governance mechanism.
+		#[pallet::call_index(5)]
+		#[pallet::weight((T::WeightInfo::set_cnight_identifier(), DispatchClass::Normal))]
+		pub fn set_cnight_identifier(
+			origin: OriginFor<T>,
+			policy_id: Vec<u8>,
+			asset_name: Vec<u8>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let new =
+				(Self::validate_policy_id(policy_id)?, Self::validate_asset_name(asset_name)?);
+			let old = CNightIdentifier::<T>::get();
+			CNightIdentifier::<T>::set(new.clone());
+
+			Self::deposit_event(Event::<T>::CNightIdentifierChanged { old, new });
+			Ok(())
+		}
+
+		/// Changes the amount of Cardano blocks the observation inherent data provider looks at
+		///
+		/// This extrinsic must be run either using `sudo` or some other chain governance mechanism.
+		#[pallet::call_index(6)]
+		#[pallet::weight((T::WeightInfo::set_cardano_block_window_size(), DispatchClass::Normal))]
+		pub fn set_cardano_block_window_size(
+			origin: OriginFor<T>,
+			window_size: u32,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(window_size > 0, Error::<T>::InvalidCardanoBlockWindowSize);
+			let old = CardanoBlockWindowSize::<T>::get();
+			CardanoBlockWindowSize::<T>::set(window_size);
+
+			Self::deposit_event(Event::<T>::CardanoBlockWindowSizeChanged {
+				old,
+				new: window_size,
+			});
+			Ok(())
+		}
+
+		/// Changes the max amount of Cardano transactions that can be processed per block
+		///
+		/// This extrinsic must be run either using `sudo` or some other chain governance mechanism.
+		#[pallet::call_index(7)]
+		#[pallet::weight((T::WeightInfo::set_cardano_tx_capacity_per_block(), DispatchClass::Normal))]
+		pub fn set_cardano_tx_capacity_per_block(
+			origin: OriginFor<T>,
+			capacity: u32,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(capacity > 0, Error::<T>::InvalidCardanoTxCapacityPerBlock);
+			let old = CardanoTxCapacityPerBlock::<T>::get();
+			CardanoTxCapacityPerBlock::<T>::set(capacity);
+
+			Self::deposit_event(Event::<T>::CardanoTxCapacityPerBlockChanged {
+				old,
+				new: capacity,
+			});
 			Ok(())
 		}
 	}
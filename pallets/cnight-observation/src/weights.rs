@@ -0,0 +1,102 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_cnight_observation`
+//!
+//! These weights are estimates, not benchmark results: every setter validates its argument, then
+//! reads and writes a single storage value. They use a fixed execution time of
+//! `SETTER_REF_TIME`, one read and one write, and a proof size bounded by the max encoded length
+//! of the stored value. Every setter has a benchmark in `benchmarking.rs`; `earthly
+//! +rebuild-weights` runs them and replaces this file with their output.
+
+use core::marker::PhantomData;
+use frame_support::{
+	traits::Get,
+	weights::{Weight, constants::ParityDbWeight},
+};
+
+/// Execution time of a setter, in picoseconds, excluding storage access
+const SETTER_REF_TIME: u64 = 10_000_000;
+
+/// Weight functions needed for `pallet_cnight_observation`.
+pub trait WeightInfo {
+	fn set_mapping_validator_contract_address() -> Weight;
+	fn set_redemption_validator_contract_address() -> Weight;
+	fn set_auth_token_asset_name() -> Weight;
+	fn set_cnight_identifier() -> Weight;
+	fn set_cardano_block_window_size() -> Weight;
+	fn set_cardano_tx_capacity_per_block() -> Weight;
+}
+
+/// Weights for `pallet_cnight_observation` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `CNightObservation::MainChainMappingValidatorAddress` (r:1 w:1)
+	fn set_mapping_validator_contract_address() -> Weight {
+		Weight::from_parts(SETTER_REF_TIME, 1595)
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	/// Storage: `CNightObservation::MainChainRedemptionValidatorAddress` (r:1 w:1)
+	fn set_redemption_validator_contract_address() -> Weight {
+		Weight::from_parts(SETTER_REF_TIME, 1595)
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	/// Storage: `CNightObservation::MainChainAuthTokenAssetName` (r:1 w:1)
+	fn set_auth_token_asset_name() -> Weight {
+		Weight::from_parts(SETTER_REF_TIME, 1518)
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	/// Storage: `CNightObservation::CNightIdentifier` (r:1 w:1)
+	fn set_cnight_identifier() -> Weight {
+		Weight::from_parts(SETTER_REF_TIME, 1547)
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	/// Storage: `CNightObservation::CardanoBlockWindowSize` (r:1 w:1)
+	fn set_cardano_block_window_size() -> Weight {
+		Weight::from_parts(SETTER_REF_TIME, 1489)
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	/// Storage: `CNightObservation::CardanoTxCapacityPerBlock` (r:1 w:1)
+	fn set_cardano_tx_capacity_per_block() -> Weight {
+		Weight::from_parts(SETTER_REF_TIME, 1489)
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn set_mapping_validator_contract_address() -> Weight {
+		Weight::from_parts(SETTER_REF_TIME, 1595)
+			.saturating_add(ParityDbWeight::get().reads_writes(1, 1))
+	}
+	fn set_redemption_validator_contract_address() -> Weight {
+		Weight::from_parts(SETTER_REF_TIME, 1595)
+			.saturating_add(ParityDbWeight::get().reads_writes(1, 1))
+	}
+	fn set_auth_token_asset_name() -> Weight {
+		Weight::from_parts(SETTER_REF_TIME, 1518)
+			.saturating_add(ParityDbWeight::get().reads_writes(1, 1))
+	}
+	fn set_cnight_identifier() -> Weight {
+		Weight::from_parts(SETTER_REF_TIME, 1547)
+			.saturating_add(ParityDbWeight::get().reads_writes(1, 1))
+	}
+	fn set_cardano_block_window_size() -> Weight {
+		Weight::from_parts(SETTER_REF_TIME, 1489)
+			.saturating_add(ParityDbWeight::get().reads_writes(1, 1))
+	}
+	fn set_cardano_tx_capacity_per_block() -> Weight {
+		Weight::from_parts(SETTER_REF_TIME, 1489)
+			.saturating_add(ParityDbWeight::get().reads_writes(1, 1))
+	}
+}
@@ -11,7 +11,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use frame_support::{
-	assert_ok, inherent::InherentData, pallet_prelude::*, sp_runtime::traits::Dispatchable,
+	assert_noop, assert_ok,
+	inherent::InherentData,
+	pallet_prelude::*,
+	sp_runtime::{DispatchError, traits::Dispatchable},
 };
 use midnight_node_ledger::types::BlockContext;
 use midnight_node_ledger_helpers::{
@@ -30,7 +33,7 @@ use midnight_primitives_mainchain_follower::{
 };
 use pallet_cnight_observation::*;
 use pallet_cnight_observation_mock::mock::{
-	self, CNightObservation, RuntimeCall, RuntimeEvent, System, Test, new_test_ext,
+	self, CNightObservation, RuntimeCall, RuntimeEvent, RuntimeOrigin, System, Test, new_test_ext,
 };
 use rand::prelude::*;
 use sidechain_domain::{McBlockHash, McTxHash};
@@ -785,6 +788,224 @@ fn emits_deregistration_and_mapping_removed_on_last_mapping_removed() {
 	});
 }
 
+const MAPPING_VALIDATOR_ADDRESS: &str =
+	"addr_test1wp5ac30f95jxl6hj85d40h7rkyen6ksy3r8adqwcrr4xkncremdyk";
+const REDEMPTION_VALIDATOR_ADDRESS: &str =
+	"addr_test1wz3t0v4r0kwdfnh44m87z4rasp4nj0rcplfpmwxvhhrzhdgl45vx4";
+
+#[test]
+fn set_mapping_validator_contract_address_should_update_storage_and_emit_event() {
+	new_test_ext().execute_with(|| {
+		let old = MainChainMappingValidatorAddress::<Test>::get();
+		let address = MAPPING_VALIDATOR_ADDRESS.as_bytes().to_vec();
+
+		assert_ok!(CNightObservation::set_mapping_validator_contract_address(
+			RuntimeOrigin::root(),
+			address.clone()
+		));
+
+		let new = MainChainMappingValidatorAddress::<Test>::get();
+		assert_eq!(new.clone().into_inner(), address);
+		System::assert_last_event(RuntimeEvent::CNightObservation(
+			Event::MappingValidatorAddressChanged { old, new },
+		));
+	});
+}
+
+#[test]
+fn set_redemption_validator_contract_address_should_update_storage_and_emit_event() {
+	new_test_ext().execute_with(|| {
+		let old = MainChainRedemptionValidatorAddress::<Test>::get();
+		let address = REDEMPTION_VALIDATOR_ADDRESS.as_bytes().to_vec();
+
+		assert_ok!(CNightObservation::set_redemption_validator_contract_address(
+			RuntimeOrigin::root(),
+			address.clone()
+		));
+
+		let new = MainChainRedemptionValidatorAddress::<Test>::get();
+		assert_eq!(new.clone().into_inner(), address);
+		System::assert_last_event(RuntimeEvent::CNightObservation(
+			Event::RedemptionValidatorAddressChanged { old, new },
+		));
+	});
+}
+
+#[test]
+fn set_contract_address_should_reject_invalid_addresses() {
+	new_test_ext().execute_with(|| {
+		// Not bech32 at all
+		assert_noop!(
+			CNightObservation::set_mapping_validator_contract_address(
+				RuntimeOrigin::root(),
+				b"nonsense".to_vec()
+			),
+			Error::<Test>::InvalidCardanoAddress
+		);
+
+		// Bad checksum
+		let mut bad_checksum = REDEMPTION_VALIDATOR_ADDRESS.as_bytes().to_vec();
+		let last = bad_checksum.len() - 1;
+		bad_checksum[last] = b'q';
+		assert_noop!(
+			CNightObservation::set_redemption_validator_contract_address(
+				RuntimeOrigin::root(),
+				bad_checksum
+			),
+			Error::<Test>::InvalidCardanoAddress
+		);
+
+		// Valid bech32, but not a Shelley address
+		assert_noop!(
+			CNightObservation::set_mapping_validator_contract_address(
+				RuntimeOrigin::root(),
+				b"stake_test1uqfu74w3wh4gfzu8m6e7j987h4lq9r3t7ef5gaw497uu85qsqfy27".to_vec()
+			),
+			Error::<Test>::InvalidCardanoAddress
+		);
+
+		// Too long for storage
+		assert_noop!(
+			CNightObservation::set_mapping_validator_contract_address(
+				RuntimeOrigin::root(),
+				vec![b'a'; 200]
+			),
+			Error::<Test>::MaxCardanoAddrLengthExceeded
+		);
+	});
+}
+
+#[test]
+fn set_cnight_identifier_should_validate_policy_id_and_asset_name() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CNightObservation::set_cnight_identifier(
+				RuntimeOrigin::root(),
+				vec![1u8; 27],
+				b"NIGHT".to_vec()
+			),
+			Error::<Test>::InvalidPolicyIdLength
+		);
+		assert_noop!(
+			CNightObservation::set_cnight_identifier(
+				RuntimeOrigin::root(),
+				vec![1u8; 28],
+				vec![b'a'; 33]
+			),
+			Error::<Test>::MaxAssetNameLengthExceeded
+		);
+
+		let old = CNightIdentifier::<Test>::get();
+		assert_ok!(CNightObservation::set_cnight_identifier(
+			RuntimeOrigin::root(),
+			vec![1u8; 28],
+			b"NIGHT".to_vec()
+		));
+
+		let new = CNightIdentifier::<Test>::get();
+		assert_eq!(new.0.clone().into_inner(), vec![1u8; 28]);
+		assert_eq!(new.1.clone().into_inner(), b"NIGHT".to_vec());
+		System::assert_last_event(RuntimeEvent::CNightObservation(
+			Event::CNightIdentifierChanged { old, new },
+		));
+	});
+}
+
+#[test]
+fn set_auth_token_asset_name_should_update_storage_and_emit_event() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CNightObservation::set_auth_token_asset_name(RuntimeOrigin::root(), vec![b'a'; 33]),
+			Error::<Test>::MaxAssetNameLengthExceeded
+		);
+
+		let old = MainChainAuthTokenAssetName::<Test>::get();
+		assert_ok!(CNightObservation::set_auth_token_asset_name(
+			RuntimeOrigin::root(),
+			b"auth".to_vec()
+		));
+
+		let new = MainChainAuthTokenAssetName::<Test>::get();
+		assert_eq!(new.clone().into_inner(), b"auth".to_vec());
+		System::assert_last_event(RuntimeEvent::CNightObservation(
+			Event::AuthTokenAssetNameChanged { old, new },
+		));
+	});
+}
+
+#[test]
+fn set_cardano_window_parameters_should_reject_zero() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CNightObservation::set_cardano_block_window_size(RuntimeOrigin::root(), 0),
+			Error::<Test>::InvalidCardanoBlockWindowSize
+		);
+		assert_noop!(
+			CNightObservation::set_cardano_tx_capacity_per_block(RuntimeOrigin::root(), 0),
+			Error::<Test>::InvalidCardanoTxCapacityPerBlock
+		);
+
+		assert_ok!(CNightObservation::set_cardano_block_window_size(RuntimeOrigin::root(), 42));
+		assert_eq!(CardanoBlockWindowSize::<Test>::get(), 42);
+		System::assert_last_event(RuntimeEvent::CNightObservation(
+			Event::CardanoBlockWindowSizeChanged {
+				old: INITIAL_CARDANO_BLOCK_WINDOW_SIZE,
+				new: 42,
+			},
+		));
+
+		assert_ok!(CNightObservation::set_cardano_tx_capacity_per_block(RuntimeOrigin::root(), 7));
+		assert_eq!(CardanoTxCapacityPerBlock::<Test>::get(), 7);
+		System::assert_last_event(RuntimeEvent::CNightObservation(
+			Event::CardanoTxCapacityPerBlockChanged {
+				old: DEFAULT_CARDANO_TX_CAPACITY_PER_BLOCK,
+				new: 7,
+			},
+		));
+	});
+}
+
+#[test]
+fn configuration_setters_should_require_root() {
+	new_test_ext().execute_with(|| {
+		let address = MAPPING_VALIDATOR_ADDRESS.as_bytes().to_vec();
+		assert_noop!(
+			CNightObservation::set_mapping_validator_contract_address(
+				RuntimeOrigin::signed(1),
+				address.clone()
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CNightObservation::set_redemption_validator_contract_address(
+				RuntimeOrigin::signed(1),
+				address
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CNightObservation::set_auth_token_asset_name(RuntimeOrigin::signed(1), vec![]),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CNightObservation::set_cnight_identifier(
+				RuntimeOrigin::signed(1),
+				vec![1u8; 28],
+				vec![]
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CNightObservation::set_cardano_block_window_size(RuntimeOrigin::signed(1), 1),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CNightObservation::set_cardano_tx_capacity_per_block(RuntimeOrigin::signed(1), 1),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
 // #[test]
 // fn no_registered_event_when_still_invalid_after_removal() {
 // 	new_test_ext().execute_with(|| {
@@ -20,7 +20,7 @@ use core::str::FromStr;
 use frame_benchmarking::{account, v2::*};
 use frame_system::RawOrigin;
 use midnight_primitives_federated_authority_observation::MainchainMember;
-use sidechain_domain::{MainchainAddress, PolicyId};
+use sidechain_domain::{MainchainAddress, McBlockHash, PolicyId};
 
 /// Helper function to generate accounts with mainchain members
 fn generate_accounts_with_mainchain<T: Config>(count: u32) -> Vec<(T::AccountId, MainchainMember)> {
@@ -35,6 +35,24 @@ fn generate_accounts_with_mainchain<T: Config>(count: u32) -> Vec<(T::AccountId,
 		.collect()
 }
 
+/// Helper function to fill `MembershipHistory` with changes of the largest size, so that recording
+/// a new change has to drop the oldest one
+fn fill_membership_history<T: Config>() {
+	let added = generate_accounts_with_mainchain::<T>(T::CouncilMaxMembers::get())
+		.into_iter()
+		.map(|(account_id, _)| account_id)
+		.collect();
+	let change = MembershipChange {
+		body: GovernanceBody::Council,
+		block_number: frame_system::Pallet::<T>::block_number(),
+		mc_block_hash: McBlockHash([0u8; 32]),
+		added,
+		removed: Vec::new(),
+	};
+
+	MembershipHistory::<T>::mutate(|history| while history.try_push(change.clone()).is_ok() {});
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -51,17 +69,20 @@ mod benchmarks {
 		let initial_council = generate_accounts_with_mainchain::<T>(a + 1);
 		let initial_tc = generate_accounts_with_mainchain::<T>(b);
 
-		let _ = FederatedAuthorityObservation::<T>::reset_members(
+		let _ = FederatedAuthorityObservation::<T>::reset_members_at(
 			RawOrigin::None.into(),
 			initial_council,
 			initial_tc.clone(),
+			McBlockHash([0u8; 32]),
 		);
 
+		fill_membership_history::<T>();
+
 		// Create new council members
 		let new_council_members = generate_accounts_with_mainchain::<T>(a);
 
 		#[extrinsic_call]
-		reset_members(RawOrigin::None, new_council_members, initial_tc);
+		reset_members_at(RawOrigin::None, new_council_members, initial_tc, McBlockHash([0u8; 32]));
 
 		// Verify the council members were changed
 		let current_council = T::CouncilMembershipHandler::sorted_members();
@@ -80,24 +101,27 @@ mod benchmarks {
 		let initial_council = generate_accounts_with_mainchain::<T>(a);
 		let initial_tc = generate_accounts_with_mainchain::<T>(b + 1);
 
-		let _ = FederatedAuthorityObservation::<T>::reset_members(
+		let _ = FederatedAuthorityObservation::<T>::reset_members_at(
 			RawOrigin::None.into(),
 			initial_council.clone(),
 			initial_tc,
+			McBlockHash([0u8; 32]),
 		);
 
+		fill_membership_history::<T>();
+
 		// Create new TC members
 		let new_tc_members = generate_accounts_with_mainchain::<T>(b);
 
 		#[extrinsic_call]
-		reset_members(RawOrigin::None, initial_council, new_tc_members);
+		reset_members_at(RawOrigin::None, initial_council, new_tc_members, McBlockHash([0u8; 32]));
 
 		// Verify the TC members were changed
 		let current_tc = T::TechnicalCommitteeMembershipHandler::sorted_members();
 		assert_eq!(current_tc.len(), b as usize);
 	}
 
-	/// Benchmark resetting both Council and Technical Committee members
+	/// Benchmark resetting both Council and Technical Committee members through the legacy call
 	/// Variable `a`: Number of council members to reset
 	/// Variable `b`: Number of technical committee members to reset
 	#[benchmark]
@@ -109,12 +133,15 @@ mod benchmarks {
 		let initial_council = generate_accounts_with_mainchain::<T>(a + 1);
 		let initial_tc = generate_accounts_with_mainchain::<T>(b + 1);
 
-		let _ = FederatedAuthorityObservation::<T>::reset_members(
+		let _ = FederatedAuthorityObservation::<T>::reset_members_at(
 			RawOrigin::None.into(),
 			initial_council,
 			initial_tc,
+			McBlockHash([0u8; 32]),
 		);
 
+		fill_membership_history::<T>();
+
 		// Create new members for both committees
 		let new_council_members = generate_accounts_with_mainchain::<T>(a);
 		let new_tc_members = generate_accounts_with_mainchain::<T>(b);
@@ -129,6 +156,46 @@ mod benchmarks {
 		assert_eq!(tc_current.len(), b as usize);
 	}
 
+	/// Benchmark resetting both Council and Technical Committee members at a main chain block
+	/// Variable `a`: Number of council members to reset
+	/// Variable `b`: Number of technical committee members to reset
+	#[benchmark]
+	fn reset_members_at(
+		a: Linear<1, { T::CouncilMaxMembers::get() - 1 }>,
+		b: Linear<1, { T::TechnicalCommitteeMaxMembers::get() - 1 }>,
+	) {
+		// Setup: Create initial state with some members
+		let initial_council = generate_accounts_with_mainchain::<T>(a + 1);
+		let initial_tc = generate_accounts_with_mainchain::<T>(b + 1);
+
+		let _ = FederatedAuthorityObservation::<T>::reset_members_at(
+			RawOrigin::None.into(),
+			initial_council,
+			initial_tc,
+			McBlockHash([0u8; 32]),
+		);
+
+		fill_membership_history::<T>();
+
+		// Create new members for both committees
+		let new_council_members = generate_accounts_with_mainchain::<T>(a);
+		let new_tc_members = generate_accounts_with_mainchain::<T>(b);
+
+		#[extrinsic_call]
+		reset_members_at(
+			RawOrigin::None,
+			new_council_members,
+			new_tc_members,
+			McBlockHash([1u8; 32]),
+		);
+
+		// Verify both were changed
+		let council_current = T::CouncilMembershipHandler::sorted_members();
+		let tc_current = T::TechnicalCommitteeMembershipHandler::sorted_members();
+		assert_eq!(council_current.len(), a as usize);
+		assert_eq!(tc_current.len(), b as usize);
+	}
+
 	/// Benchmark no-op call (no changes for either committee)
 	#[benchmark]
 	fn reset_members_none(
@@ -139,14 +206,20 @@ mod benchmarks {
 		let council_members = generate_accounts_with_mainchain::<T>(a);
 		let tc_members = generate_accounts_with_mainchain::<T>(b);
 
-		let _ = FederatedAuthorityObservation::<T>::reset_members(
+		let _ = FederatedAuthorityObservation::<T>::reset_members_at(
 			RawOrigin::None.into(),
 			council_members.clone(),
 			tc_members.clone(),
+			McBlockHash([0u8; 32]),
 		);
 
 		#[extrinsic_call]
-		reset_members(RawOrigin::None, council_members.clone(), tc_members.clone());
+		reset_members_at(
+			RawOrigin::None,
+			council_members.clone(),
+			tc_members.clone(),
+			McBlockHash([0u8; 32]),
+		);
 
 		// Verify nothing changed
 		let council_current = T::CouncilMembershipHandler::sorted_members();
@@ -215,6 +288,16 @@ mod benchmarks {
 		assert_eq!(MainChainTechnicalCommitteePolicyId::<T>::get(), policy_id);
 	}
 
+	/// Benchmark allowing an empty membership to wipe the Council
+	#[benchmark]
+	fn set_allow_empty_members() {
+		#[extrinsic_call]
+		set_allow_empty_members(RawOrigin::Root, GovernanceBody::Council, true);
+
+		// Verify the permission was set
+		assert!(AllowEmptyMembers::<T>::get(GovernanceBody::Council));
+	}
+
 	impl_benchmark_test_suite!(
 		FederatedAuthorityObservation,
 		crate::mock::new_test_ext(),
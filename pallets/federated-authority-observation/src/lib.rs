@@ -28,7 +28,7 @@ use midnight_primitives_federated_authority_observation::{
 	MainchainMember,
 };
 pub use pallet::*;
-use sidechain_domain::{MainchainAddress, PolicyId};
+use sidechain_domain::{MainchainAddress, McBlockHash, PolicyId};
 use sp_std::vec::Vec;
 
 #[cfg(test)]
@@ -42,6 +42,36 @@ mod benchmarking;
 
 pub mod weights;
 
+/// Governance bodies whose membership is observed from the main chain
+#[derive(
+	Clone,
+	Copy,
+	Debug,
+	PartialEq,
+	Eq,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum GovernanceBody {
+	Council,
+	TechnicalCommittee,
+}
+
+/// A membership change applied to a governance body, as kept in `MembershipHistory`
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct MembershipChange<AccountId, BlockNumber> {
+	pub body: GovernanceBody,
+	/// Block in which the change was applied
+	pub block_number: BlockNumber,
+	/// Main chain block the new membership was observed at
+	pub mc_block_hash: McBlockHash,
+	pub added: Vec<AccountId>,
+	pub removed: Vec<AccountId>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -77,6 +107,20 @@ pub mod pallet {
 	pub type TechnicalCommitteeMainchainMembers<T: Config> =
 		StorageValue<_, BoundedVec<MainchainMember, T::TechnicalCommitteeMaxMembers>, ValueQuery>;
 
+	#[pallet::storage]
+	/// Latest membership changes, oldest first, bounded by `T::MaxMembershipHistory`
+	pub type MembershipHistory<T: Config> = StorageValue<
+		_,
+		BoundedVec<MembershipChange<T::AccountId, BlockNumberFor<T>>, T::MaxMembershipHistory>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Governance bodies allowed to have their members wiped by an empty observed membership.
+	/// The permission is consumed once the empty membership has been applied
+	pub type AllowEmptyMembers<T: Config> =
+		StorageMap<_, Twox64Concat, GovernanceBody, bool, ValueQuery>;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// The MAX number of members for the Council
@@ -91,6 +135,9 @@ pub mod pallet {
 		/// The receiver of the signal for when the Technical Committee membership has changed.
 		type TechnicalCommitteeMembershipHandler: ChangeMembers<Self::AccountId>
 			+ SortedMembers<Self::AccountId>;
+		/// The MAX number of membership changes kept in `MembershipHistory`
+		#[pallet::constant]
+		type MaxMembershipHistory: Get<u32>;
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -156,13 +203,24 @@ pub mod pallet {
 			members: BoundedVec<T::AccountId, T::TechnicalCommitteeMaxMembers>,
 			members_mainchain: BoundedVec<MainchainMember, T::TechnicalCommitteeMaxMembers>,
 		},
+		/// A member was added to a governance body
+		MemberAdded {
+			body: GovernanceBody,
+			member: T::AccountId,
+			member_mainchain: MainchainMember,
+		},
+		/// A member was removed from a governance body
+		MemberRemoved { body: GovernanceBody, member: T::AccountId },
+		/// Permission for an empty observed membership to wipe a governance body was changed
+		AllowEmptyMembersSet { body: GovernanceBody, allowed: bool },
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Too many members.
 		TooManyMembers,
-		/// Membership set is empty
+		/// Membership set is empty and wiping the governance body was not allowed with
+		/// `set_allow_empty_members`
 		EmptyMembers,
 	}
 
@@ -171,19 +229,46 @@ pub mod pallet {
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
+		/// Legacy version of `reset_members_at`, kept so that inherents created before the main
+		/// chain block hash was recorded still decode and dispatch. The membership change is
+		/// recorded in `MembershipHistory` with a zero main chain block hash
 		#[pallet::call_index(0)]
 		#[pallet::weight((
 		T::WeightInfo::reset_members(T::CouncilMaxMembers::get(), T::TechnicalCommitteeMaxMembers::get()),
 		DispatchClass::Mandatory
 		))]
-		#[allow(clippy::useless_conversion)]
 		pub fn reset_members(
 			origin: OriginFor<T>,
 			council_authorities: Vec<(T::AccountId, MainchainMember)>,
 			technical_committee_authorities: Vec<(T::AccountId, MainchainMember)>,
+		) -> DispatchResultWithPostInfo {
+			Self::reset_members_at(
+				origin,
+				council_authorities,
+				technical_committee_authorities,
+				McBlockHash([0u8; 32]),
+			)
+		}
+
+		/// Resets the governance bodies members to the ones observed on the main chain at
+		/// `mc_block_hash`
+		#[pallet::call_index(6)]
+		#[pallet::weight((
+		T::WeightInfo::reset_members_at(T::CouncilMaxMembers::get(), T::TechnicalCommitteeMaxMembers::get()),
+		DispatchClass::Mandatory
+		))]
+		#[allow(clippy::useless_conversion)]
+		pub fn reset_members_at(
+			origin: OriginFor<T>,
+			council_authorities: Vec<(T::AccountId, MainchainMember)>,
+			technical_committee_authorities: Vec<(T::AccountId, MainchainMember)>,
+			mc_block_hash: McBlockHash,
 		) -> DispatchResultWithPostInfo {
 			ensure_none(origin)?;
 
+			let council_mainchain_by_account = council_authorities.clone();
+			let technical_committee_mainchain_by_account = technical_committee_authorities.clone();
+
 			let (council_account_ids, council_mainchain_members): (Vec<_>, Vec<_>) =
 				council_authorities.into_iter().unzip();
 			let (technical_committee_account_ids, technical_committee_mainchain_members): (
@@ -196,8 +281,11 @@ pub mod pallet {
 				BoundedVec::try_from(council_account_ids.clone())
 					.map_err(|_| Error::<T>::TooManyMembers)?;
 
-			// Make sure an empty set of members is not allowed
-			ensure!(!council_members.is_empty(), Error::<T>::EmptyMembers);
+			// Make sure an empty set of members is not allowed, unless explicitly permitted
+			ensure!(
+				!council_members.is_empty() || AllowEmptyMembers::<T>::get(GovernanceBody::Council),
+				Error::<T>::EmptyMembers
+			);
 			council_members.sort();
 
 			let council_current_members = T::CouncilMembershipHandler::sorted_members();
@@ -212,8 +300,12 @@ pub mod pallet {
 				BoundedVec::try_from(council_mainchain_members.clone())
 					.map_err(|_| Error::<T>::TooManyMembers)?;
 
-			// Make sure an empty set of mainchain members is not allowed
-			ensure!(!council_mainchain_members.is_empty(), Error::<T>::EmptyMembers);
+			// Make sure an empty set of mainchain members is not allowed, unless explicitly permitted
+			ensure!(
+				!council_mainchain_members.is_empty()
+					|| AllowEmptyMembers::<T>::get(GovernanceBody::Council),
+				Error::<T>::EmptyMembers
+			);
 
 			let council_current_mainchain_members = CouncilMainchainMembers::<T>::get();
 
@@ -237,6 +329,14 @@ pub mod pallet {
 				council_members_have_changed || council_mainchain_members_have_changed;
 
 			if council_has_changed {
+				Self::apply_membership_change(
+					GovernanceBody::Council,
+					&council_current_members,
+					&council_members,
+					&council_mainchain_by_account,
+					mc_block_hash.clone(),
+				);
+
 				Self::deposit_event(Event::<T>::CouncilMembersReset {
 					members: council_members,
 					members_mainchain: council_mainchain_members,
@@ -256,8 +356,12 @@ pub mod pallet {
 			> = BoundedVec::try_from(technical_committee_account_ids.clone())
 				.map_err(|_| Error::<T>::TooManyMembers)?;
 
-			// Make sure an empty set of members is not allowed
-			ensure!(!technical_committee_members.is_empty(), Error::<T>::EmptyMembers);
+			// Make sure an empty set of members is not allowed, unless explicitly permitted
+			ensure!(
+				!technical_committee_members.is_empty()
+					|| AllowEmptyMembers::<T>::get(GovernanceBody::TechnicalCommittee),
+				Error::<T>::EmptyMembers
+			);
 			technical_committee_members.sort();
 
 			let technical_committee_current_members =
@@ -274,8 +378,12 @@ pub mod pallet {
 			> = BoundedVec::try_from(technical_committee_mainchain_members.clone())
 				.map_err(|_| Error::<T>::TooManyMembers)?;
 
-			// Make sure an empty set of mainchain members is not allowed
-			ensure!(!technical_committee_mainchain_members.is_empty(), Error::<T>::EmptyMembers);
+			// Make sure an empty set of mainchain members is not allowed, unless explicitly permitted
+			ensure!(
+				!technical_committee_mainchain_members.is_empty()
+					|| AllowEmptyMembers::<T>::get(GovernanceBody::TechnicalCommittee),
+				Error::<T>::EmptyMembers
+			);
 
 			let technical_committee_current_mainchain_members =
 				TechnicalCommitteeMainchainMembers::<T>::get();
@@ -303,6 +411,14 @@ pub mod pallet {
 				|| technical_committee_mainchain_members_have_changed;
 
 			if technical_committee_has_changed {
+				Self::apply_membership_change(
+					GovernanceBody::TechnicalCommittee,
+					&technical_committee_current_members,
+					&technical_committee_members,
+					&technical_committee_mainchain_by_account,
+					mc_block_hash,
+				);
+
 				Self::deposit_event(Event::<T>::TechnicalCommitteeMembersReset {
 					members: technical_committee_members,
 					members_mainchain: technical_committee_mainchain_members,
@@ -375,6 +491,23 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Allows the next empty membership observed on the main chain to wipe `body`.
+		/// Without this, an empty membership is rejected to protect against wiping a governance
+		/// body because of a faulty observation.
+		#[pallet::call_index(5)]
+		#[pallet::weight((T::WeightInfo::set_allow_empty_members(), DispatchClass::Operational))]
+		pub fn set_allow_empty_members(
+			origin: OriginFor<T>,
+			body: GovernanceBody,
+			allowed: bool,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			AllowEmptyMembers::<T>::set(body, allowed);
+			Self::deposit_event(Event::<T>::AllowEmptyMembersSet { body, allowed });
+
+			Ok(())
+		}
 	}
 
 	#[pallet::inherent]
@@ -393,15 +526,30 @@ pub mod pallet {
 			let technical_committee_authorities =
 				Self::decode_auth_accounts(fed_auth_data.technical_committee_authorities).ok()?;
 
-			if !council_authorities.is_empty() && !technical_committee_authorities.is_empty() {
-				Some(Call::reset_members { council_authorities, technical_committee_authorities })
+			let council_allowed = !council_authorities.is_empty()
+				|| AllowEmptyMembers::<T>::get(GovernanceBody::Council);
+			let technical_committee_allowed = !technical_committee_authorities.is_empty()
+				|| AllowEmptyMembers::<T>::get(GovernanceBody::TechnicalCommittee);
+
+			if council_allowed && technical_committee_allowed {
+				Some(Call::reset_members_at {
+					council_authorities,
+					technical_committee_authorities,
+					mc_block_hash: fed_auth_data.mc_block_hash,
+				})
 			} else {
+				log::warn!(
+					target: "federated-authority-observation",
+					"Ignoring observed membership with an empty governance body (council: {}, technical committee: {})",
+					council_authorities.len(),
+					technical_committee_authorities.len(),
+				);
 				None
 			}
 		}
 
 		fn is_inherent(call: &Self::Call) -> bool {
-			matches!(call, Call::reset_members { .. })
+			matches!(call, Call::reset_members { .. } | Call::reset_members_at { .. })
 		}
 
 		fn check_inherent(
@@ -419,6 +567,64 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Emits per-member events for the difference between the `current` and `new` sorted
+		/// members of `body`, and records the change in `MembershipHistory` if accounts were
+		/// added or removed
+		fn apply_membership_change(
+			body: GovernanceBody,
+			current: &[T::AccountId],
+			new: &[T::AccountId],
+			mainchain_by_account: &[(T::AccountId, MainchainMember)],
+			mc_block_hash: McBlockHash,
+		) {
+			let added: Vec<T::AccountId> =
+				new.iter().filter(|m| current.binary_search(m).is_err()).cloned().collect();
+			let removed: Vec<T::AccountId> =
+				current.iter().filter(|m| new.binary_search(m).is_err()).cloned().collect();
+
+			for member in &added {
+				if let Some((_, member_mainchain)) =
+					mainchain_by_account.iter().find(|(account_id, _)| account_id == member)
+				{
+					Self::deposit_event(Event::<T>::MemberAdded {
+						body,
+						member: member.clone(),
+						member_mainchain: member_mainchain.clone(),
+					});
+				}
+			}
+
+			for member in &removed {
+				Self::deposit_event(Event::<T>::MemberRemoved { body, member: member.clone() });
+			}
+
+			// An empty membership is only applied once per permission
+			if new.is_empty() {
+				AllowEmptyMembers::<T>::remove(body);
+			}
+
+			// Changes to mainchain identifiers only are not recorded
+			if added.is_empty() && removed.is_empty() {
+				return;
+			}
+
+			let change = MembershipChange {
+				body,
+				block_number: frame_system::Pallet::<T>::block_number(),
+				mc_block_hash,
+				added,
+				removed,
+			};
+
+			MembershipHistory::<T>::mutate(|history| {
+				// Drop the oldest change to make room for the new one
+				if history.len() as u32 >= T::MaxMembershipHistory::get() && !history.is_empty() {
+					history.remove(0);
+				}
+				let _ = history.try_push(change);
+			});
+		}
+
 		fn get_data_from_inherent_data(
 			data: &InherentData,
 		) -> Result<Option<FederatedAuthorityData>, InherentError> {
@@ -44,6 +44,7 @@ parameter_types! {
 	pub const TechnicalCommitteeMaxMembers: u32 = 1000; // Higher number for more accurate benchmarks
 	pub const MotionDuration: u64 = 100;
 	pub const MaxProposals: u32 = 100;
+	pub const MaxMembershipHistory: u32 = 3;
 }
 
 /// Council
@@ -115,6 +116,7 @@ impl pallet_federated_authority_observation::Config for Test {
 		MembershipObservationHandler<Test, pallet_membership::Instance1>;
 	type TechnicalCommitteeMembershipHandler =
 		MembershipObservationHandler<Test, pallet_membership::Instance2>;
+	type MaxMembershipHistory = MaxMembershipHistory;
 	type WeightInfo = ();
 }
 
@@ -12,8 +12,8 @@
 // limitations under the License.
 
 use crate::{
-	CouncilMainchainMembers, Error, Event, MainchainMember, TechnicalCommitteeMainchainMembers,
-	mock::*,
+	AllowEmptyMembers, CouncilMainchainMembers, Error, Event, GovernanceBody, MainchainMember,
+	MembershipHistory, TechnicalCommitteeMainchainMembers, mock::*,
 };
 use core::str::FromStr;
 use frame_support::inherent::ProvideInherent;
@@ -89,10 +89,11 @@ fn reset_council_and_tc_members_works() {
 		let council_members = vec![1, 2, 3];
 		let tc_members = vec![4, 5, 6];
 
-		assert_ok!(FederatedAuthorityObservation::reset_members(
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
 			frame_system::RawOrigin::None.into(),
 			with_mainchain_members(&council_members),
 			with_mainchain_members(&tc_members),
+			McBlockHash([0u8; 32]),
 		));
 
 		// Verify members were set via MembershipHandler in both the membership and collective pallets
@@ -138,20 +139,22 @@ fn reset_members_requires_none_origin() {
 
 		// Should fail with signed origin
 		assert_noop!(
-			FederatedAuthorityObservation::reset_members(
+			FederatedAuthorityObservation::reset_members_at(
 				frame_system::RawOrigin::Signed(1).into(),
 				with_mainchain_members(&council_members),
 				with_mainchain_members(&tc_members),
+				McBlockHash([0u8; 32]),
 			),
 			sp_runtime::DispatchError::BadOrigin
 		);
 
 		// Should fail with root origin
 		assert_noop!(
-			FederatedAuthorityObservation::reset_members(
+			FederatedAuthorityObservation::reset_members_at(
 				frame_system::RawOrigin::Root.into(),
 				with_mainchain_members(&council_members),
 				with_mainchain_members(&tc_members),
+				McBlockHash([0u8; 32]),
 			),
 			sp_runtime::DispatchError::BadOrigin
 		);
@@ -167,10 +170,11 @@ fn reset_members_fails_with_too_many_council_members() {
 		let tc_members = vec![4, 5, 6];
 
 		assert_noop!(
-			FederatedAuthorityObservation::reset_members(
+			FederatedAuthorityObservation::reset_members_at(
 				frame_system::RawOrigin::None.into(),
 				with_mainchain_members(&too_many_members),
 				with_mainchain_members(&tc_members),
+				McBlockHash([0u8; 32]),
 			),
 			Error::<Test>::TooManyMembers
 		);
@@ -186,10 +190,11 @@ fn reset_members_fails_with_too_many_technical_committee_members() {
 		let too_many_members: Vec<u64> = (0..max_members + 1).collect();
 
 		assert_noop!(
-			FederatedAuthorityObservation::reset_members(
+			FederatedAuthorityObservation::reset_members_at(
 				frame_system::RawOrigin::None.into(),
 				with_mainchain_members(&council_members),
 				with_mainchain_members(&too_many_members),
+				McBlockHash([0u8; 32]),
 			),
 			Error::<Test>::TooManyMembers
 		);
@@ -204,10 +209,11 @@ fn reset_members_sorts_members() {
 		let unsorted_tc = vec![6, 4, 5];
 		let sorted_tc = vec![4, 5, 6];
 
-		assert_ok!(FederatedAuthorityObservation::reset_members(
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
 			frame_system::RawOrigin::None.into(),
 			with_mainchain_members(&unsorted_council),
 			with_mainchain_members(&unsorted_tc),
+			McBlockHash([0u8; 32]),
 		));
 
 		// Verify members are sorted
@@ -228,20 +234,22 @@ fn no_event_when_same_members() {
 		let tc_members = vec![4, 5, 6];
 
 		// Set initial members
-		assert_ok!(FederatedAuthorityObservation::reset_members(
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
 			frame_system::RawOrigin::None.into(),
 			with_mainchain_members(&council_members),
 			with_mainchain_members(&tc_members),
+			McBlockHash([0u8; 32]),
 		));
 
 		// Reset events
 		System::reset_events();
 
 		// Call with same members
-		assert_ok!(FederatedAuthorityObservation::reset_members(
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
 			frame_system::RawOrigin::None.into(),
 			with_mainchain_members(&council_members),
 			with_mainchain_members(&tc_members),
+			McBlockHash([0u8; 32]),
 		));
 
 		// Members should remain unchanged
@@ -267,10 +275,11 @@ fn create_inherent_works_when_council_changes() {
 		let new_tc = vec![4, 5, 6];
 
 		// Initialize with some members first
-		assert_ok!(FederatedAuthorityObservation::reset_members(
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
 			frame_system::RawOrigin::None.into(),
 			with_mainchain_members(&initial_council),
 			with_mainchain_members(&initial_tc),
+			McBlockHash([0u8; 32]),
 		));
 
 		// Now create inherent with different members
@@ -302,10 +311,11 @@ fn create_inherent_with_same_members_emits_no_events() {
 		let tc_members = vec![4, 5, 6];
 
 		// Initialize with some members first
-		assert_ok!(FederatedAuthorityObservation::reset_members(
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
 			frame_system::RawOrigin::None.into(),
 			with_mainchain_members(&council_members),
 			with_mainchain_members(&tc_members),
+			McBlockHash([0u8; 32]),
 		));
 
 		// Reset events
@@ -339,10 +349,11 @@ fn create_inherent_works_when_only_council_changes() {
 		let new_council = vec![7, 8, 9];
 
 		// Set initial state
-		assert_ok!(FederatedAuthorityObservation::reset_members(
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
 			frame_system::RawOrigin::None.into(),
 			with_mainchain_members(&initial_council),
 			with_mainchain_members(&tc_members),
+			McBlockHash([0u8; 32]),
 		));
 
 		// Create inherent with changed council but same TC
@@ -378,10 +389,11 @@ fn create_inherent_works_when_only_technical_committee_changes() {
 		let new_tc = vec![7, 8, 9];
 
 		// Set initial state
-		assert_ok!(FederatedAuthorityObservation::reset_members(
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
 			frame_system::RawOrigin::None.into(),
 			with_mainchain_members(&council_members),
 			with_mainchain_members(&initial_tc),
+			McBlockHash([0u8; 32]),
 		));
 
 		// Create inherent with same council but changed TC
@@ -413,10 +425,11 @@ fn reset_members_emits_event_when_only_council_mainchain_members_change() {
 		let tc_members = vec![4, 5, 6];
 
 		// Initialize with some members first
-		assert_ok!(FederatedAuthorityObservation::reset_members(
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
 			frame_system::RawOrigin::None.into(),
 			with_mainchain_members(&council_members),
 			with_mainchain_members(&tc_members),
+			McBlockHash([0u8; 32]),
 		));
 
 		// Reset events
@@ -472,10 +485,11 @@ fn reset_members_emits_event_when_only_tc_mainchain_members_change() {
 		let tc_members = vec![4, 5, 6];
 
 		// Initialize with some members first
-		assert_ok!(FederatedAuthorityObservation::reset_members(
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
 			frame_system::RawOrigin::None.into(),
 			with_mainchain_members(&council_members),
 			with_mainchain_members(&tc_members),
+			McBlockHash([0u8; 32]),
 		));
 
 		// Reset events
@@ -533,10 +547,11 @@ fn reset_members_emits_both_events_when_both_mainchain_members_change() {
 		let tc_members = vec![4, 5, 6];
 
 		// Initialize with some members first
-		assert_ok!(FederatedAuthorityObservation::reset_members(
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
 			frame_system::RawOrigin::None.into(),
 			with_mainchain_members(&council_members),
 			with_mainchain_members(&tc_members),
+			McBlockHash([0u8; 32]),
 		));
 
 		// Reset events
@@ -599,10 +614,11 @@ fn membership_changed_callbacks_are_called() {
 		let council_members = vec![1, 2, 3];
 		let tc_members = vec![4, 5, 6];
 
-		assert_ok!(FederatedAuthorityObservation::reset_members(
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
 			frame_system::RawOrigin::None.into(),
 			with_mainchain_members(&council_members),
 			with_mainchain_members(&tc_members),
+			McBlockHash([0u8; 32]),
 		));
 
 		// Verify members were set via MembershipHandler in both the membership and collective pallets
@@ -639,10 +655,11 @@ fn empty_council_members_list_fails() {
 
 		// Attempting to reset with empty council list should fail with EmptyMembers
 		assert_noop!(
-			FederatedAuthorityObservation::reset_members(
+			FederatedAuthorityObservation::reset_members_at(
 				frame_system::RawOrigin::None.into(),
 				vec![],
 				with_mainchain_members(&tc_members),
+				McBlockHash([0u8; 32]),
 			),
 			Error::<Test>::EmptyMembers
 		);
@@ -656,10 +673,11 @@ fn empty_tc_members_list_fails() {
 
 		// Attempting to reset with empty TC list should fail with EmptyMembers
 		assert_noop!(
-			FederatedAuthorityObservation::reset_members(
+			FederatedAuthorityObservation::reset_members_at(
 				frame_system::RawOrigin::None.into(),
 				with_mainchain_members(&council_members),
 				vec![],
+				McBlockHash([0u8; 32]),
 			),
 			Error::<Test>::EmptyMembers
 		);
@@ -675,10 +693,11 @@ fn duplicate_members_are_allowed() {
 		let sorted_members_with_duplicates = vec![1, 2, 2, 3];
 		let tc_members = vec![4, 5, 6];
 
-		assert_ok!(FederatedAuthorityObservation::reset_members(
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
 			frame_system::RawOrigin::None.into(),
 			with_mainchain_members(&members_with_duplicates),
 			with_mainchain_members(&tc_members),
+			McBlockHash([0u8; 32]),
 		));
 
 		// After sorting, duplicates remain
@@ -695,10 +714,11 @@ fn inherent_check_validates_data() {
 		let new_tc = vec![4, 5, 6];
 
 		// Initialize with some members first
-		assert_ok!(FederatedAuthorityObservation::reset_members(
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
 			frame_system::RawOrigin::None.into(),
 			with_mainchain_members(&initial_council),
 			with_mainchain_members(&initial_tc),
+			McBlockHash([0u8; 32]),
 		));
 
 		// Create inherent data with different members
@@ -723,15 +743,45 @@ fn is_inherent_identifies_reset_members_call() {
 		let council_members = vec![1, 2, 3];
 		let tc_members = vec![4, 5, 6];
 
-		let call = crate::Call::<Test>::reset_members {
+		let call = crate::Call::<Test>::reset_members_at {
 			council_authorities: with_mainchain_members(&council_members),
 			technical_committee_authorities: with_mainchain_members(&tc_members),
+			mc_block_hash: McBlockHash([0u8; 32]),
 		};
 
 		assert!(FederatedAuthorityObservation::is_inherent(&call));
 	});
 }
 
+#[test]
+fn legacy_reset_members_call_is_still_supported() {
+	new_test_ext().execute_with(|| {
+		let council_members = vec![1, 2, 3];
+		let tc_members = vec![4, 5, 6];
+
+		let call = crate::Call::<Test>::reset_members {
+			council_authorities: with_mainchain_members(&council_members),
+			technical_committee_authorities: with_mainchain_members(&tc_members),
+		};
+		assert!(FederatedAuthorityObservation::is_inherent(&call));
+
+		// The legacy call keeps its call index
+		assert_eq!(call.encode()[0], 0);
+
+		assert_ok!(FederatedAuthorityObservation::reset_members(
+			frame_system::RawOrigin::None.into(),
+			with_mainchain_members(&council_members),
+			with_mainchain_members(&tc_members),
+		));
+
+		assert_eq!(CouncilMembership::members().to_vec(), council_members);
+		assert_eq!(TechnicalCommitteeMembership::members().to_vec(), tc_members);
+		let history = MembershipHistory::<Test>::get();
+		assert_eq!(history.len(), 2);
+		assert!(history.iter().all(|c| c.mc_block_hash == McBlockHash([0u8; 32])));
+	});
+}
+
 #[test]
 fn multiple_consecutive_resets_work() {
 	new_test_ext().execute_with(|| {
@@ -741,17 +791,19 @@ fn multiple_consecutive_resets_work() {
 		let second_tc = vec![10, 11, 12];
 
 		// First reset
-		assert_ok!(FederatedAuthorityObservation::reset_members(
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
 			frame_system::RawOrigin::None.into(),
 			with_mainchain_members(&first_council),
 			with_mainchain_members(&first_tc),
+			McBlockHash([0u8; 32]),
 		));
 
 		// Second reset
-		assert_ok!(FederatedAuthorityObservation::reset_members(
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
 			frame_system::RawOrigin::None.into(),
 			with_mainchain_members(&second_council),
 			with_mainchain_members(&second_tc),
+			McBlockHash([0u8; 32]),
 		));
 
 		// Verify the second set of members is active
@@ -776,10 +828,11 @@ fn membership_handler_integration_test() {
 		let initial_council = vec![1, 2, 3];
 		let initial_tc = vec![4, 5, 6];
 
-		assert_ok!(FederatedAuthorityObservation::reset_members(
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
 			frame_system::RawOrigin::None.into(),
 			with_mainchain_members(&initial_council),
 			with_mainchain_members(&initial_tc),
+			McBlockHash([0u8; 32]),
 		));
 
 		// Verify members were set via MembershipHandler in both the membership and collective pallets
@@ -805,10 +858,11 @@ fn membership_handler_integration_test() {
 		let new_council = vec![2, 3, 7]; // 1 is removed, 7 is added, 2 and 3 remain
 		let new_tc = vec![5, 8]; // 4 and 6 are removed, 8 is added, 5 remains
 
-		assert_ok!(FederatedAuthorityObservation::reset_members(
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
 			frame_system::RawOrigin::None.into(),
 			with_mainchain_members(&new_council),
 			with_mainchain_members(&new_tc),
+			McBlockHash([0u8; 32]),
 		));
 
 		// Verify members were set via MembershipHandler in both the membership and collective pallets
@@ -1091,3 +1145,201 @@ fn set_council_policy_id_can_be_updated() {
 		assert_eq!(crate::MainChainCouncilPolicyId::<Test>::get(), policy_id2);
 	});
 }
+
+#[test]
+fn reset_members_emits_per_member_events() {
+	new_test_ext().execute_with(|| {
+		let initial_council = vec![1, 2, 3];
+		let tc_members = vec![4, 5, 6];
+		let new_council = vec![2, 3, 7];
+		let mc_block_hash = McBlockHash([1u8; 32]);
+
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
+			frame_system::RawOrigin::None.into(),
+			with_mainchain_members(&initial_council),
+			with_mainchain_members(&tc_members),
+			McBlockHash([0u8; 32]),
+		));
+
+		System::reset_events();
+
+		let new_council_with_mainchain = with_mainchain_members(&new_council);
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
+			frame_system::RawOrigin::None.into(),
+			new_council_with_mainchain.clone(),
+			with_mainchain_members(&tc_members),
+			mc_block_hash.clone(),
+		));
+
+		System::assert_has_event(
+			Event::MemberAdded {
+				body: GovernanceBody::Council,
+				member: 7,
+				member_mainchain: new_council_with_mainchain[2].1.clone(),
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			Event::MemberRemoved { body: GovernanceBody::Council, member: 1 }.into(),
+		);
+
+		// Only the council changed, so it's the only new entry in the history
+		let history = MembershipHistory::<Test>::get();
+		let change = history.last().expect("Membership change should be recorded");
+		assert_eq!(change.body, GovernanceBody::Council);
+		assert_eq!(change.block_number, 1);
+		assert_eq!(change.mc_block_hash, mc_block_hash);
+		assert_eq!(change.added, vec![7]);
+		assert_eq!(change.removed, vec![1]);
+		assert_eq!(history.iter().filter(|c| c.mc_block_hash == mc_block_hash).count(), 1);
+	});
+}
+
+#[test]
+fn membership_history_is_bounded() {
+	new_test_ext().execute_with(|| {
+		let tc_members = vec![100];
+
+		// Every reset replaces the single council member
+		for i in 1..=5u8 {
+			assert_ok!(FederatedAuthorityObservation::reset_members_at(
+				frame_system::RawOrigin::None.into(),
+				with_mainchain_members(&[i as u64]),
+				with_mainchain_members(&tc_members),
+				McBlockHash([i; 32]),
+			));
+		}
+
+		// Only the latest `MaxMembershipHistory` changes are kept, oldest first
+		let history = MembershipHistory::<Test>::get();
+		assert_eq!(history.len() as u32, MaxMembershipHistory::get());
+		let hashes: Vec<_> = history.iter().map(|c| c.mc_block_hash.clone()).collect();
+		assert_eq!(hashes, vec![McBlockHash([3; 32]), McBlockHash([4; 32]), McBlockHash([5; 32])]);
+		assert_eq!(history[2].added, vec![5]);
+		assert_eq!(history[2].removed, vec![4]);
+	});
+}
+
+#[test]
+fn mainchain_only_changes_are_not_recorded_in_history() {
+	new_test_ext().execute_with(|| {
+		let council_members = vec![1, 2, 3];
+		let tc_members = vec![4, 5, 6];
+
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
+			frame_system::RawOrigin::None.into(),
+			with_mainchain_members(&council_members),
+			with_mainchain_members(&tc_members),
+			McBlockHash([0u8; 32]),
+		));
+		let history_len = MembershipHistory::<Test>::get().len();
+
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
+			frame_system::RawOrigin::None.into(),
+			with_different_mainchain_members(&council_members),
+			with_mainchain_members(&tc_members),
+			McBlockHash([1u8; 32]),
+		));
+
+		assert_eq!(MembershipHistory::<Test>::get().len(), history_len);
+	});
+}
+
+#[test]
+fn empty_members_are_applied_when_allowed() {
+	new_test_ext().execute_with(|| {
+		let council_members = vec![1, 2, 3];
+		let tc_members = vec![4, 5, 6];
+
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
+			frame_system::RawOrigin::None.into(),
+			with_mainchain_members(&council_members),
+			with_mainchain_members(&tc_members),
+			McBlockHash([0u8; 32]),
+		));
+
+		assert_ok!(FederatedAuthorityObservation::set_allow_empty_members(
+			frame_system::RawOrigin::Root.into(),
+			GovernanceBody::Council,
+			true
+		));
+		System::assert_last_event(
+			Event::AllowEmptyMembersSet { body: GovernanceBody::Council, allowed: true }.into(),
+		);
+
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
+			frame_system::RawOrigin::None.into(),
+			vec![],
+			with_mainchain_members(&tc_members),
+			McBlockHash([0u8; 32]),
+		));
+
+		assert!(CouncilMembership::members().is_empty());
+		assert!(CouncilMainchainMembers::<Test>::get().is_empty());
+		for member in council_members {
+			System::assert_has_event(
+				Event::MemberRemoved { body: GovernanceBody::Council, member }.into(),
+			);
+		}
+
+		// The permission is consumed once the empty membership has been applied
+		assert!(!AllowEmptyMembers::<Test>::get(GovernanceBody::Council));
+
+		// Technical Committee is still protected
+		assert_noop!(
+			FederatedAuthorityObservation::reset_members_at(
+				frame_system::RawOrigin::None.into(),
+				vec![],
+				vec![],
+				McBlockHash([0u8; 32]),
+			),
+			Error::<Test>::EmptyMembers
+		);
+	});
+}
+
+#[test]
+fn set_allow_empty_members_requires_root() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			FederatedAuthorityObservation::set_allow_empty_members(
+				frame_system::RawOrigin::Signed(1).into(),
+				GovernanceBody::Council,
+				true
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert!(!AllowEmptyMembers::<Test>::get(GovernanceBody::Council));
+	});
+}
+
+#[test]
+fn create_inherent_skips_empty_members_unless_allowed() {
+	new_test_ext().execute_with(|| {
+		let council_members = vec![1, 2, 3];
+		let tc_members = vec![4, 5, 6];
+
+		assert_ok!(FederatedAuthorityObservation::reset_members_at(
+			frame_system::RawOrigin::None.into(),
+			with_mainchain_members(&council_members),
+			with_mainchain_members(&tc_members),
+			McBlockHash([0u8; 32]),
+		));
+
+		let inherent_data = create_inherent_data(vec![], with_mainchain_members(&tc_members));
+		assert!(FederatedAuthorityObservation::create_inherent(&inherent_data).is_none());
+
+		assert_ok!(FederatedAuthorityObservation::set_allow_empty_members(
+			frame_system::RawOrigin::Root.into(),
+			GovernanceBody::Council,
+			true
+		));
+
+		let call = FederatedAuthorityObservation::create_inherent(&inherent_data)
+			.expect("Should create inherent once empty council is allowed");
+		let runtime_call = RuntimeCall::FederatedAuthorityObservation(call);
+		assert_ok!(runtime_call.dispatch(frame_system::RawOrigin::None.into()));
+
+		assert!(CouncilMembership::members().is_empty());
+	});
+}
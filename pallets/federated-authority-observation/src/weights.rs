@@ -1,29 +1,9 @@
-
-//! Autogenerated weights for `pallet_federated_authority_observation`
+//! Weights for `pallet_federated_authority_observation`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 49.1.0
-//! DATE: 2025-11-04, STEPS: `2`, REPEAT: `1`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `MacBook-Pro-2.local`, CPU: `<UNKNOWN>`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
-
-// Executed Command:
-// ./target/release/midnight-node
-// benchmark
-// pallet
-// --runtime
-// ./target/release/wbuild/midnight-node-runtime/midnight_node_runtime.wasm
-// --genesis-builder=spec
-// --wasm-execution=compiled
-// --pallet=pallet_federated_authority_observation
-// --extrinsic=*
-// --steps
-// 2
-// --repeat
-// 1
-// --output
-// pallets/federated-authority-observation/src/weights.rs
-// --template=./res/weights-template.hbs
+//! `reset_members`, `reset_members_at` and `set_allow_empty_members` have benchmarks in
+//! `benchmarking.rs`, run against a full `MembershipHistory`. The weights below predate them and
+//! are pending regeneration with `earthly +rebuild-weights`; the ones marked `Not benchmarked yet`
+//! have never been measured.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -39,11 +19,13 @@ pub trait WeightInfo {
 	fn reset_members_only_council(a: u32, b: u32, ) -> Weight;
 	fn reset_members_only_technical_committee(a: u32, b: u32, ) -> Weight;
 	fn reset_members(a: u32, b: u32, ) -> Weight;
+	fn reset_members_at(a: u32, b: u32, ) -> Weight;
 	fn reset_members_none(a: u32, b: u32, ) -> Weight;
 	fn set_council_address() -> Weight;
 	fn set_technical_committee_address() -> Weight;
 	fn set_council_policy_id() -> Weight;
 	fn set_technical_committee_policy_id() -> Weight;
+	fn set_allow_empty_members() -> Weight;
 }
 
 /// Weights for `pallet_federated_authority_observation` using the Substrate node and recommended hardware.
@@ -65,6 +47,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Council::Prime` (r:0 w:1)
 	/// Proof: `Council::Prime` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthorityObservation::MembershipHistory` (r:1 w:1)
+	/// Proof: `FederatedAuthorityObservation::MembershipHistory` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// The range of component `a` is `[1, 9]`.
 	/// The range of component `b` is `[1, 9]`.
 	fn reset_members_only_council(a: u32, b: u32, ) -> Weight {
@@ -77,8 +61,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(312_500, 0).saturating_mul(a.into()))
 			// Standard Error: 108_253
 			.saturating_add(Weight::from_parts(187_500, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 			.saturating_add(Weight::from_parts(0, 64).saturating_mul(a.into()))
 			.saturating_add(Weight::from_parts(0, 32).saturating_mul(b.into()))
 	}
@@ -98,6 +82,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `TechnicalCommittee::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `TechnicalCommittee::Prime` (r:0 w:1)
 	/// Proof: `TechnicalCommittee::Prime` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthorityObservation::MembershipHistory` (r:1 w:1)
+	/// Proof: `FederatedAuthorityObservation::MembershipHistory` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// The range of component `a` is `[1, 9]`.
 	/// The range of component `b` is `[1, 9]`.
 	fn reset_members_only_technical_committee(a: u32, b: u32, ) -> Weight {
@@ -110,8 +96,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(187_500, 0).saturating_mul(a.into()))
 			// Standard Error: 108_253
 			.saturating_add(Weight::from_parts(187_500, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 			.saturating_add(Weight::from_parts(0, 32).saturating_mul(a.into()))
 			.saturating_add(Weight::from_parts(0, 64).saturating_mul(b.into()))
 	}
@@ -141,6 +127,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `TechnicalCommittee::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `TechnicalCommittee::Prime` (r:0 w:1)
 	/// Proof: `TechnicalCommittee::Prime` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthorityObservation::MembershipHistory` (r:1 w:1)
+	/// Proof: `FederatedAuthorityObservation::MembershipHistory` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// The range of component `a` is `[1, 9]`.
 	/// The range of component `b` is `[1, 9]`.
 	fn reset_members(a: u32, b: u32, ) -> Weight {
@@ -153,8 +141,54 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(62_500, 0).saturating_mul(a.into()))
 			// Standard Error: 108_253
 			.saturating_add(Weight::from_parts(62_500, 0).saturating_mul(b.into()))
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(11_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+			.saturating_add(Weight::from_parts(0, 55).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(0, 55).saturating_mul(b.into()))
+	}
+	/// Storage: `CouncilMembership::Members` (r:1 w:1)
+	/// Proof: `CouncilMembership::Members` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `Council::Proposals` (r:1 w:0)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CouncilMembership::Prime` (r:1 w:0)
+	/// Proof: `CouncilMembership::Prime` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TechnicalCommitteeMembership::Members` (r:1 w:1)
+	/// Proof: `TechnicalCommitteeMembership::Members` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `TechnicalCommittee::Proposals` (r:1 w:0)
+	/// Proof: `TechnicalCommittee::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `TechnicalCommitteeMembership::Prime` (r:1 w:0)
+	/// Proof: `TechnicalCommitteeMembership::Prime` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `FederatedAuthorityObservation::TechnicalCommitteeMainchainMembers` (r:0 w:1)
+	/// Proof: `FederatedAuthorityObservation::TechnicalCommitteeMainchainMembers` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthorityObservation::CouncilMainchainMembers` (r:0 w:1)
+	/// Proof: `FederatedAuthorityObservation::CouncilMainchainMembers` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Members` (r:0 w:1)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Prime` (r:0 w:1)
+	/// Proof: `Council::Prime` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `TechnicalCommittee::Members` (r:0 w:1)
+	/// Proof: `TechnicalCommittee::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `TechnicalCommittee::Prime` (r:0 w:1)
+	/// Proof: `TechnicalCommittee::Prime` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthorityObservation::MembershipHistory` (r:1 w:1)
+	/// Proof: `FederatedAuthorityObservation::MembershipHistory` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `a` is `[1, 9]`.
+	/// The range of component `b` is `[1, 9]`.
+	fn reset_members_at(a: u32, b: u32, ) -> Weight {
+		// Not benchmarked yet, same as `reset_members`
+		// Proof Size summary in bytes:
+		//  Measured:  `755 + a * (54 ±0) + b * (54 ±0)`
+		//  Estimated: `9242 + a * (55 ±0) + b * (55 ±0)`
+		// Minimum execution time: 37_000_000 picoseconds.
+		Weight::from_parts(36_375_000, 9242)
+			// Standard Error: 108_253
+			.saturating_add(Weight::from_parts(62_500, 0).saturating_mul(a.into()))
+			// Standard Error: 108_253
+			.saturating_add(Weight::from_parts(62_500, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
 			.saturating_add(Weight::from_parts(0, 55).saturating_mul(a.into()))
 			.saturating_add(Weight::from_parts(0, 55).saturating_mul(b.into()))
 	}
@@ -216,6 +250,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(2_000_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `FederatedAuthorityObservation::AllowEmptyMembers` (r:0 w:1)
+	/// Proof: `FederatedAuthorityObservation::AllowEmptyMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_allow_empty_members() -> Weight {
+		// Not benchmarked yet
+		Weight::from_parts(3_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -236,6 +277,8 @@ impl WeightInfo for () {
 	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Council::Prime` (r:0 w:1)
 	/// Proof: `Council::Prime` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthorityObservation::MembershipHistory` (r:1 w:1)
+	/// Proof: `FederatedAuthorityObservation::MembershipHistory` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// The range of component `a` is `[1, 9]`.
 	/// The range of component `b` is `[1, 9]`.
 	fn reset_members_only_council(a: u32, b: u32, ) -> Weight {
@@ -248,8 +291,8 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(312_500, 0).saturating_mul(a.into()))
 			// Standard Error: 108_253
 			.saturating_add(Weight::from_parts(187_500, 0).saturating_mul(b.into()))
-			.saturating_add(ParityDbWeight::get().reads(5_u64))
-			.saturating_add(ParityDbWeight::get().writes(5_u64))
+			.saturating_add(ParityDbWeight::get().reads(6_u64))
+			.saturating_add(ParityDbWeight::get().writes(6_u64))
 			.saturating_add(Weight::from_parts(0, 64).saturating_mul(a.into()))
 			.saturating_add(Weight::from_parts(0, 32).saturating_mul(b.into()))
 	}
@@ -269,6 +312,8 @@ impl WeightInfo for () {
 	/// Proof: `TechnicalCommittee::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `TechnicalCommittee::Prime` (r:0 w:1)
 	/// Proof: `TechnicalCommittee::Prime` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthorityObservation::MembershipHistory` (r:1 w:1)
+	/// Proof: `FederatedAuthorityObservation::MembershipHistory` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// The range of component `a` is `[1, 9]`.
 	/// The range of component `b` is `[1, 9]`.
 	fn reset_members_only_technical_committee(a: u32, b: u32, ) -> Weight {
@@ -281,8 +326,8 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(187_500, 0).saturating_mul(a.into()))
 			// Standard Error: 108_253
 			.saturating_add(Weight::from_parts(187_500, 0).saturating_mul(b.into()))
-			.saturating_add(ParityDbWeight::get().reads(5_u64))
-			.saturating_add(ParityDbWeight::get().writes(5_u64))
+			.saturating_add(ParityDbWeight::get().reads(6_u64))
+			.saturating_add(ParityDbWeight::get().writes(6_u64))
 			.saturating_add(Weight::from_parts(0, 32).saturating_mul(a.into()))
 			.saturating_add(Weight::from_parts(0, 64).saturating_mul(b.into()))
 	}
@@ -312,6 +357,8 @@ impl WeightInfo for () {
 	/// Proof: `TechnicalCommittee::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `TechnicalCommittee::Prime` (r:0 w:1)
 	/// Proof: `TechnicalCommittee::Prime` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthorityObservation::MembershipHistory` (r:1 w:1)
+	/// Proof: `FederatedAuthorityObservation::MembershipHistory` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// The range of component `a` is `[1, 9]`.
 	/// The range of component `b` is `[1, 9]`.
 	fn reset_members(a: u32, b: u32, ) -> Weight {
@@ -324,8 +371,54 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(62_500, 0).saturating_mul(a.into()))
 			// Standard Error: 108_253
 			.saturating_add(Weight::from_parts(62_500, 0).saturating_mul(b.into()))
-			.saturating_add(ParityDbWeight::get().reads(9_u64))
-			.saturating_add(ParityDbWeight::get().writes(11_u64))
+			.saturating_add(ParityDbWeight::get().reads(10_u64))
+			.saturating_add(ParityDbWeight::get().writes(12_u64))
+			.saturating_add(Weight::from_parts(0, 55).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(0, 55).saturating_mul(b.into()))
+	}
+	/// Storage: `CouncilMembership::Members` (r:1 w:1)
+	/// Proof: `CouncilMembership::Members` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `Council::Proposals` (r:1 w:0)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `CouncilMembership::Prime` (r:1 w:0)
+	/// Proof: `CouncilMembership::Prime` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `TechnicalCommitteeMembership::Members` (r:1 w:1)
+	/// Proof: `TechnicalCommitteeMembership::Members` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `TechnicalCommittee::Proposals` (r:1 w:0)
+	/// Proof: `TechnicalCommittee::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `TechnicalCommitteeMembership::Prime` (r:1 w:0)
+	/// Proof: `TechnicalCommitteeMembership::Prime` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `FederatedAuthorityObservation::TechnicalCommitteeMainchainMembers` (r:0 w:1)
+	/// Proof: `FederatedAuthorityObservation::TechnicalCommitteeMainchainMembers` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthorityObservation::CouncilMainchainMembers` (r:0 w:1)
+	/// Proof: `FederatedAuthorityObservation::CouncilMainchainMembers` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Members` (r:0 w:1)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Prime` (r:0 w:1)
+	/// Proof: `Council::Prime` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `TechnicalCommittee::Members` (r:0 w:1)
+	/// Proof: `TechnicalCommittee::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `TechnicalCommittee::Prime` (r:0 w:1)
+	/// Proof: `TechnicalCommittee::Prime` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthorityObservation::MembershipHistory` (r:1 w:1)
+	/// Proof: `FederatedAuthorityObservation::MembershipHistory` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `a` is `[1, 9]`.
+	/// The range of component `b` is `[1, 9]`.
+	fn reset_members_at(a: u32, b: u32, ) -> Weight {
+		// Not benchmarked yet, same as `reset_members`
+		// Proof Size summary in bytes:
+		//  Measured:  `755 + a * (54 ±0) + b * (54 ±0)`
+		//  Estimated: `9242 + a * (55 ±0) + b * (55 ±0)`
+		// Minimum execution time: 37_000_000 picoseconds.
+		Weight::from_parts(36_375_000, 9242)
+			// Standard Error: 108_253
+			.saturating_add(Weight::from_parts(62_500, 0).saturating_mul(a.into()))
+			// Standard Error: 108_253
+			.saturating_add(Weight::from_parts(62_500, 0).saturating_mul(b.into()))
+			.saturating_add(ParityDbWeight::get().reads(10_u64))
+			.saturating_add(ParityDbWeight::get().writes(12_u64))
 			.saturating_add(Weight::from_parts(0, 55).saturating_mul(a.into()))
 			.saturating_add(Weight::from_parts(0, 55).saturating_mul(b.into()))
 	}
@@ -387,4 +480,11 @@ impl WeightInfo for () {
 		Weight::from_parts(2_000_000, 0)
 			.saturating_add(ParityDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `FederatedAuthorityObservation::AllowEmptyMembers` (r:0 w:1)
+	/// Proof: `FederatedAuthorityObservation::AllowEmptyMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_allow_empty_members() -> Weight {
+		// Not benchmarked yet
+		Weight::from_parts(3_000_000, 0)
+			.saturating_add(ParityDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,177 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC methods for inspecting Federated Authority motions
+
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+use jsonrpsee::{
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned, INTERNAL_ERROR_CODE},
+};
+
+use pallet_federated_authority::{AuthId, FederatedAuthorityApi, MotionDetails, MotionStatus};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::{Block as BlockT, NumberFor};
+use std::sync::Arc;
+
+#[rpc(client, server)]
+pub trait FederatedAuthorityRpcApi<BlockHash, BlockNumber> {
+	/// Lists every motion currently stored in `pallet_federated_authority::Motions`
+	#[method(name = "federatedAuthority_pendingMotions")]
+	fn pending_motions(
+		&self,
+		at: Option<BlockHash>,
+	) -> Result<Vec<RpcMotion<BlockHash, BlockNumber>>, MotionRpcError>;
+
+	/// Returns a single motion by its call hash, or `null` if it doesn't exist
+	#[method(name = "federatedAuthority_motionStatus")]
+	fn motion_status(
+		&self,
+		motion_hash: BlockHash,
+		at: Option<BlockHash>,
+	) -> Result<Option<RpcMotion<BlockHash, BlockNumber>>, MotionRpcError>;
+}
+
+#[derive(Debug)]
+pub enum MotionRpcError {
+	UnableToGetMotions(String),
+}
+
+impl Display for MotionRpcError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			MotionRpcError::UnableToGetMotions(reason) => {
+				write!(f, "Unable to get federated authority motions: {}", reason)
+			},
+		}
+	}
+}
+
+impl std::error::Error for MotionRpcError {}
+
+impl From<MotionRpcError> for ErrorObjectOwned {
+	fn from(value: MotionRpcError) -> Self {
+		ErrorObject::owned(INTERNAL_ERROR_CODE, value.to_string(), None::<()>)
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum RpcMotionStatus {
+	Ongoing,
+	Scheduled,
+	Approved,
+	Expired,
+}
+
+impl From<MotionStatus> for RpcMotionStatus {
+	fn from(value: MotionStatus) -> Self {
+		match value {
+			MotionStatus::Ongoing => RpcMotionStatus::Ongoing,
+			MotionStatus::Scheduled => RpcMotionStatus::Scheduled,
+			MotionStatus::Approved => RpcMotionStatus::Approved,
+			MotionStatus::Expired => RpcMotionStatus::Expired,
+		}
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct RpcMotion<Hash, BlockNumber> {
+	pub motion_hash: Hash,
+	/// Name of the pallet the motion call belongs to, e.g. `System`
+	pub pallet: String,
+	/// Name of the motion call, e.g. `authorize_upgrade`
+	pub call: String,
+	/// Hex encoded SCALE call, as it was passed to `motion_approve`
+	pub call_data: String,
+	/// Ids (pallet indexes) of the authority bodies that approved the motion
+	pub approvals: Vec<AuthId>,
+	pub ends_block: BlockNumber,
+	/// Block from which the motion can be enacted, once it has been approved.
+	/// Until then, any authority body can veto it
+	pub enactment_block: Option<BlockNumber>,
+	pub status: RpcMotionStatus,
+	/// Whether `motion_close` can currently be called for this motion
+	pub closable: bool,
+}
+
+impl<Hash, BlockNumber> From<MotionDetails<Hash, BlockNumber>> for RpcMotion<Hash, BlockNumber> {
+	fn from(details: MotionDetails<Hash, BlockNumber>) -> Self {
+		Self {
+			motion_hash: details.motion_hash,
+			pallet: String::from_utf8_lossy(&details.pallet_name).into_owned(),
+			call: String::from_utf8_lossy(&details.call_name).into_owned(),
+			call_data: hex::encode(details.call),
+			approvals: details.approvals,
+			ends_block: details.ends_block,
+			enactment_block: details.enactment_block,
+			status: details.status.into(),
+			closable: details.status.is_closable(),
+		}
+	}
+}
+
+pub struct FederatedAuthority<C, Block> {
+	/// Shared reference to the client.
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> FederatedAuthority<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block> FederatedAuthorityRpcApiServer<<Block as BlockT>::Hash, NumberFor<Block>>
+	for FederatedAuthority<C, Block>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static,
+	C: ProvideRuntimeApi<Block>,
+	C: HeaderBackend<Block>,
+	C::Api: FederatedAuthorityApi<Block, <Block as BlockT>::Hash, NumberFor<Block>>,
+{
+	fn pending_motions(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<RpcMotion<<Block as BlockT>::Hash, NumberFor<Block>>>, MotionRpcError> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let motions = self
+			.client
+			.runtime_api()
+			.pending_motions(at)
+			.map_err(|e| MotionRpcError::UnableToGetMotions(e.to_string()))?;
+
+		Ok(motions.into_iter().map(Into::into).collect())
+	}
+
+	fn motion_status(
+		&self,
+		motion_hash: <Block as BlockT>::Hash,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<RpcMotion<<Block as BlockT>::Hash, NumberFor<Block>>>, MotionRpcError> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let motion = self
+			.client
+			.runtime_api()
+			.motion_status(at, motion_hash)
+			.map_err(|e| MotionRpcError::UnableToGetMotions(e.to_string()))?;
+
+		Ok(motion.map(Into::into))
+	}
+}
@@ -17,7 +17,7 @@ use crate::Pallet;
 use frame_benchmarking::{account, v2::*};
 use frame_support::traits::{EnsureOrigin, Get};
 use frame_system::RawOrigin;
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, traits::One};
 
 #[benchmarks]
 mod benchmarks {
@@ -65,6 +65,28 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn motion_approve_scheduled() -> Result<(), BenchmarkError> {
+		// Create a motion one approval short of the approval proportion
+		let max_approvals = T::MaxAuthorityBodies::get();
+		let required = (1..=max_approvals)
+			.find(|n| T::MotionApprovalProportion::reached_proportion(*n, max_approvals))
+			.ok_or(BenchmarkError::Stop("Unreachable approval proportion"))?;
+		let (motion_hash, call) = create_motion_with_approvals::<T>(required - 1);
+
+		// Get a valid origin for the next authority
+		let origin = T::MotionApprovalOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Stop("BadOrigin"))?;
+
+		#[extrinsic_call]
+		motion_approve(origin, Box::new(call));
+
+		// Verify the motion was scheduled for enactment
+		assert!(ScheduledMotions::<T>::get(motion_hash).is_some());
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn motion_approve_new() -> Result<(), BenchmarkError> {
 		let call: T::MotionCall = frame_system::Call::<T>::remark { remark: vec![1, 2, 3] }.into();
@@ -331,6 +353,9 @@ mod benchmarks {
 		// Assuming unanimous approval is required (all authorities)
 		let num_approvals = T::MaxAuthorityBodies::get();
 		let (motion_hash, _call) = create_ended_motion_with_approvals::<T>(num_approvals);
+		// Its enactment delay has passed
+		let enactment_block = frame_system::Pallet::<T>::block_number();
+		ScheduledMotions::<T>::insert(motion_hash, enactment_block);
 
 		let account = account("anyone", 0, 0);
 		let origin = RawOrigin::Signed(account);
@@ -340,6 +365,7 @@ mod benchmarks {
 
 		// Verify the motion was removed after execution
 		assert!(Motions::<T>::get(motion_hash).is_none());
+		assert!(ScheduledMotions::<T>::get(motion_hash).is_none());
 
 		Ok(())
 	}
@@ -368,5 +394,52 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn motion_close_too_early() -> Result<(), BenchmarkError> {
+		// Create an approved motion that still has to wait for its enactment delay
+		let num_approvals = T::MaxAuthorityBodies::get();
+		let (motion_hash, _call) = create_motion_with_approvals::<T>(num_approvals);
+		let enactment_block = frame_system::Pallet::<T>::block_number() + One::one();
+		ScheduledMotions::<T>::insert(motion_hash, enactment_block);
+
+		let account = account("anyone", 0, 0);
+		let origin = RawOrigin::Signed(account);
+
+		let result;
+
+		#[block]
+		{
+			result = Pallet::<T>::motion_close(origin.into(), motion_hash);
+		}
+
+		// The call should fail with `MotionTooEarlyToClose` error
+		assert!(
+			matches!(result, Err(e) if matches!(e.error, DispatchError::Module(ref m) if m.message == Some("MotionTooEarlyToClose")))
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn motion_veto() -> Result<(), BenchmarkError> {
+		// Create an approved motion that still has to wait for its enactment delay
+		let num_approvals = T::MaxAuthorityBodies::get();
+		let (motion_hash, _call) = create_motion_with_approvals::<T>(num_approvals);
+		let enactment_block = frame_system::Pallet::<T>::block_number() + One::one();
+		ScheduledMotions::<T>::insert(motion_hash, enactment_block);
+
+		let origin = T::MotionVetoOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Stop("BadOrigin"))?;
+
+		#[extrinsic_call]
+		_(origin, motion_hash);
+
+		// Verify the motion was removed
+		assert!(Motions::<T>::get(motion_hash).is_none());
+		assert!(ScheduledMotions::<T>::get(motion_hash).is_none());
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
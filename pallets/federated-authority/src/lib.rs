@@ -17,17 +17,21 @@
 mod benchmarking;
 #[cfg(test)]
 mod mock;
+mod runtime_api;
 #[cfg(test)]
 mod tests;
 pub mod types;
 pub mod weights;
 
 pub use pallet::*;
+pub use runtime_api::*;
 pub use types::*;
 
 use frame_support::{
 	BoundedBTreeSet,
 	dispatch::{Pays, PostDispatchInfo},
+	traits::{CallMetadata, GetCallMetadata},
+	weights::WeightMeter,
 };
 use sp_runtime::{
 	DispatchError, DispatchErrorWithPostInfo, Saturating,
@@ -65,7 +69,8 @@ pub mod pallet {
 		type MotionCall: Parameter
 			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
 			+ From<frame_system::Call<Self>>
-			+ GetDispatchInfo;
+			+ GetDispatchInfo
+			+ GetCallMetadata;
 		/// The number of expected authority bodies in the Federated Authority
 		#[pallet::constant]
 		type MaxAuthorityBodies: Get<u32>;
@@ -74,10 +79,18 @@ pub mod pallet {
 		type MotionDuration: Get<BlockNumberFor<Self>>;
 		/// The necessary proportion of approvals out of T::MaxAuthorityBodies for the motion to be enacted
 		type MotionApprovalProportion: FederatedAuthorityProportion;
+		/// Proportion (numerator, denominator) of its members each body needs to approve a motion.
+		/// Must match `MotionApprovalOrigin`, it's only exposed in the metadata for clients
+		#[pallet::constant]
+		type BodyApprovalProportion: Get<(u32, u32)>;
 		/// The priviledged origin to register an approved motion
 		type MotionApprovalOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = AuthId>;
 		/// The priviledged origin to revoke a previously registered approved motion before it gets enacted
 		type MotionRevokeOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = AuthId>;
+		/// Number of blocks an approved motion has to wait before being enacted, depending on its call
+		type EnactmentDelay: MotionEnactmentDelay<Self::MotionCall, BlockNumberFor<Self>>;
+		/// The priviledged origin to veto an approved motion while it waits for its enactment delay
+		type MotionVetoOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = AuthId>;
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -85,6 +98,15 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Motions<T: Config> = StorageMap<_, Identity, T::Hash, MotionInfo<T>, OptionQuery>;
 
+	/// Block from which an approved motion can be enacted. Until then, it can be vetoed
+	#[pallet::storage]
+	pub type ScheduledMotions<T: Config> =
+		StorageMap<_, Identity, T::Hash, BlockNumberFor<T>, OptionQuery>;
+
+	/// Last motion visited by `on_idle`, so the next block resumes from there
+	#[pallet::storage]
+	pub type MotionsCursor<T: Config> = StorageValue<_, T::Hash, OptionQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// The motion has already been approved by this authority.
@@ -105,6 +127,10 @@ pub mod pallet {
 		MotionAlreadyExists,
 		/// Motion expired without enough approvals
 		MotionExpired,
+		/// Motion is not approved or has no enactment delay to veto
+		MotionNotScheduled,
+		/// Motion enactment delay has passed and it can no longer be vetoed
+		MotionVetoPeriodEnded,
 	}
 
 	#[pallet::event]
@@ -120,13 +146,24 @@ pub mod pallet {
 		MotionRevoked { motion_hash: T::Hash, auth_id: AuthId },
 		/// A motion has been removed
 		MotionRemoved { motion_hash: T::Hash },
+		/// A motion was approved and can be enacted from `enactment_block`
+		MotionScheduled { motion_hash: T::Hash, enactment_block: BlockNumberFor<T> },
+		/// A scheduled motion was vetoed by one authority body before being enacted
+		MotionVetoed { motion_hash: T::Hash, auth_id: AuthId },
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::process_motions(remaining_weight)
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
 		#[pallet::weight((
-            T::WeightInfo::motion_approve(T::MaxAuthorityBodies::get()),
+            T::WeightInfo::motion_approve(T::MaxAuthorityBodies::get()).max(T::WeightInfo::motion_approve_scheduled()),
             DispatchClass::Operational
         ))]
 		#[allow(clippy::useless_conversion)]
@@ -136,6 +173,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let auth_id = T::MotionApprovalOrigin::ensure_origin(origin)?;
 			let motion_hash = T::Hashing::hash_of(&call);
+			let enactment_delay = T::EnactmentDelay::enactment_delay(&call);
 
 			let (is_new, total_approvals) = Motions::<T>::try_mutate(motion_hash, |maybe_motion| {
 				// Motion already exists, just try to insert approval
@@ -186,12 +224,27 @@ pub mod pallet {
 
 			Self::deposit_event(Event::MotionApproved { motion_hash, auth_id });
 
-			// Return actual weight based on whether motion was new or existing
+			// Start the enactment delay once the motion reaches the approval proportion
+			let previous_approvals = if is_new { 0 } else { total_approvals };
+			let is_scheduled = !Self::is_motion_approved(previous_approvals)
+				&& Self::is_motion_approved(previous_approvals.saturating_add(1));
+			if is_scheduled {
+				let enactment_block = Self::block_number().saturating_add(enactment_delay);
+				ScheduledMotions::<T>::insert(motion_hash, enactment_block);
+				Self::deposit_event(Event::MotionScheduled { motion_hash, enactment_block });
+			}
+
+			// Return actual weight based on whether motion was new, existing or got scheduled
 			let actual_weight = if is_new {
 				T::WeightInfo::motion_approve_new()
 			} else {
 				T::WeightInfo::motion_approve(total_approvals)
 			};
+			let actual_weight = if is_scheduled {
+				actual_weight.max(T::WeightInfo::motion_approve_scheduled())
+			} else {
+				actual_weight
+			};
 
 			Ok(PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Pays::No })
 		}
@@ -244,6 +297,13 @@ pub mod pallet {
 
 			Self::deposit_event(Event::MotionRevoked { motion_hash, auth_id });
 
+			// A motion falling below the approval proportion is no longer scheduled for enactment
+			if Self::is_motion_approved(initial_approvals)
+				&& !Self::is_motion_approved(final_approvals)
+			{
+				ScheduledMotions::<T>::remove(motion_hash);
+			}
+
 			// Return actual weight based on whether the motion gets removed or not
 			let actual_weight = if final_approvals == 0 {
 				// If approvals get empty, we proceed to remove the motion
@@ -277,6 +337,19 @@ pub mod pallet {
 			let has_ended = Self::has_ended(&motion);
 
 			if Self::is_motion_approved(total_approvals) {
+				// Wait for the enactment delay to pass
+				if !Self::is_enactable(motion_hash) {
+					let post_info = PostDispatchInfo {
+						actual_weight: Some(T::WeightInfo::motion_close_too_early()),
+						pays_fee: Pays::No,
+					};
+
+					return Err(DispatchErrorWithPostInfo {
+						post_info,
+						error: Error::<T>::MotionTooEarlyToClose.into(),
+					});
+				}
+
 				// Dispatch motion
 				Self::motion_dispatch(motion_hash)?;
 				// Get dispatch weight
@@ -316,9 +389,100 @@ pub mod pallet {
 				})
 			}
 		}
+
+		#[pallet::call_index(3)]
+		#[pallet::weight((T::WeightInfo::motion_veto(), DispatchClass::Operational))]
+		#[allow(clippy::useless_conversion)]
+		pub fn motion_veto(
+			origin: OriginFor<T>,
+			motion_hash: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let auth_id = T::MotionVetoOrigin::ensure_origin(origin)?;
+
+			let enactment_block =
+				ScheduledMotions::<T>::get(motion_hash).ok_or(Error::<T>::MotionNotScheduled)?;
+			ensure!(Self::block_number() < enactment_block, Error::<T>::MotionVetoPeriodEnded);
+
+			Self::deposit_event(Event::MotionVetoed { motion_hash, auth_id });
+			Self::motion_remove(motion_hash);
+
+			Ok(PostDispatchInfo {
+				actual_weight: Some(T::WeightInfo::motion_veto()),
+				pays_fee: Pays::No,
+			})
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Dispatches approved motions once their approval period has ended, and removes expired
+		/// ones, without waiting for `motion_close`. Approved motions can still be closed manually
+		/// before the end of their approval period.
+		///
+		/// Motions are visited starting after `MotionsCursor`, until `remaining_weight` is used up
+		/// or the end of `Motions` is reached. An approved motion whose call doesn't fit in the
+		/// remaining weight is retried in a later block, unless it doesn't even fit in the whole
+		/// `remaining_weight`, in which case it is skipped and has to be closed manually.
+		pub(crate) fn process_motions(remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+
+			// Reading and updating the cursor
+			if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+				return Weight::zero();
+			}
+
+			let mut cursor = MotionsCursor::<T>::get();
+
+			loop {
+				// Reading the next motion
+				if meter.try_consume(T::WeightInfo::motion_close_too_early()).is_err() {
+					break;
+				}
+
+				let next = match cursor {
+					Some(last) => {
+						Motions::<T>::iter_from(Motions::<T>::hashed_key_for(last)).next()
+					},
+					None => Motions::<T>::iter().next(),
+				};
+
+				// All motions have been visited, start over in the next block
+				let Some((motion_hash, motion)) = next else {
+					cursor = None;
+					break;
+				};
+
+				match Self::motion_status(motion_hash, &motion) {
+					MotionStatus::Approved if Self::has_ended(&motion) => {
+						let weight = T::WeightInfo::motion_close_approved()
+							.saturating_add(motion.call.get_dispatch_info().call_weight);
+
+						if meter.can_consume(weight) {
+							meter.consume(weight);
+							// The result is reported through `MotionDispatched`
+							let _ = Self::motion_dispatch(motion_hash);
+							Self::motion_remove(motion_hash);
+						} else if weight.all_lte(meter.limit()) {
+							break;
+						}
+					},
+					MotionStatus::Expired => {
+						if meter.try_consume(T::WeightInfo::motion_close_expired()).is_err() {
+							break;
+						}
+						Self::deposit_event(Event::MotionExpired { motion_hash });
+						Self::motion_remove(motion_hash);
+					},
+					MotionStatus::Ongoing | MotionStatus::Scheduled | MotionStatus::Approved => {},
+				}
+
+				cursor = Some(motion_hash);
+			}
+
+			MotionsCursor::<T>::set(cursor);
+
+			meter.consumed()
+		}
+
 		fn motion_dispatch(motion_hash: T::Hash) -> DispatchResult {
 			let motion = Motions::<T>::get(motion_hash).ok_or(Error::<T>::MotionNotFound)?;
 			let res = motion.call.dispatch(frame_system::RawOrigin::Root.into());
@@ -329,6 +493,7 @@ pub mod pallet {
 
 		fn motion_remove(motion_hash: T::Hash) {
 			Motions::<T>::remove(motion_hash);
+			ScheduledMotions::<T>::remove(motion_hash);
 			Self::deposit_event(Event::MotionRemoved { motion_hash });
 		}
 
@@ -343,11 +508,67 @@ pub mod pallet {
 			<frame_system::Pallet<T>>::block_number()
 		}
 
+		/// Returns `true` if the enactment delay of an approved motion has passed. Motions approved
+		/// before enactment delays were introduced have no entry and can be enacted straight away.
+		fn is_enactable(motion_hash: T::Hash) -> bool {
+			ScheduledMotions::<T>::get(motion_hash)
+				.is_none_or(|enactment_block| Self::block_number() >= enactment_block)
+		}
+
 		/// Returns `true` if the motion has finished (expired).
 		fn has_ended(motion: &MotionInfo<T>) -> bool {
 			Self::block_number() >= motion.ends_block
 		}
 
+		/// Computes the current status of a motion from its approvals, `ends_block` and
+		/// enactment block
+		pub fn motion_status(motion_hash: T::Hash, motion: &MotionInfo<T>) -> MotionStatus {
+			if Self::is_motion_approved(motion.approvals.len() as u32) {
+				if Self::is_enactable(motion_hash) {
+					MotionStatus::Approved
+				} else {
+					MotionStatus::Scheduled
+				}
+			} else if Self::has_ended(motion) {
+				MotionStatus::Expired
+			} else {
+				MotionStatus::Ongoing
+			}
+		}
+
+		/// Returns the details and current status of a motion, if it exists
+		pub fn motion_details(
+			motion_hash: T::Hash,
+		) -> Option<MotionDetails<T::Hash, BlockNumberFor<T>>> {
+			Motions::<T>::get(motion_hash)
+				.map(|motion| Self::to_motion_details(motion_hash, motion))
+		}
+
+		/// Returns the details and current status of every stored motion
+		pub fn pending_motions() -> Vec<MotionDetails<T::Hash, BlockNumberFor<T>>> {
+			Motions::<T>::iter()
+				.map(|(motion_hash, motion)| Self::to_motion_details(motion_hash, motion))
+				.collect()
+		}
+
+		fn to_motion_details(
+			motion_hash: T::Hash,
+			motion: MotionInfo<T>,
+		) -> MotionDetails<T::Hash, BlockNumberFor<T>> {
+			let status = Self::motion_status(motion_hash, &motion);
+			let CallMetadata { pallet_name, function_name } = motion.call.get_call_metadata();
+			MotionDetails {
+				motion_hash,
+				pallet_name: pallet_name.as_bytes().to_vec(),
+				call_name: function_name.as_bytes().to_vec(),
+				call: motion.call.encode(),
+				approvals: motion.approvals.into_iter().collect(),
+				ends_block: motion.ends_block,
+				enactment_block: ScheduledMotions::<T>::get(motion_hash),
+				status,
+			}
+		}
+
 		fn get_dispatch_weight(motion_hash: T::Hash) -> Result<Weight, DispatchError> {
 			let motion = Motions::<T>::get(motion_hash).ok_or(Error::<T>::MotionNotFound)?;
 			Ok(motion.call.get_dispatch_info().call_weight)
@@ -14,12 +14,13 @@
 use crate::{
 	self as pallet_federated_authority, AuthId,
 	types::{
-		AuthorityBody, FederatedAuthorityEnsureProportionAtLeast, FederatedAuthorityOriginManager,
+		AuthorityBody, EnactmentDelayByCategory, FederatedAuthorityEnsureProportionAtLeast,
+		FederatedAuthorityOriginManager,
 	},
 };
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{ConstU32, Everything, Hooks, NeverEnsureOrigin},
+	traits::{ConstU32, ConstU64, Contains, Everything, Hooks, NeverEnsureOrigin},
 };
 use frame_system::{EnsureNone, EnsureRoot};
 use sp_core::H256;
@@ -80,11 +81,13 @@ impl frame_system::Config for Test {
 
 // Parameters matching runtime
 pub const MOTION_DURATION: u64 = 5 * 24 * 60 * 60 / 6; // 5 days in 6-second blocks
+pub const RUNTIME_UPGRADE_ENACTMENT_DELAY: u64 = 24 * 60 * 60 / 6; // 1 day in 6-second blocks
 pub const MAX_PROPOSALS: u32 = 100;
 pub const MAX_MEMBERS: u32 = 10;
 
 parameter_types! {
 	pub const MotionDurationParam: u64 = MOTION_DURATION;
+	pub const BodyApprovalProportion: (u32, u32) = (2, 3);
 	pub MaxProposalWeight: frame_support::weights::Weight = frame_support::weights::Weight::from_parts(u64::MAX, u64::MAX);
 }
 
@@ -171,15 +174,46 @@ type TechnicalCommitteeRevoke = AuthorityBody<
 	pallet_collective::EnsureProportionAtLeast<u64, TechnicalCommitteeCollective, 2, 3>,
 >;
 
+type CouncilVeto = AuthorityBody<
+	Council,
+	pallet_collective::EnsureProportionAtLeast<u64, CouncilCollective, 2, 3>,
+>;
+type TechnicalCommitteeVeto = AuthorityBody<
+	TechnicalCommittee,
+	pallet_collective::EnsureProportionAtLeast<u64, TechnicalCommitteeCollective, 2, 3>,
+>;
+
+/// Calls dispatching another call
+pub struct WrapperCalls;
+impl Contains<RuntimeCall> for WrapperCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Council(_)
+				| RuntimeCall::TechnicalCommittee(_)
+				| RuntimeCall::FederatedAuthority(_)
+		)
+	}
+}
+
 impl crate::Config for Test {
 	type MotionCall = RuntimeCall;
 	type MaxAuthorityBodies = ConstU32<MAX_NUM_BODIES>;
 	type MotionDuration = MotionDurationParam;
 	type MotionApprovalProportion = FederatedAuthorityEnsureProportionAtLeast<2, MAX_NUM_BODIES>; // Council +  TechnicalCommittee approvals should be enough
+	type BodyApprovalProportion = BodyApprovalProportion;
 	type MotionApprovalOrigin =
 		FederatedAuthorityOriginManager<(CouncilApproval, TechnicalCommitteeApproval)>;
 	type MotionRevokeOrigin =
 		FederatedAuthorityOriginManager<(CouncilRevoke, TechnicalCommitteeRevoke)>;
+	// Parameter changes are enacted straight away once approved
+	type EnactmentDelay = EnactmentDelayByCategory<
+		Test,
+		WrapperCalls,
+		ConstU64<RUNTIME_UPGRADE_ENACTMENT_DELAY>,
+		ConstU64<0>,
+	>;
+	type MotionVetoOrigin = FederatedAuthorityOriginManager<(CouncilVeto, TechnicalCommitteeVeto)>;
 	type WeightInfo = ();
 }
 
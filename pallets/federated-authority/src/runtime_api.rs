@@ -0,0 +1,31 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for Federated Authority pallet
+
+use crate::MotionDetails;
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait FederatedAuthorityApi<Hash, BlockNumber>
+	where
+		Hash: Codec,
+		BlockNumber: Codec,
+	{
+		/// All motions currently in storage, with their approvals and status
+		fn pending_motions() -> Vec<MotionDetails<Hash, BlockNumber>>;
+		/// Approvals and status of a single motion, `None` if it doesn't exist
+		fn motion_status(motion_hash: Hash) -> Option<MotionDetails<Hash, BlockNumber>>;
+	}
+}
@@ -11,12 +11,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{Config, Error, Event, MotionInfo, Motions, mock::*, weights::WeightInfo};
+use crate::{
+	Config, Error, Event, MotionInfo, MotionStatus, Motions, MotionsCursor, ScheduledMotions,
+	mock::*, weights::WeightInfo,
+};
 use frame_support::{
 	BoundedBTreeSet, assert_noop, assert_ok,
-	dispatch::{DispatchErrorWithPostInfo, Pays, PostDispatchInfo},
+	dispatch::{DispatchErrorWithPostInfo, GetDispatchInfo, Pays, PostDispatchInfo},
+	traits::{Get, Hooks},
+	weights::Weight,
 };
 use pallet_collective::Proposals;
+use parity_scale_codec::Encode;
 use sp_core::H256;
 use sp_runtime::traits::{Dispatchable, Hash};
 use sp_std::boxed::Box;
@@ -39,6 +45,12 @@ fn tech_origin() -> RuntimeOrigin {
 	pallet_collective::RawOrigin::<u64, pallet_collective::Instance2>::Members(2, 3).into()
 }
 
+fn create_authorize_upgrade_call() -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::System(frame_system::Call::authorize_upgrade {
+		code_hash: H256::repeat_byte(1),
+	}))
+}
+
 fn invalid_council_origin() -> RuntimeOrigin {
 	pallet_collective::RawOrigin::<u64, pallet_collective::Instance1>::Members(1, 3).into()
 }
@@ -774,3 +786,370 @@ fn complete_collective_to_federated_flow() {
 		assert!(dispatched_event.is_some(), "MotionDispatched event should be emitted");
 	});
 }
+
+#[test]
+fn motion_details_reports_status() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let call = create_remark_call(vec![1, 2, 3]);
+		let motion_hash = get_motion_hash(&call);
+
+		assert!(FederatedAuthority::motion_details(motion_hash).is_none());
+
+		// A single approval keeps the motion ongoing
+		assert_ok!(FederatedAuthority::motion_approve(council_origin(), call.clone()));
+		let details = FederatedAuthority::motion_details(motion_hash).unwrap();
+		assert_eq!(details.motion_hash, motion_hash);
+		assert_eq!(details.approvals, vec![COUNCIL_PALLET_ID]);
+		assert_eq!(details.ends_block, 1 + MOTION_DURATION);
+		assert_eq!(details.status, MotionStatus::Ongoing);
+		assert_eq!(details.pallet_name, b"System".to_vec());
+		assert_eq!(details.call_name, b"remark".to_vec());
+		assert_eq!(details.call, call.encode());
+		assert!(!details.status.is_closable());
+
+		// Once every body approved the motion it is approved and can be closed
+		assert_ok!(FederatedAuthority::motion_approve(tech_origin(), call));
+		let details = FederatedAuthority::motion_details(motion_hash).unwrap();
+		assert_eq!(details.status, MotionStatus::Approved);
+		assert!(details.status.is_closable());
+	});
+}
+
+#[test]
+fn motion_details_reports_expired_motion() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let call = create_remark_call(vec![1, 2, 3]);
+		let motion_hash = get_motion_hash(&call);
+
+		assert_ok!(FederatedAuthority::motion_approve(council_origin(), call));
+
+		run_to_block(1 + MOTION_DURATION);
+
+		let details = FederatedAuthority::motion_details(motion_hash).unwrap();
+		assert_eq!(details.status, MotionStatus::Expired);
+		assert!(details.status.is_closable());
+	});
+}
+
+#[test]
+fn pending_motions_lists_all_motions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert!(FederatedAuthority::pending_motions().is_empty());
+
+		let call_1 = create_remark_call(vec![1]);
+		let call_2 = create_remark_call(vec![2]);
+		assert_ok!(FederatedAuthority::motion_approve(council_origin(), call_1.clone()));
+		assert_ok!(FederatedAuthority::motion_approve(tech_origin(), call_2.clone()));
+
+		let mut hashes: Vec<H256> = FederatedAuthority::pending_motions()
+			.into_iter()
+			.map(|m| m.motion_hash)
+			.collect();
+		hashes.sort();
+		let mut expected = vec![get_motion_hash(&call_1), get_motion_hash(&call_2)];
+		expected.sort();
+		assert_eq!(hashes, expected);
+	});
+}
+
+fn on_idle(remaining_weight: Weight) -> Weight {
+	<FederatedAuthority as Hooks<u64>>::on_idle(System::block_number(), remaining_weight)
+}
+
+#[test]
+fn on_idle_dispatches_approved_motion() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let call = create_remark_call(vec![1, 2, 3]);
+		let motion_hash = get_motion_hash(&call);
+
+		assert_ok!(FederatedAuthority::motion_approve(council_origin(), call.clone()));
+		assert_ok!(FederatedAuthority::motion_approve(tech_origin(), call));
+
+		// Approved motions are only enacted once their approval period has ended
+		on_idle(Weight::MAX);
+		assert!(Motions::<Test>::get(motion_hash).is_some());
+
+		run_to_block(1 + MOTION_DURATION);
+
+		let consumed = on_idle(Weight::MAX);
+		assert!(consumed.any_gt(Weight::zero()));
+
+		assert!(Motions::<Test>::get(motion_hash).is_none());
+		let events = federated_authority_events();
+		assert!(events.contains(&Event::MotionDispatched { motion_hash, motion_result: Ok(()) }));
+		assert!(events.contains(&Event::MotionRemoved { motion_hash }));
+
+		// Every motion was visited, so the next block starts over
+		assert!(MotionsCursor::<Test>::get().is_none());
+	});
+}
+
+#[test]
+fn on_idle_removes_expired_motion() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let call = create_remark_call(vec![1, 2, 3]);
+		let motion_hash = get_motion_hash(&call);
+
+		assert_ok!(FederatedAuthority::motion_approve(council_origin(), call));
+
+		// Ongoing motions are left untouched
+		on_idle(Weight::MAX);
+		assert!(Motions::<Test>::get(motion_hash).is_some());
+
+		run_to_block(1 + MOTION_DURATION);
+
+		on_idle(Weight::MAX);
+		assert!(Motions::<Test>::get(motion_hash).is_none());
+		let events = federated_authority_events();
+		assert!(events.contains(&Event::MotionExpired { motion_hash }));
+		assert!(events.contains(&Event::MotionRemoved { motion_hash }));
+	});
+}
+
+#[test]
+fn on_idle_respects_remaining_weight() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let call = create_remark_call(vec![1, 2, 3]);
+		let motion_hash = get_motion_hash(&call);
+
+		let dispatch_weight = <Test as Config>::WeightInfo::motion_close_approved()
+			.saturating_add(call.get_dispatch_info().call_weight);
+		assert_ok!(FederatedAuthority::motion_approve(council_origin(), call.clone()));
+		assert_ok!(FederatedAuthority::motion_approve(tech_origin(), call));
+		run_to_block(1 + MOTION_DURATION);
+
+		// No idle weight left in the block
+		assert_eq!(on_idle(Weight::zero()), Weight::zero());
+		assert!(Motions::<Test>::get(motion_hash).is_some());
+
+		// Not enough weight to dispatch the motion call
+		let limit = dispatch_weight.saturating_sub(Weight::from_parts(1, 0));
+		let consumed = on_idle(limit);
+		assert!(consumed.all_lte(limit));
+		assert!(Motions::<Test>::get(motion_hash).is_some());
+
+		// The motion is retried in the next block
+		assert!(MotionsCursor::<Test>::get().is_none());
+		on_idle(Weight::MAX);
+		assert!(Motions::<Test>::get(motion_hash).is_none());
+	});
+}
+
+#[test]
+fn parameter_change_motion_is_enactable_once_approved() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let call = create_remark_call(vec![1, 2, 3]);
+		let motion_hash = get_motion_hash(&call);
+
+		assert_ok!(FederatedAuthority::motion_approve(council_origin(), call.clone()));
+		assert!(ScheduledMotions::<Test>::get(motion_hash).is_none());
+
+		assert_ok!(FederatedAuthority::motion_approve(tech_origin(), call));
+		assert_eq!(ScheduledMotions::<Test>::get(motion_hash), Some(1));
+		assert!(
+			federated_authority_events()
+				.contains(&Event::MotionScheduled { motion_hash, enactment_block: 1 })
+		);
+
+		let details = FederatedAuthority::motion_details(motion_hash).unwrap();
+		assert_eq!(details.enactment_block, Some(1));
+		assert_eq!(details.status, MotionStatus::Approved);
+	});
+}
+
+#[test]
+fn runtime_upgrade_motion_waits_for_enactment_delay() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let call = create_authorize_upgrade_call();
+		let motion_hash = get_motion_hash(&call);
+		let enactment_block = 1 + RUNTIME_UPGRADE_ENACTMENT_DELAY;
+
+		assert_ok!(FederatedAuthority::motion_approve(council_origin(), call.clone()));
+		assert_ok!(FederatedAuthority::motion_approve(tech_origin(), call));
+
+		assert_eq!(ScheduledMotions::<Test>::get(motion_hash), Some(enactment_block));
+		assert!(
+			federated_authority_events()
+				.contains(&Event::MotionScheduled { motion_hash, enactment_block })
+		);
+		let details = FederatedAuthority::motion_details(motion_hash).unwrap();
+		assert_eq!(details.status, MotionStatus::Scheduled);
+		assert!(!details.status.is_closable());
+
+		// Cannot be closed before the enactment delay passes
+		assert_noop!(
+			FederatedAuthority::motion_close(RuntimeOrigin::signed(1), motion_hash),
+			DispatchErrorWithPostInfo {
+				post_info: PostDispatchInfo {
+					actual_weight: Some(<Test as Config>::WeightInfo::motion_close_too_early()),
+					pays_fee: Pays::No
+				},
+				error: Error::<Test>::MotionTooEarlyToClose.into()
+			}
+		);
+
+		// Nor enacted in `on_idle`
+		on_idle(Weight::MAX);
+		assert!(Motions::<Test>::get(motion_hash).is_some());
+
+		run_to_block(enactment_block);
+
+		assert_eq!(
+			FederatedAuthority::motion_details(motion_hash).unwrap().status,
+			MotionStatus::Approved
+		);
+		assert_ok!(FederatedAuthority::motion_close(RuntimeOrigin::signed(1), motion_hash));
+
+		assert!(Motions::<Test>::get(motion_hash).is_none());
+		assert!(ScheduledMotions::<Test>::get(motion_hash).is_none());
+		assert!(
+			federated_authority_events()
+				.contains(&Event::MotionDispatched { motion_hash, motion_result: Ok(()) })
+		);
+	});
+}
+
+#[test]
+fn wrapped_runtime_upgrade_motion_waits_for_enactment_delay() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		// A runtime upgrade wrapped in another call
+		let call = Box::new(RuntimeCall::Council(pallet_collective::Call::execute {
+			proposal: create_authorize_upgrade_call(),
+			length_bound: 100,
+		}));
+		let motion_hash = get_motion_hash(&call);
+
+		assert_ok!(FederatedAuthority::motion_approve(council_origin(), call.clone()));
+		assert_ok!(FederatedAuthority::motion_approve(tech_origin(), call));
+
+		assert_eq!(
+			ScheduledMotions::<Test>::get(motion_hash),
+			Some(1 + RUNTIME_UPGRADE_ENACTMENT_DELAY)
+		);
+		assert_eq!(
+			FederatedAuthority::motion_details(motion_hash).unwrap().status,
+			MotionStatus::Scheduled
+		);
+	});
+}
+
+#[test]
+fn motion_veto_removes_scheduled_motion() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let call = create_authorize_upgrade_call();
+		let motion_hash = get_motion_hash(&call);
+
+		assert_ok!(FederatedAuthority::motion_approve(council_origin(), call.clone()));
+		assert_ok!(FederatedAuthority::motion_approve(tech_origin(), call));
+
+		run_to_block(RUNTIME_UPGRADE_ENACTMENT_DELAY);
+
+		let post_info = FederatedAuthority::motion_veto(tech_origin(), motion_hash).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<Test as Config>::WeightInfo::motion_veto()));
+
+		assert!(Motions::<Test>::get(motion_hash).is_none());
+		assert!(ScheduledMotions::<Test>::get(motion_hash).is_none());
+
+		let events = federated_authority_events();
+		assert!(events.contains(&Event::MotionVetoed {
+			motion_hash,
+			auth_id: TECHNICAL_COMMITTEE_PALLET_ID
+		}));
+		assert!(events.contains(&Event::MotionRemoved { motion_hash }));
+		assert!(!events.iter().any(|e| matches!(e, Event::MotionDispatched { .. })));
+	});
+}
+
+#[test]
+fn motion_veto_fails_if_motion_not_scheduled() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let call = create_authorize_upgrade_call();
+		let motion_hash = get_motion_hash(&call);
+
+		// Unknown motion
+		assert_noop!(
+			FederatedAuthority::motion_veto(council_origin(), motion_hash),
+			Error::<Test>::MotionNotScheduled
+		);
+
+		// Motion still collecting approvals
+		assert_ok!(FederatedAuthority::motion_approve(council_origin(), call));
+		assert_noop!(
+			FederatedAuthority::motion_veto(tech_origin(), motion_hash),
+			Error::<Test>::MotionNotScheduled
+		);
+	});
+}
+
+#[test]
+fn motion_veto_fails_after_enactment_delay() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let call = create_authorize_upgrade_call();
+		let motion_hash = get_motion_hash(&call);
+
+		assert_ok!(FederatedAuthority::motion_approve(council_origin(), call.clone()));
+		assert_ok!(FederatedAuthority::motion_approve(tech_origin(), call));
+
+		run_to_block(1 + RUNTIME_UPGRADE_ENACTMENT_DELAY);
+
+		assert_noop!(
+			FederatedAuthority::motion_veto(council_origin(), motion_hash),
+			Error::<Test>::MotionVetoPeriodEnded
+		);
+	});
+}
+
+#[test]
+fn motion_veto_fails_from_unauthorized_origin() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let call = create_authorize_upgrade_call();
+		let motion_hash = get_motion_hash(&call);
+
+		assert_ok!(FederatedAuthority::motion_approve(council_origin(), call.clone()));
+		assert_ok!(FederatedAuthority::motion_approve(tech_origin(), call));
+
+		assert_noop!(
+			FederatedAuthority::motion_veto(RuntimeOrigin::signed(1), motion_hash),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			FederatedAuthority::motion_veto(invalid_council_origin(), motion_hash),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn motion_revoke_unschedules_motion() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let call = create_authorize_upgrade_call();
+		let motion_hash = get_motion_hash(&call);
+
+		assert_ok!(FederatedAuthority::motion_approve(council_origin(), call.clone()));
+		assert_ok!(FederatedAuthority::motion_approve(tech_origin(), call));
+		assert!(ScheduledMotions::<Test>::get(motion_hash).is_some());
+
+		assert_ok!(FederatedAuthority::motion_revoke(council_origin(), motion_hash));
+
+		assert!(Motions::<Test>::get(motion_hash).is_some());
+		assert!(ScheduledMotions::<Test>::get(motion_hash).is_none());
+		assert_eq!(
+			FederatedAuthority::motion_details(motion_hash).unwrap().status,
+			MotionStatus::Ongoing
+		);
+	});
+}
@@ -11,11 +11,110 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use frame_support::traits::{EnsureOrigin, PalletInfoAccess};
-use sp_std::marker::PhantomData;
+use frame_support::traits::{Contains, EnsureOrigin, Get, IsSubType, PalletInfoAccess};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::traits::Zero;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 pub type AuthId = u32;
 
+/// Lifecycle status of a stored motion
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum MotionStatus {
+	/// Still collecting approvals and the approval period has not ended
+	Ongoing,
+	/// Reached `MotionApprovalProportion`, but its enactment delay has not passed yet.
+	/// It can still be vetoed with `motion_veto`
+	Scheduled,
+	/// Reached `MotionApprovalProportion` and can be dispatched with `motion_close`
+	Approved,
+	/// The approval period ended without enough approvals and can be removed with `motion_close`
+	Expired,
+}
+
+impl MotionStatus {
+	/// Returns `true` if a `motion_close` call would succeed for a motion with this status
+	pub fn is_closable(&self) -> bool {
+		matches!(self, MotionStatus::Approved | MotionStatus::Expired)
+	}
+}
+
+/// Snapshot of a stored motion, as returned by the `FederatedAuthorityApi`
+///
+/// The call is returned SCALE encoded, along with its pallet and function names resolved by the
+/// runtime, so that clients don't need to decode it with a `RuntimeCall` of a matching version.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct MotionDetails<Hash, BlockNumber> {
+	pub motion_hash: Hash,
+	/// Name of the pallet the motion call belongs to, e.g. `System`
+	pub pallet_name: Vec<u8>,
+	/// Name of the motion call, e.g. `authorize_upgrade`
+	pub call_name: Vec<u8>,
+	/// SCALE encoded motion call
+	pub call: Vec<u8>,
+	/// Ids (pallet indexes) of the authority bodies that approved the motion
+	pub approvals: Vec<AuthId>,
+	pub ends_block: BlockNumber,
+	/// Block from which the motion can be enacted, once it has been approved
+	pub enactment_block: Option<BlockNumber>,
+	pub status: MotionStatus,
+}
+
+/// Provides the number of blocks between a motion being approved and its call being enacted
+pub trait MotionEnactmentDelay<Call, BlockNumber> {
+	fn enactment_delay(call: &Call) -> BlockNumber;
+}
+
+/// Approved motions can be enacted straight away
+impl<Call, BlockNumber: Zero> MotionEnactmentDelay<Call, BlockNumber> for () {
+	fn enactment_delay(_call: &Call) -> BlockNumber {
+		BlockNumber::zero()
+	}
+}
+
+/// Enactment delay depending on the motion call category:
+/// - `RuntimeUpgradeDelay`: for `frame_system` calls authorizing or applying a runtime upgrade,
+///   and for calls matched by `WrapperCalls`
+/// - `ParameterChangeDelay`: for any other call
+///
+/// `WrapperCalls` should match every call able to dispatch another call (e.g. `pallet_utility`,
+/// `pallet_scheduler` or `pallet_sudo` calls). Their inner calls are not inspected, so they can't
+/// be used to get a runtime upgrade enacted with the parameter change delay.
+pub struct EnactmentDelayByCategory<
+	Runtime,
+	WrapperCalls,
+	RuntimeUpgradeDelay,
+	ParameterChangeDelay,
+>(PhantomData<(Runtime, WrapperCalls, RuntimeUpgradeDelay, ParameterChangeDelay)>);
+
+impl<Runtime, Call, BlockNumber, WrapperCalls, RuntimeUpgradeDelay, ParameterChangeDelay>
+	MotionEnactmentDelay<Call, BlockNumber>
+	for EnactmentDelayByCategory<Runtime, WrapperCalls, RuntimeUpgradeDelay, ParameterChangeDelay>
+where
+	Runtime: frame_system::Config,
+	Call: IsSubType<frame_system::Call<Runtime>>,
+	WrapperCalls: Contains<Call>,
+	RuntimeUpgradeDelay: Get<BlockNumber>,
+	ParameterChangeDelay: Get<BlockNumber>,
+{
+	fn enactment_delay(call: &Call) -> BlockNumber {
+		if WrapperCalls::contains(call) {
+			return RuntimeUpgradeDelay::get();
+		}
+
+		match call.is_sub_type() {
+			Some(
+				frame_system::Call::authorize_upgrade { .. }
+				| frame_system::Call::authorize_upgrade_without_checks { .. }
+				| frame_system::Call::set_code { .. }
+				| frame_system::Call::set_code_without_checks { .. },
+			) => RuntimeUpgradeDelay::get(),
+			_ => ParameterChangeDelay::get(),
+		}
+	}
+}
+
 pub trait FederatedAuthorityProportion {
 	fn reached_proportion(n: u32, d: u32) -> bool;
 }
@@ -1,29 +1,9 @@
-
-//! Autogenerated weights for `pallet_federated_authority`
+//! Weights for `pallet_federated_authority`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 49.0.0
-//! DATE: 2025-09-29, STEPS: `100`, REPEAT: `2`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `MacBook-Pro-2.local`, CPU: `<UNKNOWN>`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
-
-// Executed Command:
-// ./target/release/midnight-node
-// benchmark
-// pallet
-// --runtime
-// ./target/release/wbuild/midnight-node-runtime/midnight_node_runtime.wasm
-// --genesis-builder=spec
-// --wasm-execution=compiled
-// --pallet=pallet_federated_authority
-// --extrinsic=*
-// --steps
-// 100
-// --repeat
-// 2
-// --output
-// pallets/federated-authority/src/weights.rs
-// --template=./res/weights-template.hbs
+//! Every call path, including `motion_approve_scheduled`, `motion_close_too_early` and
+//! `motion_veto`, has a benchmark in `benchmarking.rs`. The weights below predate the enactment
+//! delay and are pending regeneration with `earthly +rebuild-weights`; the ones marked
+//! `Not benchmarked yet` have never been measured.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -38,6 +18,7 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn motion_approve(a: u32, ) -> Weight;
 	fn motion_approve_new() -> Weight;
+	fn motion_approve_scheduled() -> Weight;
 	fn motion_approve_ended() -> Weight;
 	fn motion_approve_already_approved(a: u32, ) -> Weight;
 	fn motion_approve_exceeds_bounds() -> Weight;
@@ -50,6 +31,8 @@ pub trait WeightInfo {
 	fn motion_close_expired() -> Weight;
 	fn motion_close_approved() -> Weight;
 	fn motion_close_not_found() -> Weight;
+	fn motion_close_too_early() -> Weight;
+	fn motion_veto() -> Weight;
 }
 
 /// Weights for `pallet_federated_authority` using the Substrate node and recommended hardware.
@@ -57,6 +40,8 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:1)
 	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthority::ScheduledMotions` (r:0 w:1)
+	/// Proof: `FederatedAuthority::ScheduledMotions` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `a` is `[1, 2]`.
 	fn motion_approve(a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -65,11 +50,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 8_000_000 picoseconds.
 		Weight::from_parts(10_000_000, 3642)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 			.saturating_add(Weight::from_parts(0, 5).saturating_mul(a.into()))
 	}
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:1)
 	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthority::ScheduledMotions` (r:0 w:1)
+	/// Proof: `FederatedAuthority::ScheduledMotions` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn motion_approve_new() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `114`
@@ -77,7 +64,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 7_000_000 picoseconds.
 		Weight::from_parts(8_000_000, 3579)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `FederatedAuthority::Motions` (r:1 w:1)
+	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthority::ScheduledMotions` (r:0 w:1)
+	/// Proof: `FederatedAuthority::ScheduledMotions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn motion_approve_scheduled() -> Weight {
+		// Not benchmarked yet
+		Weight::from_parts(10_000_000, 3652)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:0)
 	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -115,6 +112,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:1)
 	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthority::ScheduledMotions` (r:0 w:1)
+	/// Proof: `FederatedAuthority::ScheduledMotions` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `a` is `[1, 2]`.
 	fn motion_revoke(a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -123,7 +122,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 8_000_000 picoseconds.
 		Weight::from_parts(12_640_000, 3648)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 			.saturating_add(Weight::from_parts(0, 4).saturating_mul(a.into()))
 	}
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:0)
@@ -162,6 +161,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:1)
 	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthority::ScheduledMotions` (r:0 w:1)
+	/// Proof: `FederatedAuthority::ScheduledMotions` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn motion_revoke_remove() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `187`
@@ -169,7 +170,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 11_000_000 picoseconds.
 		Weight::from_parts(11_000_000, 3652)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:0)
 	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -183,6 +184,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:1)
 	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthority::ScheduledMotions` (r:0 w:1)
+	/// Proof: `FederatedAuthority::ScheduledMotions` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn motion_close_expired() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `187`
@@ -190,18 +193,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 10_000_000 picoseconds.
 		Weight::from_parts(12_000_000, 3652)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:1)
 	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthority::ScheduledMotions` (r:1 w:1)
+	/// Proof: `FederatedAuthority::ScheduledMotions` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn motion_close_approved() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `191`
 		//  Estimated: `3656`
 		// Minimum execution time: 13_000_000 picoseconds.
 		Weight::from_parts(13_000_000, 3656)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:0)
 	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -213,12 +218,33 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(4_000_000, 3579)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
+	/// Storage: `FederatedAuthority::Motions` (r:1 w:0)
+	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthority::ScheduledMotions` (r:1 w:0)
+	/// Proof: `FederatedAuthority::ScheduledMotions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn motion_close_too_early() -> Weight {
+		// Not benchmarked yet
+		Weight::from_parts(7_000_000, 3652)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
+	/// Storage: `FederatedAuthority::ScheduledMotions` (r:1 w:1)
+	/// Proof: `FederatedAuthority::ScheduledMotions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthority::Motions` (r:0 w:1)
+	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn motion_veto() -> Weight {
+		// Not benchmarked yet
+		Weight::from_parts(12_000_000, 3652)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:1)
 	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthority::ScheduledMotions` (r:0 w:1)
+	/// Proof: `FederatedAuthority::ScheduledMotions` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `a` is `[1, 2]`.
 	fn motion_approve(a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -227,11 +253,13 @@ impl WeightInfo for () {
 		// Minimum execution time: 8_000_000 picoseconds.
 		Weight::from_parts(10_000_000, 3642)
 			.saturating_add(ParityDbWeight::get().reads(1_u64))
-			.saturating_add(ParityDbWeight::get().writes(1_u64))
+			.saturating_add(ParityDbWeight::get().writes(2_u64))
 			.saturating_add(Weight::from_parts(0, 5).saturating_mul(a.into()))
 	}
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:1)
 	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthority::ScheduledMotions` (r:0 w:1)
+	/// Proof: `FederatedAuthority::ScheduledMotions` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn motion_approve_new() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `114`
@@ -239,7 +267,17 @@ impl WeightInfo for () {
 		// Minimum execution time: 7_000_000 picoseconds.
 		Weight::from_parts(8_000_000, 3579)
 			.saturating_add(ParityDbWeight::get().reads(1_u64))
-			.saturating_add(ParityDbWeight::get().writes(1_u64))
+			.saturating_add(ParityDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `FederatedAuthority::Motions` (r:1 w:1)
+	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthority::ScheduledMotions` (r:0 w:1)
+	/// Proof: `FederatedAuthority::ScheduledMotions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn motion_approve_scheduled() -> Weight {
+		// Not benchmarked yet
+		Weight::from_parts(10_000_000, 3652)
+			.saturating_add(ParityDbWeight::get().reads(1_u64))
+			.saturating_add(ParityDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:0)
 	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -277,6 +315,8 @@ impl WeightInfo for () {
 	}
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:1)
 	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthority::ScheduledMotions` (r:0 w:1)
+	/// Proof: `FederatedAuthority::ScheduledMotions` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `a` is `[1, 2]`.
 	fn motion_revoke(a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -285,7 +325,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 8_000_000 picoseconds.
 		Weight::from_parts(12_640_000, 3648)
 			.saturating_add(ParityDbWeight::get().reads(1_u64))
-			.saturating_add(ParityDbWeight::get().writes(1_u64))
+			.saturating_add(ParityDbWeight::get().writes(2_u64))
 			.saturating_add(Weight::from_parts(0, 4).saturating_mul(a.into()))
 	}
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:0)
@@ -324,6 +364,8 @@ impl WeightInfo for () {
 	}
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:1)
 	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthority::ScheduledMotions` (r:0 w:1)
+	/// Proof: `FederatedAuthority::ScheduledMotions` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn motion_revoke_remove() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `187`
@@ -331,7 +373,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 11_000_000 picoseconds.
 		Weight::from_parts(11_000_000, 3652)
 			.saturating_add(ParityDbWeight::get().reads(1_u64))
-			.saturating_add(ParityDbWeight::get().writes(1_u64))
+			.saturating_add(ParityDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:0)
 	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -345,6 +387,8 @@ impl WeightInfo for () {
 	}
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:1)
 	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthority::ScheduledMotions` (r:0 w:1)
+	/// Proof: `FederatedAuthority::ScheduledMotions` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn motion_close_expired() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `187`
@@ -352,18 +396,20 @@ impl WeightInfo for () {
 		// Minimum execution time: 10_000_000 picoseconds.
 		Weight::from_parts(12_000_000, 3652)
 			.saturating_add(ParityDbWeight::get().reads(1_u64))
-			.saturating_add(ParityDbWeight::get().writes(1_u64))
+			.saturating_add(ParityDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:1)
 	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthority::ScheduledMotions` (r:1 w:1)
+	/// Proof: `FederatedAuthority::ScheduledMotions` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn motion_close_approved() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `191`
 		//  Estimated: `3656`
 		// Minimum execution time: 13_000_000 picoseconds.
 		Weight::from_parts(13_000_000, 3656)
-			.saturating_add(ParityDbWeight::get().reads(1_u64))
-			.saturating_add(ParityDbWeight::get().writes(1_u64))
+			.saturating_add(ParityDbWeight::get().reads(2_u64))
+			.saturating_add(ParityDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `FederatedAuthority::Motions` (r:1 w:0)
 	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -375,4 +421,23 @@ impl WeightInfo for () {
 		Weight::from_parts(4_000_000, 3579)
 			.saturating_add(ParityDbWeight::get().reads(1_u64))
 	}
+	/// Storage: `FederatedAuthority::Motions` (r:1 w:0)
+	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthority::ScheduledMotions` (r:1 w:0)
+	/// Proof: `FederatedAuthority::ScheduledMotions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn motion_close_too_early() -> Weight {
+		// Not benchmarked yet
+		Weight::from_parts(7_000_000, 3652)
+			.saturating_add(ParityDbWeight::get().reads(2_u64))
+	}
+	/// Storage: `FederatedAuthority::ScheduledMotions` (r:1 w:1)
+	/// Proof: `FederatedAuthority::ScheduledMotions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `FederatedAuthority::Motions` (r:0 w:1)
+	/// Proof: `FederatedAuthority::Motions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn motion_veto() -> Weight {
+		// Not benchmarked yet
+		Weight::from_parts(12_000_000, 3652)
+			.saturating_add(ParityDbWeight::get().reads(1_u64))
+			.saturating_add(ParityDbWeight::get().writes(2_u64))
+	}
 }
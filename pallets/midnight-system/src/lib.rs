@@ -9,6 +9,7 @@ pub use pallet::*;
 pub mod pallet {
 	use midnight_primitives::{
 		LedgerBlockContextProvider, LedgerStateProviderMut, MidnightSystemTransactionExecutor,
+		tx_index::{self, IndexedTransaction, IndexedTransactionStatus},
 	};
 
 	use midnight_node_ledger::types::{
@@ -75,7 +76,7 @@ pub mod pallet {
 			let runtime_version = <frame_system::Pallet<T>>::runtime_version().spec_version;
 			let block_context = <T as Config>::LedgerBlockContextProvider::get_block_context();
 
-			<T as Config>::LedgerStateProviderMut::mut_ledger_state(move |state_key| {
+			let hash = <T as Config>::LedgerStateProviderMut::mut_ledger_state(move |state_key| {
 				let result = LedgerApi::apply_system_transaction(
 					&state_key,
 					&midnight_system_tx,
@@ -83,13 +84,37 @@ pub mod pallet {
 					runtime_version,
 				)
 				.map_err(Error::<T>::from)?;
-				Ok::<(Vec<u8>, ()), Error<T>>((result.state_root, ()))
+				Ok::<(Vec<u8>, Hash), Error<T>>((result.state_root, result.tx_hash))
 			})?;
+			Self::index_system_transaction(&hash);
 
 			Ok(())
 		}
 	}
 
+	impl<T: Config> Pallet<T> {
+		fn index_system_transaction(hash: &Hash) {
+			let block_number = frame_system::Pallet::<T>::block_number();
+			let parent_hash = frame_system::Pallet::<T>::parent_hash();
+			let block_id = tx_index::block_id(
+				&block_number,
+				&parent_hash,
+				&frame_system::Pallet::<T>::digest(),
+			);
+			tx_index::index(
+				hash,
+				&block_id,
+				&IndexedTransaction {
+					block_number,
+					parent_hash,
+					extrinsic_index: frame_system::Pallet::<T>::extrinsic_index(),
+					status: IndexedTransactionStatus::Applied,
+					system: true,
+				},
+			);
+		}
+	}
+
 	impl<T: Config> MidnightSystemTransactionExecutor for Pallet<T> {
 		fn execute_system_transaction(
 			serialized_system_transaction: Vec<u8>,
@@ -108,6 +133,8 @@ pub mod pallet {
 				Ok::<(Vec<u8>, Hash), Error<T>>((result.state_root, result.tx_hash))
 			})?;
 
+			Self::index_system_transaction(&hash);
+
 			// Emit System Transaction for the indexer
 			Self::deposit_event(Event::<T>::SystemTransactionApplied(
 				super::SystemTransactionApplied { hash, serialized_system_transaction },
@@ -20,11 +20,18 @@ use jsonrpsee::{
 	types::error::{ErrorObject, ErrorObjectOwned, INVALID_PARAMS_CODE},
 };
 
+use midnight_node_ledger::types::Op;
+use midnight_primitives::tx_index::{self, IndexedTransaction, IndexedTransactionStatus};
 use pallet_midnight::MidnightRuntimeApi;
+use parity_scale_codec::Decode;
 use sc_client_api::{BlockBackend, BlockchainEvents};
 use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
-use sp_runtime::traits::Block as BlockT;
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
+use sp_runtime::{
+	SaturatedConversion,
+	traits::{Block as BlockT, Header as HeaderT, NumberFor},
+};
 use std::sync::Arc;
 
 pub const API_VERSIONS: [u32; 1] = [2];
@@ -46,6 +53,14 @@ pub trait MidnightApi<BlockHash> {
 
 	#[method(name = "midnight_ledgerVersion")]
 	fn get_ledger_version(&self, at: Option<BlockHash>) -> Result<String, BlockRpcError>;
+
+	/// Looks up a Midnight transaction by hash in the offchain index. Returns `None` for
+	/// transactions that are unknown or not in the canonical chain.
+	#[method(name = "midnight_getTransaction")]
+	fn get_transaction(
+		&self,
+		tx_hash: String,
+	) -> Result<Option<RpcIndexedTransaction<BlockHash>>, TransactionRpcError>;
 }
 
 #[derive(Debug)]
@@ -67,6 +82,14 @@ pub enum BlockRpcError {
 	UnableToGetChainVersion,
 }
 
+#[derive(Debug)]
+pub enum TransactionRpcError {
+	BadTransactionHash(String),
+	UnableToDecodeIndexEntry(String),
+	UnableToGetBlock(String),
+	UnableToDecodeTransaction,
+}
+
 #[derive(Debug, Serialize)]
 pub enum EventsError {
 	HexDecode { event: String, error: String },
@@ -121,6 +144,25 @@ impl Display for StateRpcError {
 	}
 }
 
+impl Display for TransactionRpcError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TransactionRpcError::BadTransactionHash(malformed_hash) => {
+				write!(f, "Unable to decode transaction hash: {}", malformed_hash)
+			},
+			TransactionRpcError::UnableToDecodeIndexEntry(reason) => {
+				write!(f, "Unable to decode transaction index entry: {}", reason)
+			},
+			TransactionRpcError::UnableToGetBlock(reason) => {
+				write!(f, "Error while getting block: {}", reason)
+			},
+			TransactionRpcError::UnableToDecodeTransaction => {
+				write!(f, "Unable to decode transaction")
+			},
+		}
+	}
+}
+
 impl Display for EventsError {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		match self {
@@ -146,6 +188,7 @@ impl Display for EventsError {
 impl std::error::Error for BlockRpcError {}
 impl std::error::Error for StateRpcError {}
 impl std::error::Error for EventsError {}
+impl std::error::Error for TransactionRpcError {}
 
 impl From<EventsError> for ErrorObjectOwned {
 	fn from(value: EventsError) -> Self {
@@ -165,6 +208,12 @@ impl From<StateRpcError> for ErrorObjectOwned {
 	}
 }
 
+impl From<TransactionRpcError> for ErrorObjectOwned {
+	fn from(value: TransactionRpcError) -> Self {
+		ErrorObject::owned(INVALID_PARAMS_CODE, value.to_string(), None::<()>)
+	}
+}
+
 #[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum Operation {
 	Call { address: String, entry_point: String },
@@ -201,19 +250,182 @@ pub struct RpcBlock<Header> {
 	pub transactions_index: Vec<(String, String)>,
 }
 
-pub struct Midnight<C, Block> {
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum RpcTransactionStatus {
+	Applied,
+	PartialSuccess,
+}
+
+impl From<IndexedTransactionStatus> for RpcTransactionStatus {
+	fn from(status: IndexedTransactionStatus) -> Self {
+		match status {
+			IndexedTransactionStatus::Applied => RpcTransactionStatus::Applied,
+			IndexedTransactionStatus::PartialSuccess => RpcTransactionStatus::PartialSuccess,
+		}
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct RpcIndexedTransaction<BlockHash> {
+	pub block_hash: BlockHash,
+	pub block_number: u64,
+	/// `None` for system transactions applied outside of an extrinsic
+	pub extrinsic_index: Option<u32>,
+	pub status: RpcTransactionStatus,
+	/// `None` for system transactions
+	pub transaction: Option<MidnightRpcTransaction>,
+}
+
+pub struct Midnight<C, Block, S> {
 	/// Shared reference to the client.
 	client: Arc<C>,
+	/// Offchain storage holding the transaction index.
+	offchain_storage: S,
 	//todo do I need this one?
 	_marker: std::marker::PhantomData<Block>,
 }
 
-impl<C, Block> Midnight<C, Block> {
-	pub fn new(client: Arc<C>) -> Self {
-		Self { client, _marker: Default::default() }
+impl<C, Block, S> Midnight<C, Block, S> {
+	pub fn new(client: Arc<C>, offchain_storage: S) -> Self {
+		Self { client, offchain_storage, _marker: Default::default() }
 	}
 }
 
+impl<C, Block, S> Midnight<C, Block, S>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static,
+	C: ProvideRuntimeApi<Block>,
+	C: HeaderBackend<Block>,
+	C: BlockBackend<Block>,
+	C: BlockchainEvents<Block>,
+	C::Api: MidnightRuntimeApi<Block>,
+{
+	/// Decodes the Midnight transaction of the extrinsic at `extrinsic_index` in `block_hash`
+	fn get_block_transaction(
+		&self,
+		block_hash: Block::Hash,
+		extrinsic_index: u32,
+	) -> Result<Option<MidnightRpcTransaction>, TransactionRpcError> {
+		let api = self.client.runtime_api();
+		let api_version = get_api_version::<C, Block>(&api, block_hash)
+			.map_err(|e| TransactionRpcError::UnableToGetBlock(e.to_string()))?;
+		if api_version < 6 {
+			return Ok(None);
+		}
+
+		let Some(extrinsic) = self
+			.client
+			.block_body(block_hash)
+			.map_err(|e| TransactionRpcError::UnableToGetBlock(e.to_string()))?
+			.and_then(|body| body.into_iter().nth(extrinsic_index as usize))
+		else {
+			return Ok(None);
+		};
+
+		let Some(midnight_tx) = api
+			.get_midnight_transaction(block_hash, extrinsic)
+			.map_err(|_e| TransactionRpcError::UnableToDecodeTransaction)?
+		else {
+			return Ok(None);
+		};
+
+		let tx = api
+			.get_decoded_transaction(block_hash, midnight_tx)
+			.map_err(|_e| TransactionRpcError::UnableToDecodeTransaction)?
+			.map_err(|_e| TransactionRpcError::UnableToDecodeTransaction)?;
+
+		let mut operations: Vec<Operation> = tx
+			.operations
+			.into_iter()
+			.map(|op| match op {
+				Op::Call { address, entry_point } => Operation::Call {
+					address: hex::encode(address),
+					entry_point: String::from_utf8_lossy(&entry_point).to_string(),
+				},
+				Op::Deploy { address } => Operation::Deploy { address: hex::encode(address) },
+				Op::Maintain { address } => Operation::Maintain { address: hex::encode(address) },
+				Op::ClaimRewards { value } => Operation::ClaimRewards { value },
+			})
+			.collect();
+		if tx.has_fallible_coins {
+			operations.push(Operation::FallibleCoins);
+		}
+		if tx.has_guaranteed_coins {
+			operations.push(Operation::GuaranteedCoins);
+		}
+
+		Ok(Some(MidnightRpcTransaction {
+			tx_hash: hex::encode(tx.hash),
+			operations,
+			identifiers: tx.identifiers.into_iter().map(hex::encode).collect(),
+		}))
+	}
+
+	/// Looks up the index entry of the transaction `tx_hash` in the canonical block at `number`
+	fn get_canonical_transaction(
+		&self,
+		tx_hash: &[u8; 32],
+		number: u64,
+	) -> Result<Option<RpcIndexedTransaction<Block::Hash>>, TransactionRpcError>
+	where
+		S: OffchainStorage,
+	{
+		let get_block_error =
+			|e: sp_blockchain::Error| TransactionRpcError::UnableToGetBlock(e.to_string());
+		let Some(block_hash) =
+			self.client.hash(number.saturated_into()).map_err(get_block_error)?
+		else {
+			return Ok(None);
+		};
+		let Some(header) = self.client.header(block_hash).map_err(get_block_error)? else {
+			return Ok(None);
+		};
+
+		let block_id = tx_index::block_id(header.number(), header.parent_hash(), header.digest());
+		let Some(entry) = self
+			.offchain_storage
+			.get(STORAGE_PREFIX, &tx_index::block_key(tx_hash, &block_id))
+		else {
+			return Ok(None);
+		};
+		let entry = decode_entry::<Block>(&entry)?;
+
+		let transaction = match (entry.system, entry.extrinsic_index) {
+			(false, Some(extrinsic_index)) => {
+				let transaction = self.get_block_transaction(block_hash, extrinsic_index)?;
+				// The block id can't tell apart blocks equivocated by the same author in the same
+				// slot, so the extrinsic must carry the requested transaction
+				if transaction.as_ref().is_some_and(|tx| tx.tx_hash != hex::encode(tx_hash)) {
+					return Ok(None);
+				}
+				transaction
+			},
+			_ => None,
+		};
+
+		Ok(Some(RpcIndexedTransaction {
+			block_hash,
+			block_number: number,
+			extrinsic_index: entry.extrinsic_index,
+			status: entry.status.into(),
+			transaction,
+		}))
+	}
+}
+
+/// How far from the height of the latest index entry of a transaction to look for its canonical
+/// entry. A transaction included in several forks is included in each of them within the
+/// unfinalized part of the chain, so this bounds the number of unfinalized blocks
+const MAX_FORK_DISTANCE: u64 = 128;
+
+fn decode_entry<Block: BlockT>(
+	entry: &[u8],
+) -> Result<IndexedTransaction<NumberFor<Block>, Block::Hash>, TransactionRpcError> {
+	IndexedTransaction::decode(&mut &entry[..])
+		.map_err(|e| TransactionRpcError::UnableToDecodeIndexEntry(e.to_string()))
+}
+
 fn get_api_version<C, Block>(
 	runtime_api: &sp_api::ApiRef<'_, <C as ProvideRuntimeApi<Block>>::Api>,
 	block_hash: Block::Hash,
@@ -232,7 +444,7 @@ where
 		.ok_or(sp_api::ApiError::UsingSameInstanceForDifferentBlocks)
 }
 
-impl<C, Block> MidnightApiServer<<Block as BlockT>::Hash> for Midnight<C, Block>
+impl<C, Block, S> MidnightApiServer<<Block as BlockT>::Hash> for Midnight<C, Block, S>
 where
 	Block: BlockT,
 	C: Send + Sync + 'static,
@@ -241,6 +453,7 @@ where
 	C: BlockBackend<Block>,
 	C: BlockchainEvents<Block>,
 	C::Api: MidnightRuntimeApi<Block>,
+	S: OffchainStorage + 'static,
 {
 	fn get_state(
 		&self,
@@ -310,4 +523,35 @@ where
 
 		Ok(String::from_utf8_lossy(&ledger_version).to_string())
 	}
+
+	fn get_transaction(
+		&self,
+		tx_hash: String,
+	) -> Result<Option<RpcIndexedTransaction<<Block as BlockT>::Hash>>, TransactionRpcError> {
+		let hash: [u8; 32] = hex::decode(&tx_hash)
+			.ok()
+			.and_then(|bytes| bytes.try_into().ok())
+			.ok_or(TransactionRpcError::BadTransactionHash(tx_hash))?;
+
+		// The latest entry may come from a block that is no longer canonical, but it locates the
+		// transaction within the unfinalized part of the chain
+		let Some(latest) = self.offchain_storage.get(STORAGE_PREFIX, &tx_index::key(&hash)) else {
+			return Ok(None);
+		};
+		let latest = decode_entry::<Block>(&latest)?;
+		let latest_number: u64 = latest.block_number.saturated_into();
+		let best_number: u64 = self.client.info().best_number.saturated_into();
+
+		let candidates = (0..=MAX_FORK_DISTANCE).flat_map(|distance| {
+			let below = latest_number.checked_sub(distance);
+			let above = (distance > 0).then(|| latest_number.saturating_add(distance));
+			below.into_iter().chain(above)
+		});
+		for number in candidates.filter(|number| *number <= best_number) {
+			if let Some(found) = self.get_canonical_transaction(&hash, number)? {
+				return Ok(Some(found));
+			}
+		}
+		Ok(None)
+	}
 }
@@ -11,20 +11,81 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-// //! Benchmarking setup for pallet-midnight
+//! Benchmarking setup for pallet-midnight
+//!
+//! `send_mn_transaction` is benchmarked for each transaction shape using the `undeployed` test
+//! transactions, so the benchmarks must run against a chain spec with the `undeployed` ledger
+//! genesis.
 
 use super::*;
 use frame_benchmarking::v2::*;
+use frame_support::{sp_runtime::traits::UniqueSaturatedInto, traits::Hooks};
 use frame_system::RawOrigin;
+use midnight_node_ledger::types::active_ledger_bridge as LedgerApi;
 use sp_std::vec::Vec;
 
-// TODO
+const DEPLOY_TX: &[u8] =
+	include_bytes!("../../../res/test-contract/contract_tx_1_deploy_undeployed.mn");
+const STORE_TX: &[u8] =
+	include_bytes!("../../../res/test-contract/contract_tx_2_store_undeployed.mn");
+const CHECK_TX: &[u8] =
+	include_bytes!("../../../res/test-contract/contract_tx_3_check_undeployed.mn");
+const MAINTENANCE_TX: &[u8] =
+	include_bytes!("../../../res/test-contract/contract_tx_4_change_authority_undeployed.mn");
+
+/// Extracts the transaction from a test transaction, and moves the chain to the time the
+/// transaction was created for
+fn prepare_tx<T: Config>(tx_with_context: &[u8]) -> Vec<u8> {
+	let (tx, block_context) = LedgerApi::get_transaction_with_context(tx_with_context)
+		.expect("Benchmark transactions should be valid `TransactionWithContext`s");
+	let now_ms = block_context.tblock.saturating_mul(1_000);
+	pallet_timestamp::Now::<T>::put(now_ms.unique_saturated_into());
+	tx
+}
+
+/// Applies a test transaction and finalizes the block, as a setup step
+fn apply_tx<T: Config>(tx_with_context: &[u8]) {
+	let tx = prepare_tx::<T>(tx_with_context);
+	Pallet::<T>::send_mn_transaction(RawOrigin::None.into(), tx)
+		.expect("Benchmark setup transaction should apply");
+
+	let block = frame_system::Pallet::<T>::block_number();
+	Pallet::<T>::on_finalize(block);
+	frame_system::Pallet::<T>::set_block_number(block + 1u32.into());
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
+
+	/// Benchmark a contract deployment
+	#[benchmark]
+	fn send_mn_transaction_deploy() {
+		let tx = prepare_tx::<T>(DEPLOY_TX);
+
+		#[extrinsic_call]
+		send_mn_transaction(RawOrigin::None, tx);
+	}
+
+	/// Benchmark a contract call
 	#[benchmark]
-	fn todo() {
+	fn send_mn_transaction_call() {
+		apply_tx::<T>(DEPLOY_TX);
+		let tx = prepare_tx::<T>(STORE_TX);
+
+		#[extrinsic_call]
+		send_mn_transaction(RawOrigin::None, tx);
+	}
+
+	/// Benchmark a contract maintenance update
+	#[benchmark]
+	fn send_mn_transaction_maintain() {
+		apply_tx::<T>(DEPLOY_TX);
+		apply_tx::<T>(STORE_TX);
+		apply_tx::<T>(CHECK_TX);
+		let tx = prepare_tx::<T>(MAINTENANCE_TX);
+
 		#[extrinsic_call]
-		send_mn_transaction(RawOrigin::None, Vec::default());
+		send_mn_transaction(RawOrigin::None, tx);
 	}
 }
@@ -36,17 +36,23 @@ mod tests;
 mod benchmarking;
 
 pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
+	use super::WeightInfo;
 	use frame_support::{pallet_prelude::*, sp_runtime::traits::UniqueSaturatedInto};
 	use frame_system::pallet_prelude::*;
-	use midnight_primitives::LedgerBlockContextProvider;
+	use midnight_primitives::{
+		LedgerBlockContextProvider,
+		tx_index::{self, IndexedTransaction, IndexedTransactionStatus},
+	};
 	use scale_info::prelude::{string::String, vec::Vec};
 
 	use midnight_node_ledger::types::{
-		self as LedgerTypes, GasCost, StorageCost, Tx as LedgerTx, UtxoInfo,
-		active_ledger_bridge as LedgerApi,
+		self as LedgerTypes, GasCost, StorageCost, TransactionFees, TransactionTags,
+		Tx as LedgerTx, UtxoInfo, active_ledger_bridge as LedgerApi,
 		active_version::{
 			DeserializationError, LedgerApiError, SerializationError, TransactionError,
 		},
@@ -98,6 +104,43 @@ pub mod pallet {
 	// Manually add ~1% of block weight
 	pub const EXTRA_WEIGHT_TX_SIZE: Weight = Weight::from_parts(20_000_000_000, 0);
 
+	/// Default weights applied to the encoded length and the ledger costs of a transaction
+	pub const DEFAULT_TX_COST_WEIGHTS: TxCostWeights = TxCostWeights {
+		// Copying and hashing the transaction, on top of the benchmarked transaction
+		per_byte: Weight::from_parts(2_000, 0),
+		// The ledger reports gas in picoseconds of execution time
+		per_gas: Weight::from_parts(1, 0),
+		// Writing a byte to the ledger storage
+		per_storage_cost: Weight::from_parts(50_000, 0),
+	};
+
+	/// Fee ratio of a transaction paying exactly the fee required by the ledger, used to scale
+	/// transaction pool priorities
+	pub const FEE_RATIO_UNIT: u128 = 1_000_000;
+
+	/// Weights converting the encoded length and the ledger `GasCost`/`StorageCost` of a
+	/// transaction into dispatch weight
+	#[derive(
+		Clone,
+		Copy,
+		Debug,
+		PartialEq,
+		Eq,
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub struct TxCostWeights {
+		/// Weight per byte of the encoded transaction
+		pub per_byte: Weight,
+		/// Weight per unit of gas reported by the ledger
+		pub per_gas: Weight,
+		/// Weight per unit of storage cost reported by the ledger
+		pub per_storage_cost: Weight,
+	}
+
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
@@ -126,6 +169,9 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type SlotDuration: Get<<Self as pallet_timestamp::Config>::Moment>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	// The pallet's runtime storage items.
@@ -149,16 +195,26 @@ pub mod pallet {
 		EXTRA_WEIGHT_TX_SIZE
 	}
 
+	#[pallet::type_value]
+	pub fn DefaultTxCostWeights() -> TxCostWeights {
+		DEFAULT_TX_COST_WEIGHTS
+	}
+
 	#[pallet::type_value]
 	pub fn DefaultMaxSkippedSlots() -> u8 {
 		1
 	}
 
+	/// Flat weight charged for transactions the ledger can't decode
 	#[pallet::storage]
 	#[pallet::getter(fn configurable_transaction_size_weight)]
 	pub type ConfigurableTransactionSizeWeight<T> =
 		StorageValue<_, Weight, ValueQuery, DefaultWeight>;
 
+	#[pallet::storage]
+	pub type ConfigurableTransactionCostWeights<T> =
+		StorageValue<_, TxCostWeights, ValueQuery, DefaultTxCostWeights>;
+
 	#[pallet::storage]
 	pub type ConfigurableOnInitializeWeight<T> = StorageValue<_, Weight, ValueQuery, DefaultWeight>;
 
@@ -261,6 +317,12 @@ pub mod pallet {
 		HostApiError,
 		#[codec(index = 11)]
 		NetworkIdNotString,
+		/// Transaction cost weights must be non-zero and lighter than a block
+		#[codec(index = 12)]
+		InvalidTxCostWeights,
+		/// The ledger costs of the transaction weigh more than `ConfigurableTransactionSizeWeight`
+		#[codec(index = 13)]
+		TransactionTooHeavy,
 	}
 	// grcov-excl-stop
 
@@ -351,8 +413,11 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
-		#[pallet::weight(ConfigurableTransactionSizeWeight::<T>::get())]
-		pub fn send_mn_transaction(_origin: OriginFor<T>, midnight_tx: Vec<u8>) -> DispatchResult {
+		#[pallet::weight(Pallet::<T>::send_mn_transaction_max_weight(midnight_tx.len()))]
+		pub fn send_mn_transaction(
+			_origin: OriginFor<T>,
+			midnight_tx: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
 			let state_key = StateKey::<T>::get().expect("Failed to get state key");
 			let block_context = Self::get_block_context();
 			let runtime_version = <frame_system::Pallet<T>>::runtime_version().spec_version;
@@ -365,6 +430,8 @@ pub mod pallet {
 			)
 			.map_err(Error::<T>::from)?;
 
+			let actual_weight = Self::send_mn_transaction_weight(&result, midnight_tx.len())?;
+
 			let state_key: BoundedVec<_, _> =
 				result.state_root.to_vec().try_into().expect("State key size out of boundaries");
 			StateKey::<T>::put(state_key);
@@ -402,13 +469,34 @@ pub mod pallet {
 				}));
 			}
 
-			if result.all_applied {
+			let status = if result.all_applied {
 				Self::deposit_event(Event::TxApplied(TxAppliedDetails { tx_hash }));
+				IndexedTransactionStatus::Applied
 			} else {
 				Self::deposit_event(Event::TxPartialSuccess(TxAppliedDetails { tx_hash }));
-			}
+				IndexedTransactionStatus::PartialSuccess
+			};
+
+			let block_number = frame_system::Pallet::<T>::block_number();
+			let parent_hash = frame_system::Pallet::<T>::parent_hash();
+			let block_id = tx_index::block_id(
+				&block_number,
+				&parent_hash,
+				&frame_system::Pallet::<T>::digest(),
+			);
+			tx_index::index(
+				&tx_hash,
+				&block_id,
+				&IndexedTransaction {
+					block_number,
+					parent_hash,
+					extrinsic_index: frame_system::Pallet::<T>::extrinsic_index(),
+					status,
+					system: false,
+				},
+			);
 
-			Ok(())
+			Ok(Some(actual_weight).into())
 		}
 
 		#[pallet::call_index(1)]
@@ -430,6 +518,16 @@ pub mod pallet {
 			ConfigurableTransactionSizeWeight::<T>::set(new_weight);
 			Ok(())
 		}
+
+		#[pallet::call_index(3)]
+		#[pallet::weight((T::DbWeight::get().writes(1), DispatchClass::Operational))]
+		// A system transaction for configuring the weight of transaction lengths and ledger costs
+		pub fn set_tx_cost_weights(origin: OriginFor<T>, weights: TxCostWeights) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(Self::are_valid_tx_cost_weights(&weights), Error::<T>::InvalidTxCostWeights);
+			ConfigurableTransactionCostWeights::<T>::set(weights);
+			Ok(())
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -452,7 +550,42 @@ pub mod pallet {
 				.saturating_add(slot_duration_secs)
 				.saturating_add(skipped_slots_margin);
 
-			Self::validate_unsigned(call, block_context)
+			let Call::send_mn_transaction { midnight_tx } = call else {
+				return Self::validate_unsigned(call, block_context);
+			};
+
+			// A single ledger call validates the transaction and returns what the pool needs to
+			// order it by fee, deduplicate transactions spending the same UTXOs or coins, and
+			// queue transactions spending the outputs of pending transactions
+			let state_key = StateKey::<T>::get().expect("Failed to get state key");
+			let runtime_version = <frame_system::Pallet<T>>::runtime_version().spec_version;
+			let validated = LedgerApi::validate_pool_transaction(
+				&state_key,
+				midnight_tx,
+				block_context,
+				runtime_version,
+			)
+			.map_err(|e| Self::invalid_transaction(e.into()))?;
+
+			// Dispatch rejects transactions whose ledger costs weigh more than it is charged
+			let ledger_weight =
+				Self::ledger_cost_weight(validated.storage_cost, validated.gas_cost);
+			if !ledger_weight.all_lte(ConfigurableTransactionSizeWeight::<T>::get()) {
+				return Err(InvalidTransaction::ExhaustsResources.into());
+			}
+
+			let mut pool_info = ValidTransaction::with_tag_prefix("Midnight")
+				.priority(Self::transaction_priority(midnight_tx.len(), validated.fees))
+				// Transactions can live in the pool for max 600 blocks before they must be revalidated
+				.longevity(600)
+				.and_provides(validated.tx_hash);
+			for tag in validated.tags.provides {
+				pool_info = pool_info.and_provides(tag);
+			}
+			for tag in validated.tags.requires {
+				pool_info = pool_info.and_requires(tag);
+			}
+			pool_info.build()
 		}
 
 		fn pre_dispatch(call: &Self::Call) -> Result<(), TransactionValidityError> {
@@ -549,6 +682,108 @@ pub mod pallet {
 			LedgerApi::get_ledger_parameters(&state_key)
 		}
 
+		/// Dispatch weight charged for `send_mn_transaction` before it is applied, for a
+		/// transaction of `tx_len` bytes. It only depends on the transaction length, so that it is
+		/// cheap to compute and can't fail: the heaviest transaction shape, the weight of the
+		/// length, and the maximum weight of the ledger costs. The difference with the actual
+		/// weight is refunded once the transaction is applied
+		pub fn send_mn_transaction_max_weight(tx_len: usize) -> Weight {
+			let shape_weight = T::WeightInfo::send_mn_transaction_call()
+				.max(T::WeightInfo::send_mn_transaction_deploy())
+				.max(T::WeightInfo::send_mn_transaction_maintain());
+			let length_weight = ConfigurableTransactionCostWeights::<T>::get()
+				.per_byte
+				.saturating_mul(tx_len as u64);
+
+			shape_weight
+				.saturating_add(length_weight)
+				.saturating_add(ConfigurableTransactionSizeWeight::<T>::get())
+		}
+
+		/// Actual dispatch weight of an applied `send_mn_transaction`: the benchmarked weight of a
+		/// transaction of the same shape, plus the weight of its length and of the ledger costs
+		/// reported by `apply_transaction`. Transactions whose ledger costs weigh more than the
+		/// `ConfigurableTransactionSizeWeight` charged before dispatch are rejected
+		fn send_mn_transaction_weight(
+			result: &LedgerTypes::TransactionAppliedStateRoot,
+			tx_len: usize,
+		) -> Result<Weight, Error<T>> {
+			let shape_weight = if !result.call_addresses.is_empty() {
+				T::WeightInfo::send_mn_transaction_call()
+			} else if !result.deploy_addresses.is_empty() {
+				T::WeightInfo::send_mn_transaction_deploy()
+			} else if !result.maintain_addresses.is_empty() {
+				T::WeightInfo::send_mn_transaction_maintain()
+			} else {
+				// TODO: benchmark transfers once the benchmark genesis funds a transfer fixture.
+				// Until then, a contract call is used as an upper bound
+				T::WeightInfo::send_mn_transaction_call()
+			};
+
+			let length_weight = ConfigurableTransactionCostWeights::<T>::get()
+				.per_byte
+				.saturating_mul(tx_len as u64);
+			let ledger_weight = Self::ledger_cost_weight(result.storage_cost, result.gas_cost);
+
+			ensure!(
+				ledger_weight.all_lte(ConfigurableTransactionSizeWeight::<T>::get()),
+				Error::<T>::TransactionTooHeavy
+			);
+
+			Ok(shape_weight.saturating_add(length_weight).saturating_add(ledger_weight))
+		}
+
+		/// Weight of the `GasCost`/`StorageCost` of a transaction, as reported by the ledger
+		fn ledger_cost_weight(storage_cost: StorageCost, gas_cost: GasCost) -> Weight {
+			let cost_weights = ConfigurableTransactionCostWeights::<T>::get();
+			cost_weights.per_gas.saturating_mul(gas_cost).saturating_add(
+				cost_weights
+					.per_storage_cost
+					.saturating_mul(storage_cost.try_into().unwrap_or(u64::MAX)),
+			)
+		}
+
+		/// Cost weights must be non-zero, and a single unit must not weigh more than a whole block
+		fn are_valid_tx_cost_weights(weights: &TxCostWeights) -> bool {
+			let max_block = T::BlockWeights::get().max_block;
+			[weights.per_byte, weights.per_gas, weights.per_storage_cost]
+				.iter()
+				.all(|weight| weight.ref_time() > 0 && weight.all_lte(max_block))
+		}
+
+		/// Transaction pool priority of a Midnight transaction: the ratio of the DUST fee it pays
+		/// to the fee required by the ledger, scaled by how many transactions of the same weight
+		/// fit in a block. Paying more than the required fee outbids transactions of the same
+		/// weight paying the minimum
+		pub fn transaction_priority(tx_len: usize, fees: TransactionFees) -> TransactionPriority {
+			let fee_ratio =
+				fees.paid.saturating_mul(FEE_RATIO_UNIT).saturating_div(fees.required.max(1));
+
+			// The weight charged before dispatch only depends on the length
+			let max_block_weight = T::BlockWeights::get().max_block.ref_time();
+			let tx_weight = Self::send_mn_transaction_max_weight(tx_len).ref_time().max(1);
+			let txs_per_block = max_block_weight.saturating_div(tx_weight).max(1);
+
+			let priority: TransactionPriority =
+				fee_ratio.saturating_mul(txs_per_block.into()).try_into().unwrap_or(u64::MAX);
+			log::debug!(
+				"Midnight transaction priority {priority}: paid fee {}, required fee {}, weight {tx_weight}",
+				fees.paid,
+				fees.required,
+			);
+			priority
+		}
+
+		pub fn get_transaction_tags(tx: &[u8]) -> Result<TransactionTags, LedgerApiError> {
+			let state_key = StateKey::<T>::get().expect("Failed to get state key");
+			LedgerApi::get_transaction_tags(&state_key, tx)
+		}
+
+		pub fn get_transaction_fees(tx: &[u8]) -> Result<TransactionFees, LedgerApiError> {
+			let state_key = StateKey::<T>::get().expect("Failed to get state key");
+			LedgerApi::get_transaction_fees(&state_key, tx)
+		}
+
 		pub fn get_transaction_cost(tx: &[u8]) -> Result<(StorageCost, GasCost), LedgerApiError> {
 			let state_key = StateKey::<T>::get().expect("Failed to get state key");
 			let block_context = Self::get_block_context();
@@ -15,10 +15,11 @@
 
 use midnight_node_ledger::types::{GasCost, StorageCost, Tx, active_version::LedgerApiError};
 use scale_info::prelude::string::String;
+use sp_runtime::traits::Block as BlockT;
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
-	#[api_version(5)]
+	#[api_version(6)]
 	pub trait MidnightRuntimeApi {
 		#[changed_in(2)]
 		fn get_contract_state(contract_address: Vec<u8>) -> Vec<u8>;
@@ -39,5 +40,7 @@ sp_api::decl_runtime_apis! {
 		fn get_ledger_parameters() -> Result<Vec<u8>, LedgerApiError>;
 		fn get_transaction_cost(transaction_bytes: Vec<u8>) -> Result<(StorageCost, GasCost), LedgerApiError>;
 		fn get_zswap_state_root() -> Result<Vec<u8>, LedgerApiError>;
+		/// Serialized Midnight transaction carried by `extrinsic`, if any
+		fn get_midnight_transaction(extrinsic: <Block as BlockT>::Extrinsic) -> Option<Vec<u8>>;
 	}
 }
@@ -24,16 +24,35 @@ use midnight_node_ledger::types::{
 	BlockContext,
 	active_version::{DeserializationError, LedgerApiError, MalformedError, TransactionError},
 };
+use midnight_node_ledger_helpers::{DefaultDB, NIGHT, TransactionWithContext, WalletAddress};
 use midnight_node_res::{
 	networks::{MidnightNetwork, UndeployedNetwork},
 	undeployed::transactions::{
 		CHECK_TX, CONTRACT_ADDR, DEPLOY_TX, MAINTENANCE_TX, STORE_TX, ZSWAP_TX,
 	},
 };
+use midnight_node_toolkit::{
+	ProofType, SignatureType,
+	serde_def::DeserializedTransactionsWithContext,
+	t_token,
+	tx_generator::{
+		TxGenerator,
+		builder::{BatchesArgs, Builder, SingleTxArgs},
+		destination::Destination,
+		source::Source,
+	},
+};
+use midnight_primitives::tx_index::{self, IndexedTransaction, IndexedTransactionStatus};
+use parity_scale_codec::{Decode, Encode};
+use sp_core::H256;
 use sp_runtime::{
+	Digest, DigestItem,
 	traits::ValidateUnsigned,
-	transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidityError},
+	transaction_validity::{
+		InvalidTransaction, TransactionSource, TransactionValidityError, ValidTransaction,
+	},
 };
+use std::str::FromStr;
 use test_log::test;
 
 fn init_ledger_state(block_context: BlockContext) {
@@ -81,7 +100,8 @@ fn test_send_mn_transaction_malformed_tx() {
 		let error: sp_runtime::DispatchError =
 			Error::<Test>::Deserialization(DeserializationError::Transaction).into();
 		assert_err!(
-			mock::Midnight::send_mn_transaction(RuntimeOrigin::none(), bytes.clone()),
+			mock::Midnight::send_mn_transaction(RuntimeOrigin::none(), bytes.clone())
+				.map_err(|e| e.error),
 			error
 		);
 
@@ -101,7 +121,10 @@ fn test_send_mn_transaction_invalid_tx() {
 			TransactionError::Malformed(MalformedError::ContractNotPresent),
 		)
 		.into();
-		assert_err!(mock::Midnight::send_mn_transaction(RuntimeOrigin::none(), tx), error);
+		assert_err!(
+			mock::Midnight::send_mn_transaction(RuntimeOrigin::none(), tx).map_err(|e| e.error),
+			error
+		);
 
 		// Check emitted events
 		assert!(mock::midnight_events().is_empty());
@@ -156,6 +179,169 @@ fn test_validation_works() {
 	})
 }
 
+#[test]
+fn validation_sets_priority_from_fees_and_weight() {
+	let (tx, block_context) =
+		midnight_node_ledger_helpers::extract_info_from_tx_with_context(DEPLOY_TX);
+
+	let call = MidnightCall::send_mn_transaction { midnight_tx: tx.clone() };
+	mock::new_test_ext().execute_with(|| {
+		init_ledger_state(block_context.into());
+
+		let fees = mock::Midnight::get_transaction_fees(&tx).unwrap();
+		assert!(fees.paid >= fees.required);
+
+		let valid_tx = <mock::Midnight as ValidateUnsigned>::validate_unsigned(
+			TransactionSource::External,
+			&call,
+		)
+		.unwrap();
+		assert!(valid_tx.priority > 0);
+		assert_eq!(valid_tx.priority, mock::Midnight::transaction_priority(tx.len(), fees));
+
+		// Heavier transactions paying the same fee get a lower priority
+		mock::Midnight::set_tx_cost_weights(
+			RawOrigin::Root.into(),
+			TxCostWeights {
+				per_byte: Weight::from_parts(1_000_000, 0),
+				..crate::DEFAULT_TX_COST_WEIGHTS
+			},
+		)
+		.unwrap();
+		assert!(mock::Midnight::transaction_priority(tx.len(), fees) < valid_tx.priority);
+	})
+}
+
+#[test]
+fn validation_provides_tags_for_spent_and_created_coins() {
+	let (tx, block_context) =
+		midnight_node_ledger_helpers::extract_info_from_tx_with_context(DEPLOY_TX);
+
+	let call = MidnightCall::send_mn_transaction { midnight_tx: tx.clone() };
+	mock::new_test_ext().execute_with(|| {
+		init_ledger_state(block_context.into());
+
+		let tags = mock::Midnight::get_transaction_tags(&tx).unwrap();
+		assert!(!tags.provides.is_empty());
+
+		let valid_tx = <mock::Midnight as ValidateUnsigned>::validate_unsigned(
+			TransactionSource::External,
+			&call,
+		)
+		.unwrap();
+
+		for tag in tags.provides {
+			assert!(valid_tx.provides.contains(&("Midnight", tag).encode()));
+		}
+		// The coins spent by the transaction are in the ledger state
+		assert!(valid_tx.requires.is_empty());
+	})
+}
+
+/// Transactions built by the toolkit on top of the undeployed genesis block
+async fn generate_txs(
+	builder: Builder,
+) -> DeserializedTransactionsWithContext<SignatureType, ProofType> {
+	let generator = TxGenerator::<SignatureType, ProofType>::new(
+		Source {
+			src_url: None,
+			fetch_concurrency: 20,
+			src_files: Some(vec!["../../res/genesis/genesis_block_undeployed.mn".to_string()]),
+		},
+		Destination { dest_urls: vec![], rate: 1.0, dest_file: None, to_bytes: false },
+		builder,
+		None,
+		false,
+		false,
+	)
+	.await
+	.unwrap();
+	let received_txs = generator.get_txs().await.unwrap();
+	generator.build_txs(&received_txs).await.unwrap()
+}
+
+fn single_tx(rng_seed: [u8; 32]) -> Builder {
+	Builder::SingleTx(SingleTxArgs {
+		shielded_amount: Some(0),
+		shielded_token_type: t_token(),
+		unshielded_amount: Some(100),
+		unshielded_token_type: NIGHT,
+		source_seed: "0000000000000000000000000000000000000000000000000000000000000001".to_string(),
+		destination_address: vec![
+			WalletAddress::from_str(
+				"mn_addr_undeployed13h0e3c2m7rcfem6wvjljnyjmxy5rkg9kkwcldzt73ya5pv7c4p8skzgqwj",
+			)
+			.unwrap(),
+		],
+		rng_seed: Some(rng_seed),
+	})
+}
+
+fn validate_generated_tx(
+	tx: &TransactionWithContext<SignatureType, ProofType, DefaultDB>,
+) -> ValidTransaction {
+	let call = MidnightCall::send_mn_transaction { midnight_tx: tx.tx.serialize_inner().unwrap() };
+	<mock::Midnight as ValidateUnsigned>::validate_unsigned(TransactionSource::External, &call)
+		.unwrap()
+}
+
+#[tokio::test]
+async fn validation_accepts_txs_spending_pending_utxos() {
+	let txs = generate_txs(Builder::Batches(BatchesArgs {
+		funding_seed: "0000000000000000000000000000000000000000000000000000000000000001"
+			.to_string(),
+		num_txs_per_batch: 2,
+		num_batches: 1,
+		concurrency: None,
+		rng_seed: None,
+		shielded_token_type: t_token(),
+		coin_amount: 100,
+		initial_unshielded_intent_value: 500_000_000_000_000,
+		unshielded_token_type: NIGHT,
+		enable_shielded: false,
+	}))
+	.await;
+
+	mock::new_test_ext().execute_with(|| {
+		init_ledger_state(txs.initial_tx.block_context.clone().into());
+
+		// The initial tx only spends genesis UTXOs
+		let initial_tx = validate_generated_tx(&txs.initial_tx);
+		assert!(initial_tx.requires.is_empty());
+
+		// The batch txs spend the UTXOs created by the initial tx, which is only in the pool: they
+		// are accepted, and wait for the initial tx
+		let batch_txs: Vec<_> = txs.batches[0].txs.iter().map(validate_generated_tx).collect();
+		assert_eq!(batch_txs.len(), 2);
+		for batch_tx in batch_txs.iter() {
+			assert!(!batch_tx.requires.is_empty());
+			assert!(batch_tx.requires.iter().all(|tag| initial_tx.provides.contains(tag)));
+		}
+		// Batch txs spend different UTXOs, so they don't conflict with each other
+		assert!(batch_txs[0].provides.iter().all(|tag| !batch_txs[1].provides.contains(tag)));
+	})
+}
+
+#[tokio::test]
+async fn validation_makes_txs_spending_the_same_utxo_conflict() {
+	let first = generate_txs(single_tx([1; 32])).await.initial_tx;
+	let second = generate_txs(single_tx([2; 32])).await.initial_tx;
+
+	mock::new_test_ext().execute_with(|| {
+		init_ledger_state(first.block_context.clone().into());
+
+		let first_tx = validate_generated_tx(&first);
+		let second_tx = validate_generated_tx(&second);
+
+		// Different txs, spending the same genesis UTXO of the source wallet: they provide the same
+		// spend tag, so the pool only keeps one of them
+		assert_ne!(first_tx.provides[0], second_tx.provides[0]);
+		assert!(second_tx.provides.iter().any(|tag| first_tx.provides.contains(tag)));
+		assert!(first_tx.requires.is_empty());
+		assert!(second_tx.requires.is_empty());
+	})
+}
+
 #[test]
 fn test_validation_fails() {
 	let call = MidnightCall::send_mn_transaction { midnight_tx: vec![1, 2, 3] };
@@ -194,7 +380,102 @@ fn sets_extra_transaction_size_weight() {
 }
 
 #[test]
-#[ignore = "TODO COST MODEL - fix when new Ledger's cost model is available"]
+fn sets_transaction_cost_weights() {
+	mock::new_test_ext().execute_with(|| {
+		assert_eq!(
+			crate::ConfigurableTransactionCostWeights::<Test>::get(),
+			crate::DEFAULT_TX_COST_WEIGHTS
+		);
+
+		let new_weights = TxCostWeights {
+			per_byte: Weight::from_parts(1, 0),
+			per_gas: Weight::from_parts(2, 0),
+			per_storage_cost: Weight::from_parts(3, 0),
+		};
+
+		assert_err!(
+			mock::Midnight::set_tx_cost_weights(RuntimeOrigin::none(), new_weights),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_err!(
+			mock::Midnight::set_tx_cost_weights(
+				RawOrigin::Root.into(),
+				TxCostWeights { per_gas: Weight::zero(), ..new_weights }
+			),
+			Error::<Test>::InvalidTxCostWeights
+		);
+		assert_err!(
+			mock::Midnight::set_tx_cost_weights(
+				RawOrigin::Root.into(),
+				TxCostWeights { per_byte: Weight::MAX, ..new_weights }
+			),
+			Error::<Test>::InvalidTxCostWeights
+		);
+		assert_eq!(
+			crate::ConfigurableTransactionCostWeights::<Test>::get(),
+			crate::DEFAULT_TX_COST_WEIGHTS
+		);
+
+		assert_ok!(mock::Midnight::set_tx_cost_weights(RawOrigin::Root.into(), new_weights));
+
+		assert_eq!(crate::ConfigurableTransactionCostWeights::<Test>::get(), new_weights);
+	});
+}
+
+#[test]
+fn send_mn_transaction_weight_depends_on_transaction() {
+	mock::new_test_ext().execute_with(|| {
+		let (deploy_tx, deploy_context) =
+			midnight_node_ledger_helpers::extract_info_from_tx_with_context(DEPLOY_TX);
+		let (store_tx, store_context) =
+			midnight_node_ledger_helpers::extract_info_from_tx_with_context(STORE_TX);
+		init_ledger_state(deploy_context.into());
+
+		let deploy_weight =
+			mock::Midnight::send_mn_transaction(RuntimeOrigin::none(), deploy_tx.clone())
+				.unwrap()
+				.actual_weight
+				.unwrap();
+		process_block(1, store_context.into());
+		let store_weight =
+			mock::Midnight::send_mn_transaction(RuntimeOrigin::none(), store_tx.clone())
+				.unwrap()
+				.actual_weight
+				.unwrap();
+
+		assert!(deploy_weight.any_gt(Weight::zero()));
+		assert!(store_weight.any_gt(Weight::zero()));
+		assert_ne!(deploy_weight, store_weight);
+
+		// The actual weight is refunded from the weight charged before dispatch
+		assert!(
+			deploy_weight.all_lte(mock::Midnight::send_mn_transaction_max_weight(deploy_tx.len()))
+		);
+		assert!(
+			store_weight.all_lte(mock::Midnight::send_mn_transaction_max_weight(store_tx.len()))
+		);
+	});
+}
+
+#[test]
+fn send_mn_transaction_rejects_transaction_heavier_than_charged() {
+	mock::new_test_ext().execute_with(|| {
+		let (tx, block_context) =
+			midnight_node_ledger_helpers::extract_info_from_tx_with_context(DEPLOY_TX);
+		init_ledger_state(block_context.into());
+
+		// The weight charged before dispatch doesn't cover the ledger costs of the deployment
+		crate::ConfigurableTransactionSizeWeight::<Test>::put(Weight::from_parts(1, 0));
+
+		let error: sp_runtime::DispatchError = Error::<Test>::TransactionTooHeavy.into();
+		assert_err!(
+			mock::Midnight::send_mn_transaction(RuntimeOrigin::none(), tx).map_err(|e| e.error),
+			error
+		);
+	});
+}
+
+#[test]
 fn test_get_mn_transaction_fee() {
 	mock::new_test_ext().execute_with(|| {
 		let (tx, block_context) =
@@ -304,3 +585,52 @@ fn test_send_claim_mint() {
 	*/
 }
 // grcov-excl-stop
+
+#[test]
+fn applied_transactions_are_indexed_by_hash_and_block() {
+	let (tx, block_context) =
+		midnight_node_ledger_helpers::extract_info_from_tx_with_context(DEPLOY_TX);
+
+	let mut ext = mock::new_test_ext();
+	let (tx_hash, block_id) = ext.execute_with(|| {
+		init_ledger_state(block_context.into());
+		assert_ok!(mock::Midnight::send_mn_transaction(RuntimeOrigin::none(), tx.clone()));
+
+		let tx_hash = mock::Midnight::get_decoded_transaction(&tx).unwrap().hash;
+		let block_id = tx_index::block_id(
+			&mock::System::block_number(),
+			&mock::System::parent_hash(),
+			&mock::System::digest(),
+		);
+		(tx_hash, block_id)
+	});
+	ext.persist_offchain_overlay();
+
+	let offchain_db = ext.offchain_db();
+	let latest = offchain_db.get(&tx_index::key(&tx_hash)).unwrap();
+	assert_eq!(offchain_db.get(&tx_index::block_key(&tx_hash, &block_id)), Some(latest.clone()));
+
+	let entry = IndexedTransaction::<u64, H256>::decode(&mut &latest[..]).unwrap();
+	assert_eq!(entry.block_number, 1);
+	assert_eq!(entry.status, IndexedTransactionStatus::Applied);
+	assert!(!entry.system);
+}
+
+#[test]
+fn sibling_blocks_have_different_index_ids() {
+	let digest = |slot: u64| Digest { logs: vec![DigestItem::PreRuntime(*b"aura", slot.encode())] };
+	let parent_hash = H256::repeat_byte(1);
+
+	assert_ne!(
+		tx_index::block_id(&2u64, &parent_hash, &digest(1)),
+		tx_index::block_id(&2u64, &parent_hash, &digest(2))
+	);
+
+	// Logs deposited during the execution of the block don't change its id
+	let mut executed = digest(1);
+	executed.push(DigestItem::Other(vec![1, 2, 3]));
+	assert_eq!(
+		tx_index::block_id(&2u64, &parent_hash, &digest(1)),
+		tx_index::block_id(&2u64, &parent_hash, &executed)
+	);
+}
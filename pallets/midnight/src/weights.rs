@@ -0,0 +1,82 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_midnight`
+//!
+//! These weights are NOT benchmark results: the benchmarks in `benchmarking.rs` have not been run
+//! on reference hardware yet. The execution times are rough estimates of applying the
+//! `undeployed` test transactions, including proof verification and the ledger cost model, and
+//! the proof size only covers the pallet storage. Replace them with the output of
+//! `earthly +rebuild-weights`, against a chain spec whose ledger genesis matches the `undeployed`
+//! test transactions in `res/`.
+
+use core::marker::PhantomData;
+use frame_support::{
+	traits::Get,
+	weights::{Weight, constants::ParityDbWeight},
+};
+
+/// Estimated execution time of a contract deployment, in picoseconds
+const DEPLOY_REF_TIME: u64 = 9_000_000_000;
+/// Estimated execution time of a contract call, in picoseconds
+const CALL_REF_TIME: u64 = 25_000_000_000;
+/// Estimated execution time of a contract maintenance update, in picoseconds
+const MAINTAIN_REF_TIME: u64 = 8_000_000_000;
+/// Max encoded length of `Midnight::StateKey` and `Timestamp::Now`, with their storage keys
+const PROOF_SIZE: u64 = 1_616;
+
+/// Weight functions needed for `pallet_midnight`.
+pub trait WeightInfo {
+	fn send_mn_transaction_deploy() -> Weight;
+	fn send_mn_transaction_call() -> Weight;
+	fn send_mn_transaction_maintain() -> Weight;
+}
+
+/// Weights for `pallet_midnight` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Midnight::StateKey` (r:1 w:1)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	fn send_mn_transaction_deploy() -> Weight {
+		Weight::from_parts(DEPLOY_REF_TIME, PROOF_SIZE)
+			.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+	}
+	/// Storage: `Midnight::StateKey` (r:1 w:1)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	fn send_mn_transaction_call() -> Weight {
+		Weight::from_parts(CALL_REF_TIME, PROOF_SIZE)
+			.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+	}
+	/// Storage: `Midnight::StateKey` (r:1 w:1)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	fn send_mn_transaction_maintain() -> Weight {
+		Weight::from_parts(MAINTAIN_REF_TIME, PROOF_SIZE)
+			.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn send_mn_transaction_deploy() -> Weight {
+		Weight::from_parts(DEPLOY_REF_TIME, PROOF_SIZE)
+			.saturating_add(ParityDbWeight::get().reads_writes(2, 1))
+	}
+	fn send_mn_transaction_call() -> Weight {
+		Weight::from_parts(CALL_REF_TIME, PROOF_SIZE)
+			.saturating_add(ParityDbWeight::get().reads_writes(2, 1))
+	}
+	fn send_mn_transaction_maintain() -> Weight {
+		Weight::from_parts(MAINTAIN_REF_TIME, PROOF_SIZE)
+			.saturating_add(ParityDbWeight::get().reads_writes(2, 1))
+	}
+}
@@ -0,0 +1,289 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Light client for Midnight BEEFY finality proofs.
+//!
+//! Verifies that a commitment is signed by a supermajority of a known validator set, that the MMR
+//! leaf of its parent block is included in the committed MMR root, and decodes the stake data of
+//! the leaf. [`LightClient`] follows validator set changes from a trusted checkpoint, using the
+//! next authority set announced in the verified leaves.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod types;
+
+pub use types::*;
+
+use alloc::vec::Vec;
+use pallet_mmr::primitives::{DataOrHash, LeafProof, OpaqueLeaf};
+use parity_scale_codec::{DecodeAll, Encode};
+use sp_core::{H256, ecdsa, hashing::keccak_256};
+use sp_runtime::traits::Keccak256;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+pub enum Error {
+	#[cfg_attr(
+		feature = "std",
+		error("commitment of block {block} is not newer than block {latest}")
+	)]
+	StaleCommitment { block: u32, latest: u32 },
+	#[cfg_attr(
+		feature = "std",
+		error("commitment is signed by validator set {id}, expected {current} or {next}")
+	)]
+	UnknownValidatorSet { id: u64, current: u64, next: u64 },
+	#[cfg_attr(feature = "std", error("{provided} signature(s) for a threshold of {required}"))]
+	NotEnoughSignatures { provided: usize, required: usize },
+	#[cfg_attr(feature = "std", error("{signatures} signature(s) but {proofs} authority proof(s)"))]
+	AuthorityProofCount { signatures: usize, proofs: usize },
+	#[cfg_attr(feature = "std", error("invalid or duplicate validator index {0}"))]
+	InvalidValidatorIndex(u32),
+	#[cfg_attr(feature = "std", error("validator {0} is not in the validator set"))]
+	NotInValidatorSet(u32),
+	#[cfg_attr(feature = "std", error("invalid signature of validator {0}"))]
+	InvalidSignature(u32),
+	#[cfg_attr(feature = "std", error("commitment has no MMR root"))]
+	MissingMmrRoot,
+	#[cfg_attr(
+		feature = "std",
+		error("MMR leaf of block {leaf} given for commitment of block {commitment}")
+	)]
+	LeafBlockMismatch { leaf: u32, commitment: u32 },
+	#[cfg_attr(feature = "std", error("invalid MMR leaf proof"))]
+	InvalidLeafProof,
+	#[cfg_attr(feature = "std", error("undecodable stake data in the MMR leaf"))]
+	InvalidLeafExtra,
+}
+
+/// Contents of an MMR leaf proven final
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifiedLeaf {
+	/// Block of the commitment
+	pub block_number: u32,
+	/// Block of the leaf, the parent of the commitment block
+	pub parent_number: u32,
+	pub parent_hash: Hash,
+	pub next_authority_set: AuthoritySet,
+	/// Stake of the committee members, if the runtime provided it
+	pub stakes: Option<Vec<ValidatorStake>>,
+}
+
+/// Tracks the BEEFY validator sets from a trusted checkpoint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LightClient {
+	latest_block: u32,
+	current_set: AuthoritySet,
+	next_set: AuthoritySet,
+}
+
+impl LightClient {
+	/// Starts from the validator sets at `block_number`, which must come from a trusted source
+	pub fn new(block_number: u32, current_set: AuthoritySet, next_set: AuthoritySet) -> Self {
+		Self { latest_block: block_number, current_set, next_set }
+	}
+
+	pub fn latest_block(&self) -> u32 {
+		self.latest_block
+	}
+
+	pub fn current_set(&self) -> &AuthoritySet {
+		&self.current_set
+	}
+
+	pub fn next_set(&self) -> &AuthoritySet {
+		&self.next_set
+	}
+
+	/// Verifies a finality proof newer than the latest imported one
+	///
+	/// A proof signed by the next validator set makes it the current one. The next set is then
+	/// taken from the verified leaves.
+	pub fn import(&mut self, proof: &FinalityProof) -> Result<VerifiedLeaf, Error> {
+		let commitment = &proof.signed_commitment.commitment;
+		if commitment.block_number <= self.latest_block {
+			return Err(Error::StaleCommitment {
+				block: commitment.block_number,
+				latest: self.latest_block,
+			});
+		}
+
+		let id = commitment.validator_set_id;
+		let set = if id == self.current_set.id {
+			&self.current_set
+		} else if id == self.next_set.id {
+			&self.next_set
+		} else {
+			return Err(Error::UnknownValidatorSet {
+				id,
+				current: self.current_set.id,
+				next: self.next_set.id,
+			});
+		};
+
+		let leaf = verify_finality_proof(proof, set)?;
+		if id != self.current_set.id {
+			self.current_set = self.next_set.clone();
+		}
+		if leaf.next_authority_set.id > self.next_set.id {
+			self.next_set = leaf.next_authority_set.clone();
+		}
+		self.latest_block = leaf.block_number;
+		Ok(leaf)
+	}
+}
+
+/// Minimum number of signatures for a commitment signed by `validators` validators
+///
+/// Same as the BEEFY voting rounds: strictly more than two thirds.
+pub fn signature_threshold(validators: u32) -> usize {
+	let faulty = validators.saturating_sub(1) / 3;
+	(validators - faulty) as usize
+}
+
+/// Verifies that `proof` is signed by a supermajority of `authority_set`, and that its MMR leaf is
+/// included in the MMR root of the commitment
+pub fn verify_finality_proof(
+	proof: &FinalityProof,
+	authority_set: &AuthoritySet,
+) -> Result<VerifiedLeaf, Error> {
+	let commitment = &proof.signed_commitment.commitment;
+	if commitment.validator_set_id != authority_set.id {
+		return Err(Error::UnknownValidatorSet {
+			id: commitment.validator_set_id,
+			current: authority_set.id,
+			next: authority_set.id,
+		});
+	}
+	verify_signatures(proof, authority_set)?;
+	verify_leaf(proof)
+}
+
+fn verify_signatures(proof: &FinalityProof, authority_set: &AuthoritySet) -> Result<(), Error> {
+	let signatures = &proof.signed_commitment.signatures;
+	let required = signature_threshold(authority_set.len);
+	if signatures.len() < required {
+		return Err(Error::NotEnoughSignatures { provided: signatures.len(), required });
+	}
+	if signatures.len() != proof.authority_proofs.len() {
+		return Err(Error::AuthorityProofCount {
+			signatures: signatures.len(),
+			proofs: proof.authority_proofs.len(),
+		});
+	}
+
+	let commitment_hash = keccak_256(&proof.signed_commitment.commitment.encode());
+	let root = H256(authority_set.keyset_commitment);
+	let mut last_index = None;
+	for (signature, authority_proof) in signatures.iter().zip(&proof.authority_proofs) {
+		let index = signature.validator_index;
+		if last_index.is_some_and(|last| index <= last)
+			|| index >= authority_set.len
+			|| authority_proof.validator_index != index
+		{
+			return Err(Error::InvalidValidatorIndex(index));
+		}
+		last_index = Some(index);
+
+		let in_set = binary_merkle_tree::verify_proof::<Keccak256, _, _>(
+			&root,
+			authority_proof.proof.iter().map(|item| H256(*item)),
+			authority_set.len,
+			index,
+			&signature.public_key[..],
+		);
+		if !in_set {
+			return Err(Error::NotInValidatorSet(index));
+		}
+
+		let public = ecdsa::Public::from_raw(signature.public_key);
+		if !verify_compact_signature(&signature.signature, &commitment_hash, &public) {
+			return Err(Error::InvalidSignature(index));
+		}
+	}
+	Ok(())
+}
+
+/// Verifies the MMR leaf of the parent block against the MMR root of the commitment
+fn verify_leaf(proof: &FinalityProof) -> Result<VerifiedLeaf, Error> {
+	let commitment = &proof.signed_commitment.commitment;
+	let mmr_root = commitment.mmr_root().ok_or(Error::MissingMmrRoot)?;
+
+	let leaf = &proof.leaf;
+	let (parent_number, parent_hash) = leaf.parent_number_and_hash;
+	if parent_number.checked_add(1) != Some(commitment.block_number) {
+		return Err(Error::LeafBlockMismatch {
+			leaf: parent_number,
+			commitment: commitment.block_number,
+		});
+	}
+
+	let leaf_proof = LeafProof {
+		leaf_indices: alloc::vec![proof.leaf_proof.leaf_index],
+		leaf_count: proof.leaf_proof.leaf_count,
+		items: proof.leaf_proof.items.iter().map(|item| H256(*item)).collect(),
+	};
+	pallet_mmr::verify_leaves_proof::<Keccak256, _>(
+		H256(mmr_root),
+		alloc::vec![DataOrHash::Data(OpaqueLeaf::from_encoded_leaf(leaf.encode()))],
+		leaf_proof,
+	)
+	.map_err(|_| Error::InvalidLeafProof)?;
+
+	let stakes = decode_stakes(leaf)?;
+	Ok(VerifiedLeaf {
+		block_number: commitment.block_number,
+		parent_number,
+		parent_hash,
+		next_authority_set: leaf.next_authority_set.clone(),
+		stakes,
+	})
+}
+
+/// Decodes the stake data of `leaf`
+///
+/// The runtime's `KeyAndStakeDataProvider` lists the committee selected by `select_authorities`:
+/// one entry per seat, in selection order, so a validator can appear more than once and the list
+/// is not the BEEFY validator set. It can't be checked against a keyset commitment, but it is
+/// authenticated by the MMR leaf proof like the rest of the leaf.
+fn decode_stakes(leaf: &MmrLeaf) -> Result<Option<Vec<ValidatorStake>>, Error> {
+	// The runtime leaves the extra data empty when it cannot compute the stakes
+	if leaf.leaf_extra.is_empty() {
+		return Ok(None);
+	}
+	let stakes = Vec::<ValidatorStake>::decode_all(&mut &leaf.leaf_extra[..])
+		.map_err(|_| Error::InvalidLeafExtra)?;
+	Ok(Some(stakes))
+}
+
+/// Keccak-256 binary merkle root of validator public keys, as in the MMR leaves
+pub fn keyset_commitment<'a>(keys: impl IntoIterator<Item = &'a ValidatorKey>) -> Hash {
+	binary_merkle_tree::merkle_root::<Keccak256, _>(keys).0
+}
+
+/// Verifies a signature without its recovery id, as the Cardano bridge contract does
+///
+/// Signatures are produced with a normalized `s`, so the recovery id is either 0 or 1.
+fn verify_compact_signature(signature: &[u8; 64], hash: &[u8; 32], public: &ecdsa::Public) -> bool {
+	(0..2).any(|recovery_id| {
+		let mut full = [0u8; 65];
+		full[..64].copy_from_slice(signature);
+		full[64] = recovery_id;
+		ecdsa::Pair::verify_prehashed(&ecdsa::Signature::from_raw(full), hash, public)
+	})
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,202 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use sp_core::{Pair, ecdsa};
+
+const VALIDATORS: u8 = 4;
+
+fn validators(set_id: u64) -> Vec<ecdsa::Pair> {
+	(0..VALIDATORS)
+		.map(|i| ecdsa::Pair::from_seed(&[set_id as u8 * VALIDATORS + i + 1; 32]))
+		.collect()
+}
+
+fn keys(validators: &[ecdsa::Pair]) -> Vec<ValidatorKey> {
+	validators.iter().map(|pair| pair.public().0).collect()
+}
+
+fn authority_set(id: u64) -> AuthoritySet {
+	let keys = keys(&validators(id));
+	AuthoritySet { id, len: keys.len() as u32, keyset_commitment: keyset_commitment(&keys) }
+}
+
+/// Committee seats of `KeyAndStakeDataProvider`, encoded as the runtime does: a validator can hold
+/// several seats, in selection order
+fn stakes(set_id: u64) -> Vec<u8> {
+	let validators = validators(set_id);
+	COMMITTEE_SEATS
+		.iter()
+		.map(|&seat| (validators[seat].public(), 1u64))
+		.collect::<Vec<(ecdsa::Public, u64)>>()
+		.encode()
+}
+
+const COMMITTEE_SEATS: [usize; 6] = [2, 0, 1, 2, 3, 0];
+
+/// Proof of a single leaf MMR, signed by the first `signers` validators of `set_id`
+fn finality_proof(block_number: u32, set_id: u64, signers: usize) -> FinalityProof {
+	let leaf = MmrLeaf {
+		version: 0,
+		parent_number_and_hash: (block_number - 1, [7; 32]),
+		next_authority_set: authority_set(set_id + 1),
+		leaf_extra: stakes(set_id),
+	};
+	let commitment = Commitment {
+		payload: vec![(MMR_ROOT_ID, keccak_256(&leaf.encode()).encode())],
+		block_number,
+		validator_set_id: set_id,
+	};
+
+	let validators = validators(set_id);
+	let keys = keys(&validators);
+	let commitment_hash = keccak_256(&commitment.encode());
+	let (signatures, authority_proofs) = validators[..signers]
+		.iter()
+		.enumerate()
+		.map(|(index, pair)| {
+			let signature = pair.sign_prehashed(&commitment_hash);
+			let merkle = binary_merkle_tree::merkle_proof::<Keccak256, _, _>(&keys, index as u32);
+			(
+				CommitmentSignature {
+					signature: signature.0[..64].try_into().unwrap(),
+					validator_index: index as u32,
+					public_key: keys[index],
+				},
+				AuthorityProof {
+					validator_index: index as u32,
+					proof: merkle.proof.iter().map(|item| item.0).collect(),
+				},
+			)
+		})
+		.unzip();
+
+	FinalityProof {
+		signed_commitment: SignedCommitment { commitment, signatures },
+		leaf,
+		leaf_proof: MmrLeafProof { leaf_index: 0, leaf_count: 1, items: vec![] },
+		authority_proofs,
+	}
+}
+
+#[test]
+fn verifies_finality_proof() {
+	let leaf = verify_finality_proof(&finality_proof(10, 0, 3), &authority_set(0)).unwrap();
+	assert_eq!(leaf.block_number, 10);
+	assert_eq!(leaf.parent_number, 9);
+	assert_eq!(leaf.parent_hash, [7; 32]);
+	assert_eq!(leaf.next_authority_set, authority_set(1));
+	let stakes = leaf.stakes.unwrap();
+	let keys = keys(&validators(0));
+	assert_eq!(stakes.len(), COMMITTEE_SEATS.len());
+	for (stake, seat) in stakes.iter().zip(COMMITTEE_SEATS) {
+		assert_eq!(stake, &ValidatorStake { public_key: keys[seat], stake: 1 });
+	}
+}
+
+#[test]
+fn rejects_missing_signatures() {
+	assert_eq!(
+		verify_finality_proof(&finality_proof(10, 0, 2), &authority_set(0)),
+		Err(Error::NotEnoughSignatures { provided: 2, required: 3 })
+	);
+}
+
+#[test]
+fn rejects_signer_outside_of_set() {
+	let mut proof = finality_proof(10, 0, 3);
+	proof.signed_commitment.signatures[1].public_key = validators(1)[1].public().0;
+	assert_eq!(verify_finality_proof(&proof, &authority_set(0)), Err(Error::NotInValidatorSet(1)));
+}
+
+#[test]
+fn rejects_tampered_commitment() {
+	let mut proof = finality_proof(10, 0, 3);
+	proof.signed_commitment.commitment.payload[0].1 = [0; 32].encode();
+	assert_eq!(verify_finality_proof(&proof, &authority_set(0)), Err(Error::InvalidSignature(0)));
+}
+
+#[test]
+fn rejects_tampered_leaf() {
+	let mut proof = finality_proof(10, 0, 3);
+	proof.leaf.parent_number_and_hash.1 = [8; 32];
+	assert_eq!(verify_finality_proof(&proof, &authority_set(0)), Err(Error::InvalidLeafProof));
+}
+
+#[test]
+fn rejects_undecodable_stakes() {
+	let mut proof = finality_proof(10, 0, 3);
+	// Keeps the MMR root valid, so that only the stake data is wrong
+	proof.leaf.leaf_extra.push(0);
+	let root = keccak_256(&proof.leaf.encode()).encode();
+	proof.signed_commitment = finality_proof_with_root(10, 0, root);
+	assert_eq!(verify_finality_proof(&proof, &authority_set(0)), Err(Error::InvalidLeafExtra));
+}
+
+#[test]
+fn rejects_tampered_stakes() {
+	let mut proof = finality_proof(10, 0, 3);
+	proof.leaf.leaf_extra = stakes(5);
+	assert_eq!(verify_finality_proof(&proof, &authority_set(0)), Err(Error::InvalidLeafProof));
+}
+
+#[test]
+fn accepts_leaf_without_stakes() {
+	let mut proof = finality_proof(10, 0, 3);
+	proof.leaf.leaf_extra = vec![];
+	proof.signed_commitment =
+		finality_proof_with_root(10, 0, keccak_256(&proof.leaf.encode()).encode());
+	assert_eq!(verify_finality_proof(&proof, &authority_set(0)).unwrap().stakes, None);
+}
+
+#[test]
+fn light_client_follows_validator_set_changes() {
+	let mut client = LightClient::new(1, authority_set(0), authority_set(1));
+
+	client.import(&finality_proof(5, 0, 3)).unwrap();
+	assert_eq!(client.latest_block(), 5);
+	assert_eq!(client.current_set(), &authority_set(0));
+
+	client.import(&finality_proof(8, 1, 4)).unwrap();
+	assert_eq!(client.current_set(), &authority_set(1));
+	assert_eq!(client.next_set(), &authority_set(2));
+
+	assert_eq!(
+		client.import(&finality_proof(7, 1, 4)),
+		Err(Error::StaleCommitment { block: 7, latest: 8 })
+	);
+	assert_eq!(
+		client.import(&finality_proof(9, 0, 4)),
+		Err(Error::UnknownValidatorSet { id: 0, current: 1, next: 2 })
+	);
+}
+
+/// Signed commitment of the first three validators of `set_id` to `mmr_root`
+fn finality_proof_with_root(block_number: u32, set_id: u64, mmr_root: Vec<u8>) -> SignedCommitment {
+	let mut proof = finality_proof(block_number, set_id, 0);
+	let commitment = &mut proof.signed_commitment.commitment;
+	commitment.payload = vec![(MMR_ROOT_ID, mmr_root)];
+	let commitment_hash = keccak_256(&commitment.encode());
+	let reference = finality_proof(block_number, set_id, 3);
+	proof.signed_commitment.signatures = reference
+		.signed_commitment
+		.signatures
+		.into_iter()
+		.zip(validators(set_id))
+		.map(|(signature, pair)| CommitmentSignature {
+			signature: pair.sign_prehashed(&commitment_hash).0[..64].try_into().unwrap(),
+			..signature
+		})
+		.collect();
+	proof.signed_commitment
+}
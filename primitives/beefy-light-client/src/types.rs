@@ -0,0 +1,110 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! BEEFY commitments, MMR leaves and their proofs, with the SCALE encoding used by the runtime.
+
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, Encode};
+
+pub type Hash = [u8; 32];
+
+/// Compressed secp256k1 public key of a BEEFY validator
+pub type ValidatorKey = [u8; 33];
+
+/// Payload id of the MMR root in BEEFY commitments
+pub const MMR_ROOT_ID: [u8; 2] = *b"mh";
+
+/// Proof of finality of a commitment, and of the MMR leaf of its parent block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FinalityProof {
+	pub signed_commitment: SignedCommitment,
+	/// MMR leaf of the parent of the commitment block
+	pub leaf: MmrLeaf,
+	pub leaf_proof: MmrLeafProof,
+	/// Proofs that the signers belong to the validator set of the commitment, in the order of
+	/// the signatures
+	pub authority_proofs: Vec<AuthorityProof>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedCommitment {
+	pub commitment: Commitment,
+	/// Signatures of the validators who signed the commitment, in validator index order
+	pub signatures: Vec<CommitmentSignature>,
+}
+
+/// Same SCALE encoding as `sp_consensus_beefy::Commitment<u32>`
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct Commitment {
+	pub payload: Vec<([u8; 2], Vec<u8>)>,
+	pub block_number: u32,
+	pub validator_set_id: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitmentSignature {
+	/// Compact ECDSA signature (`r || s`) of the keccak-256 hash of the SCALE encoded commitment
+	pub signature: [u8; 64],
+	pub validator_index: u32,
+	pub public_key: ValidatorKey,
+}
+
+/// Same SCALE encoding as `sp_consensus_beefy::mmr::MmrLeaf<u32, H256, H256, Vec<u8>>`
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct MmrLeaf {
+	pub version: u8,
+	pub parent_number_and_hash: (u32, Hash),
+	pub next_authority_set: AuthoritySet,
+	/// Encoded [`ValidatorStake`]s of the committee from the runtime's `KeyAndStakeDataProvider`,
+	/// or empty if the runtime could not provide them
+	pub leaf_extra: Vec<u8>,
+}
+
+/// Same SCALE encoding as `sp_consensus_beefy::mmr::BeefyAuthoritySet<H256>`
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct AuthoritySet {
+	pub id: u64,
+	pub len: u32,
+	/// Keccak-256 binary merkle root of the raw public keys of the validators
+	pub keyset_commitment: Hash,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MmrLeafProof {
+	pub leaf_index: u64,
+	pub leaf_count: u64,
+	pub items: Vec<Hash>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorityProof {
+	pub validator_index: u32,
+	pub proof: Vec<Hash>,
+}
+
+/// Same SCALE encoding as the `(BeefyId, StakeDelegation)` pairs of the MMR leaf extra data, one
+/// per committee seat
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+pub struct ValidatorStake {
+	pub public_key: ValidatorKey,
+	pub stake: u64,
+}
+
+impl Commitment {
+	pub fn mmr_root(&self) -> Option<Hash> {
+		self.payload
+			.iter()
+			.find(|(id, _)| *id == MMR_ROOT_ID)
+			.and_then(|(_, data)| Hash::decode(&mut &data[..]).ok())
+	}
+}
@@ -12,10 +12,12 @@
 // limitations under the License.
 
 use prometheus_endpoint::{
-	self as prometheus, HistogramOpts, HistogramVec, PrometheusError, Registry,
+	self as prometheus, Counter, CounterVec, F64, Gauge, HistogramOpts, HistogramVec, Opts,
+	PrometheusError, Registry, U64,
 };
+use serde::{Deserialize, Serialize};
 use std::{
-	path::PathBuf,
+	path::{Path, PathBuf},
 	sync::{Arc, Mutex},
 };
 
@@ -35,6 +37,22 @@ pub struct LedgerMetrics {
 	pub storage_fetch_time: HistogramVec,
 	/// Storage flush time
 	pub storage_flush_time: HistogramVec,
+	/// Transactions of finalized blocks by kind and outcome
+	pub txs_outcome: CounterVec<U64>,
+	/// Transactions rejected by validation or failed in finalized blocks, by reason
+	pub txs_rejected: CounterVec<U64>,
+	/// Contract operations of finalized blocks
+	pub contract_operations: CounterVec<U64>,
+	/// Unshielded UTXOs created and spent in finalized blocks
+	pub unshielded_utxos: CounterVec<U64>,
+	/// DUST generation events from cNight in finalized blocks
+	pub dust_generation_events: CounterVec<U64>,
+	/// Block rewards minted in finalized blocks. Amounts are `u128`, so this is a float counter
+	pub block_rewards_minted: Counter<F64>,
+	/// Number of commitments in the zswap commitment tree of the last executed block
+	pub zswap_tree_size: Gauge<U64>,
+	/// Size of the ledger arena database on disk
+	pub arena_disk_size: Gauge<U64>,
 }
 
 /// Time constants to build a Prometheus Histogram bucket
@@ -149,6 +167,77 @@ impl LedgerMetrics {
 				)?,
 				registry,
 			)?,
+			txs_outcome: prometheus::register(
+				CounterVec::new(
+					Opts::new(
+						"ledger_txs_outcome",
+						"Transactions of finalized blocks by kind and outcome",
+					),
+					&["kind", "outcome"],
+				)?,
+				registry,
+			)?,
+			txs_rejected: prometheus::register(
+				CounterVec::new(
+					Opts::new(
+						"ledger_txs_rejected",
+						"Transactions rejected by validation or failed in finalized blocks, by reason",
+					),
+					&["stage", "reason"],
+				)?,
+				registry,
+			)?,
+			contract_operations: prometheus::register(
+				CounterVec::new(
+					Opts::new(
+						"ledger_contract_operations",
+						"Contract deploys, calls and maintenances of finalized blocks",
+					),
+					&["operation"],
+				)?,
+				registry,
+			)?,
+			unshielded_utxos: prometheus::register(
+				CounterVec::new(
+					Opts::new(
+						"ledger_unshielded_utxos",
+						"Unshielded UTXOs created and spent in finalized blocks",
+					),
+					&["event"],
+				)?,
+				registry,
+			)?,
+			dust_generation_events: prometheus::register(
+				CounterVec::new(
+					Opts::new(
+						"ledger_dust_generation_events",
+						"DUST generation events from cNight in finalized blocks",
+					),
+					&["action"],
+				)?,
+				registry,
+			)?,
+			block_rewards_minted: prometheus::register(
+				Counter::new(
+					"ledger_block_rewards_minted",
+					"Block rewards minted in finalized blocks",
+				)?,
+				registry,
+			)?,
+			zswap_tree_size: prometheus::register(
+				Gauge::new(
+					"ledger_zswap_tree_size",
+					"Number of commitments in the zswap commitment tree of the last executed block",
+				)?,
+				registry,
+			)?,
+			arena_disk_size: prometheus::register(
+				Gauge::new(
+					"ledger_arena_disk_size",
+					"Size in bytes of the ledger arena database on disk",
+				)?,
+				registry,
+			)?,
 		})
 	}
 }
@@ -215,6 +304,42 @@ impl LedgerMetricsExt {
 			m.storage_flush_time.with_label_values(&[label]).observe(time);
 		});
 	}
+
+	pub fn observe_tx_rejected(&mut self, stage: &'static str, reason: &'static str) {
+		self.observe(|m| {
+			m.txs_rejected.with_label_values(&[stage, reason]).inc();
+		});
+	}
+
+	pub fn observe_zswap_tree_size(&mut self, size: u64) {
+		self.observe(|m| {
+			m.zswap_tree_size.set(size);
+		});
+	}
+}
+
+/// Total size in bytes of the files under `path`
+pub fn disk_size(path: &Path) -> std::io::Result<u64> {
+	let mut size = 0;
+	for entry in std::fs::read_dir(path)? {
+		let entry = entry?;
+		let metadata = entry.metadata()?;
+		size += if metadata.is_dir() { disk_size(&entry.path())? } else { metadata.len() };
+	}
+	Ok(size)
+}
+
+/// Database backend of the ledger arena. These are the databases the ledger storage crate
+/// implements: it has no RocksDB implementation
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LedgerStorageBackend {
+	/// ParityDB under the node's base path
+	#[default]
+	ParityDb,
+	/// Kept in memory only, for tests, `--tmp` dev nodes and benchmarking. Nothing survives a
+	/// restart, so it should only be used with a chain that is also wiped on restart
+	InMemory,
 }
 
 /// Ledger Storage info to be sent to host functions
@@ -222,11 +347,12 @@ impl LedgerMetricsExt {
 pub struct LedgerStorage {
 	pub db_path: PathBuf,
 	pub cache_size: usize,
+	pub backend: LedgerStorageBackend,
 }
 
 impl LedgerStorage {
-	pub fn new(db_path: PathBuf, cache_size: usize) -> Self {
-		Self { db_path, cache_size }
+	pub fn new(db_path: PathBuf, cache_size: usize, backend: LedgerStorageBackend) -> Self {
+		Self { db_path, cache_size, backend }
 	}
 }
 
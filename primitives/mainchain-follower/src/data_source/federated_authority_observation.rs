@@ -22,6 +22,16 @@ use partner_chains_db_sync_data_sources::McFollowerMetrics;
 use sidechain_domain::{McBlockHash, PolicyId};
 pub use sqlx::PgPool;
 
+/// A governance body datum was found on the main chain but could not be decoded.
+///
+/// The observation is skipped rather than treating the governance body as having no members.
+#[derive(thiserror::Error, Debug)]
+#[error("Failed to decode {body} datum: {reason}")]
+pub struct GovernanceDatumDecodeError {
+	pub body: &'static str,
+	pub reason: String,
+}
+
 #[derive(new)]
 pub struct FederatedAuthorityObservationDataSourceImpl {
 	pub pool: PgPool,
@@ -67,8 +77,10 @@ impl FederatedAuthorityObservationDataSource for FederatedAuthorityObservationDa
 					keys
 				},
 				Err(e) => {
-					log::warn!("Failed to decode council datum: {}. Using empty list.", e);
-					vec![]
+					return Err(Box::new(GovernanceDatumDecodeError {
+						body: "council",
+						reason: e.to_string(),
+					}));
 				},
 			},
 			None => {
@@ -102,11 +114,10 @@ impl FederatedAuthorityObservationDataSource for FederatedAuthorityObservationDa
 					keys
 				},
 				Err(e) => {
-					log::warn!(
-						"Failed to decode technical committee datum: {}. Using empty list.",
-						e
-					);
-					vec![]
+					return Err(Box::new(GovernanceDatumDecodeError {
+						body: "technical committee",
+						reason: e.to_string(),
+					}));
 				},
 			},
 			None => {
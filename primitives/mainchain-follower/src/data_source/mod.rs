@@ -25,7 +25,9 @@ pub use cnight_observation::{
 	TxPosition,
 };
 pub use cnight_observation_mock::CNightObservationDataSourceMock;
-pub use federated_authority_observation::FederatedAuthorityObservationDataSourceImpl;
+pub use federated_authority_observation::{
+	FederatedAuthorityObservationDataSourceImpl, GovernanceDatumDecodeError,
+};
 pub use federated_authority_observation_mock::FederatedAuthorityObservationDataSourceMock;
 
 pub use sqlx::PgPool;
@@ -13,7 +13,7 @@
 
 //! Federated Authority Observation Inherent Data Provider
 
-use crate::FederatedAuthorityObservationDataSource;
+use crate::{FederatedAuthorityObservationDataSource, data_source::GovernanceDatumDecodeError};
 use midnight_primitives_federated_authority_observation::{
 	AuthBodyConfig, FederatedAuthorityData, FederatedAuthorityObservationApi,
 	FederatedAuthorityObservationConfig,
@@ -23,7 +23,9 @@ use sp_runtime::traits::Block as BlockT;
 use std::{error::Error, sync::Arc};
 
 pub struct FederatedAuthorityInherentDataProvider {
-	pub data: FederatedAuthorityData,
+	/// `None` if the governance bodies could not be observed, in which case no membership update
+	/// is proposed for the block
+	pub data: Option<FederatedAuthorityData>,
 }
 
 impl FederatedAuthorityInherentDataProvider {
@@ -66,7 +68,14 @@ impl FederatedAuthorityInherentDataProvider {
 
 		let config = FederatedAuthorityObservationConfig { council, technical_committee };
 
-		let data = data_source.get_federated_authority_data(&config, mc_block_hash).await?;
+		let data = match data_source.get_federated_authority_data(&config, mc_block_hash).await {
+			Ok(data) => Some(data),
+			Err(e) if e.is::<GovernanceDatumDecodeError>() => {
+				log::error!("{e}. Skipping federated authority membership update.");
+				None
+			},
+			Err(e) => return Err(e),
+		};
 
 		Ok(Self { data })
 	}
@@ -78,12 +87,16 @@ impl sp_inherents::InherentDataProvider for FederatedAuthorityInherentDataProvid
 		&self,
 		inherent_data: &mut sp_inherents::InherentData,
 	) -> Result<(), sp_inherents::Error> {
+		let Some(data) = &self.data else {
+			return Ok(());
+		};
+
 		inherent_data.put_data(
 			midnight_primitives_federated_authority_observation::INHERENT_IDENTIFIER,
 			&FederatedAuthorityData {
-				council_authorities: self.data.council_authorities.clone(),
-				technical_committee_authorities: self.data.technical_committee_authorities.clone(),
-				mc_block_hash: self.data.mc_block_hash.clone(),
+				council_authorities: data.council_authorities.clone(),
+				technical_committee_authorities: data.technical_committee_authorities.clone(),
+				mc_block_hash: data.mc_block_hash.clone(),
 			},
 		)
 	}
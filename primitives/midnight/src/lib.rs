@@ -65,3 +65,75 @@ pub mod well_known_keys {
 	pub const MIDNIGHT_NETWORK_ID_KEY: &[u8] =
 		&hex!["2a760f9a173a6df5cd4373ff49fa999b47872dec514b30607df0c271efce9fc4"];
 }
+
+/// Offchain index of Midnight transactions by hash, written during block execution when the node
+/// runs with `--enable-offchain-indexing`.
+///
+/// The offchain index isn't fork-aware, and a block can't read it, so every entry is written
+/// twice: under the transaction hash, overwritten by the last imported block including it, and
+/// under the transaction hash and the [`block_id`] of the including block, which no other block
+/// overwrites.
+pub mod tx_index {
+	use super::*;
+	use sp_runtime::{Digest, DigestItem};
+
+	/// Prefix of the offchain index keys
+	pub const KEY_PREFIX: &[u8] = b"midnight::tx_index::";
+
+	#[derive(Clone, Copy, Encode, Decode, Debug, PartialEq, Eq)]
+	pub enum IndexedTransactionStatus {
+		/// Both the guaranteed and fallible parts were applied
+		Applied,
+		/// Only the guaranteed part was applied
+		PartialSuccess,
+	}
+
+	/// Location and outcome of a Midnight transaction
+	#[derive(Clone, Encode, Decode, Debug, PartialEq, Eq)]
+	pub struct IndexedTransaction<BlockNumber, BlockHash> {
+		pub block_number: BlockNumber,
+		pub parent_hash: BlockHash,
+		/// `None` for system transactions applied outside of an extrinsic
+		pub extrinsic_index: Option<u32>,
+		pub status: IndexedTransactionStatus,
+		/// Whether this is a system transaction
+		pub system: bool,
+	}
+
+	/// Identifies a block during its own execution, as a block can't know its own hash. Sibling
+	/// blocks share their number and parent hash, but not their pre-runtime digests (e.g. the
+	/// AURA slot), which are in the header from the start of the execution
+	pub fn block_id<BlockNumber: Encode, BlockHash: Encode>(
+		block_number: &BlockNumber,
+		parent_hash: &BlockHash,
+		digest: &Digest,
+	) -> [u8; 32] {
+		let pre_runtime: Vec<&DigestItem> = digest
+			.logs()
+			.iter()
+			.filter(|item| matches!(item, DigestItem::PreRuntime(..)))
+			.collect();
+		sp_io::hashing::blake2_256(&(block_number, parent_hash, pre_runtime).encode())
+	}
+
+	/// Key of the entry of the last imported block including the transaction, in any fork
+	pub fn key(tx_hash: &Hash) -> Vec<u8> {
+		[KEY_PREFIX, &tx_hash[..]].concat()
+	}
+
+	/// Key of the entry of the transaction in the block with id `block_id`
+	pub fn block_key(tx_hash: &Hash, block_id: &[u8; 32]) -> Vec<u8> {
+		[KEY_PREFIX, &tx_hash[..], &block_id[..]].concat()
+	}
+
+	/// Writes `entry` to the offchain index, for the block with id `block_id`
+	pub fn index<BlockNumber: Encode, BlockHash: Encode>(
+		tx_hash: &Hash,
+		block_id: &[u8; 32],
+		entry: &IndexedTransaction<BlockNumber, BlockHash>,
+	) {
+		let entry = entry.encode();
+		sp_io::offchain_index::set(&key(tx_hash), &entry);
+		sp_io::offchain_index::set(&block_key(tx_hash, block_id), &entry);
+	}
+}
@@ -0,0 +1,291 @@
+//! Minimal Plutus data CBOR codec.
+//!
+//! Only the subset of Plutus data used by the relay messages is supported: constructors
+//! (`Constr n [..]`, tags 121-127), lists, non-negative integers and byte strings. The encoding
+//! follows the one produced by the Cardano serialization libraries: non-empty lists and
+//! constructor fields use indefinite-length arrays, and byte strings longer than 64 bytes are
+//! split into 64-byte chunks, as required by the ledger.
+
+use crate::Error;
+
+/// Maximum length of a single byte string chunk in Plutus data
+const BYTES_CHUNK_LEN: usize = 64;
+
+/// CBOR tag of `Constr 0`; tags up to 127 map to constructors 0 to 6
+const CONSTR_TAG_BASE: u64 = 121;
+const MAX_COMPACT_CONSTR: u64 = 6;
+
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_TAG: u8 = 6;
+
+const INDEFINITE: u8 = 31;
+
+/// Maximum nesting of lists and constructors when decoding, relay messages nest 5 levels deep
+const MAX_DEPTH: usize = 16;
+const BREAK: u8 = 0xff;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlutusData {
+	Constr(u64, Vec<PlutusData>),
+	List(Vec<PlutusData>),
+	Int(u64),
+	Bytes(Vec<u8>),
+}
+
+impl PlutusData {
+	pub fn to_cbor(&self) -> Vec<u8> {
+		let mut out = Vec::new();
+		self.encode(&mut out);
+		out
+	}
+
+	pub fn from_cbor(data: &[u8]) -> Result<Self, Error> {
+		let mut decoder = Decoder { data, pos: 0, depth: 0 };
+		let value = decoder.data()?;
+		if decoder.pos != data.len() {
+			return Err(Error::CborDecodeError(format!(
+				"{} trailing byte(s)",
+				data.len() - decoder.pos
+			)));
+		}
+		Ok(value)
+	}
+
+	fn encode(&self, out: &mut Vec<u8>) {
+		match self {
+			PlutusData::Constr(index, fields) => {
+				// Only the compact tags are needed by the relay messages
+				assert!(*index <= MAX_COMPACT_CONSTR, "unsupported constructor index {index}");
+				write_head(out, MAJOR_TAG, CONSTR_TAG_BASE + index);
+				encode_list(out, fields);
+			},
+			PlutusData::List(items) => encode_list(out, items),
+			PlutusData::Int(value) => write_head(out, MAJOR_UNSIGNED, *value),
+			PlutusData::Bytes(bytes) if bytes.len() <= BYTES_CHUNK_LEN => {
+				write_head(out, MAJOR_BYTES, bytes.len() as u64);
+				out.extend_from_slice(bytes);
+			},
+			PlutusData::Bytes(bytes) => {
+				out.push(MAJOR_BYTES << 5 | INDEFINITE);
+				for chunk in bytes.chunks(BYTES_CHUNK_LEN) {
+					write_head(out, MAJOR_BYTES, chunk.len() as u64);
+					out.extend_from_slice(chunk);
+				}
+				out.push(BREAK);
+			},
+		}
+	}
+
+	/// Fields of `Constr index [..]`, checking the constructor index and number of fields
+	pub fn constr_fields(&self, index: u64, len: usize) -> Result<&[PlutusData], Error> {
+		match self {
+			PlutusData::Constr(i, fields) if *i == index && fields.len() == len => Ok(fields),
+			other => Err(Error::CborDecodeError(format!(
+				"expected Constr {index} with {len} field(s), found {other:?}"
+			))),
+		}
+	}
+
+	pub fn as_list(&self) -> Result<&[PlutusData], Error> {
+		match self {
+			PlutusData::List(items) => Ok(items),
+			other => Err(Error::CborDecodeError(format!("expected a list, found {other:?}"))),
+		}
+	}
+
+	pub fn as_int(&self) -> Result<u64, Error> {
+		match self {
+			PlutusData::Int(value) => Ok(*value),
+			other => Err(Error::CborDecodeError(format!("expected an integer, found {other:?}"))),
+		}
+	}
+
+	pub fn as_bytes(&self) -> Result<&[u8], Error> {
+		match self {
+			PlutusData::Bytes(bytes) => Ok(bytes),
+			other => Err(Error::CborDecodeError(format!("expected bytes, found {other:?}"))),
+		}
+	}
+}
+
+fn encode_list(out: &mut Vec<u8>, items: &[PlutusData]) {
+	if items.is_empty() {
+		write_head(out, MAJOR_ARRAY, 0);
+		return;
+	}
+	out.push(MAJOR_ARRAY << 5 | INDEFINITE);
+	for item in items {
+		item.encode(out);
+	}
+	out.push(BREAK);
+}
+
+fn write_head(out: &mut Vec<u8>, major: u8, value: u64) {
+	let major = major << 5;
+	match value {
+		0..=23 => out.push(major | value as u8),
+		24..=0xff => out.extend([major | 24, value as u8]),
+		0x100..=0xffff => {
+			out.push(major | 25);
+			out.extend((value as u16).to_be_bytes());
+		},
+		0x1_0000..=0xffff_ffff => {
+			out.push(major | 26);
+			out.extend((value as u32).to_be_bytes());
+		},
+		_ => {
+			out.push(major | 27);
+			out.extend(value.to_be_bytes());
+		},
+	}
+}
+
+struct Decoder<'a> {
+	data: &'a [u8],
+	pos: usize,
+	/// Number of lists and constructors the decoder is in
+	depth: usize,
+}
+
+impl Decoder<'_> {
+	fn data(&mut self) -> Result<PlutusData, Error> {
+		let (major, value) = self.head()?;
+		match (major, value) {
+			(MAJOR_UNSIGNED, Some(value)) => Ok(PlutusData::Int(value)),
+			(MAJOR_BYTES, Some(len)) => Ok(PlutusData::Bytes(self.take(len)?.to_vec())),
+			(MAJOR_BYTES, None) => {
+				let mut bytes = Vec::new();
+				while !self.at_break()? {
+					match self.head()? {
+						(MAJOR_BYTES, Some(len)) => bytes.extend_from_slice(self.take(len)?),
+						_ => return Err(self.error("invalid byte string chunk")),
+					}
+				}
+				Ok(PlutusData::Bytes(bytes))
+			},
+			(MAJOR_ARRAY, len) => Ok(PlutusData::List(self.items(len)?)),
+			(MAJOR_TAG, Some(tag))
+				if (CONSTR_TAG_BASE..=CONSTR_TAG_BASE + MAX_COMPACT_CONSTR).contains(&tag) =>
+			{
+				match self.head()? {
+					(MAJOR_ARRAY, len) => {
+						Ok(PlutusData::Constr(tag - CONSTR_TAG_BASE, self.items(len)?))
+					},
+					_ => Err(self.error("constructor fields are not an array")),
+				}
+			},
+			_ => Err(self.error(&format!("unsupported item with major type {major}"))),
+		}
+	}
+
+	fn items(&mut self, len: Option<u64>) -> Result<Vec<PlutusData>, Error> {
+		if self.depth == MAX_DEPTH {
+			return Err(self.error(&format!("nesting deeper than {MAX_DEPTH} levels")));
+		}
+		self.depth += 1;
+		let items = self.items_at_depth(len);
+		self.depth -= 1;
+		items
+	}
+
+	fn items_at_depth(&mut self, len: Option<u64>) -> Result<Vec<PlutusData>, Error> {
+		let mut items = Vec::new();
+		match len {
+			Some(len) => {
+				for _ in 0..len {
+					items.push(self.data()?);
+				}
+			},
+			None => {
+				while !self.at_break()? {
+					items.push(self.data()?);
+				}
+			},
+		}
+		Ok(items)
+	}
+
+	/// Reads an item head, returning its major type and argument (`None` if indefinite)
+	fn head(&mut self) -> Result<(u8, Option<u64>), Error> {
+		let initial = self.take(1)?[0];
+		let major = initial >> 5;
+		let value = match initial & 0x1f {
+			info @ 0..=23 => Some(info as u64),
+			24 => Some(self.take(1)?[0] as u64),
+			25 => Some(u16::from_be_bytes(self.take(2)?.try_into().expect("2 bytes")) as u64),
+			26 => Some(u32::from_be_bytes(self.take(4)?.try_into().expect("4 bytes")) as u64),
+			27 => Some(u64::from_be_bytes(self.take(8)?.try_into().expect("8 bytes"))),
+			INDEFINITE if matches!(major, MAJOR_BYTES | MAJOR_ARRAY) => None,
+			_ => return Err(self.error("invalid additional information")),
+		};
+		Ok((major, value))
+	}
+
+	/// Consumes a break byte if it is next
+	fn at_break(&mut self) -> Result<bool, Error> {
+		match self.data.get(self.pos) {
+			Some(&BREAK) => {
+				self.pos += 1;
+				Ok(true)
+			},
+			Some(_) => Ok(false),
+			None => Err(self.error("unexpected end of input")),
+		}
+	}
+
+	fn take(&mut self, len: u64) -> Result<&[u8], Error> {
+		let start = self.pos;
+		let end = usize::try_from(len)
+			.ok()
+			.and_then(|len| start.checked_add(len))
+			.filter(|end| *end <= self.data.len())
+			.ok_or_else(|| self.error("unexpected end of input"))?;
+		self.pos = end;
+		Ok(&self.data[start..end])
+	}
+
+	fn error(&self, msg: &str) -> Error {
+		Error::CborDecodeError(format!("{msg} at offset {}", self.pos))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::PlutusData;
+
+	#[test]
+	fn test_long_bytes_are_chunked() {
+		let data = PlutusData::Bytes(vec![7; 100]);
+		let cbor = data.to_cbor();
+		assert_eq!(&cbor[..2], &[0x5f, 0x58]);
+		assert_eq!(cbor.last(), Some(&0xff));
+		assert_eq!(PlutusData::from_cbor(&cbor).unwrap(), data);
+	}
+
+	#[test]
+	fn test_nesting_is_limited() {
+		let nested = |depth| {
+			let mut data = PlutusData::Int(1);
+			for _ in 0..depth {
+				data = PlutusData::List(vec![data]);
+			}
+			data.to_cbor()
+		};
+		assert!(PlutusData::from_cbor(&nested(16)).is_ok());
+		assert!(PlutusData::from_cbor(&nested(17)).is_err());
+
+		// Indefinite length arrays that never end must not overflow the stack
+		let cbor = vec![0x9f; 100_000];
+		assert!(PlutusData::from_cbor(&cbor).is_err());
+	}
+
+	#[test]
+	fn test_sample_commitment_roundtrip() {
+		let hex_data = std::fs::read_to_string("commitment.cbor").unwrap();
+		let cbor = hex::decode(hex_data.trim()).unwrap();
+		let data = PlutusData::from_cbor(&cbor).unwrap();
+		assert_eq!(data.to_cbor(), cbor);
+	}
+}
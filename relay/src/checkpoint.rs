@@ -0,0 +1,111 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Trusted starting point of the relay's light client.
+
+use std::{fs, path::Path};
+
+use midnight_primitives_beefy_light_client::{AuthoritySet, LightClient};
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// BEEFY validator sets at a block, obtained from a trusted source such as the chain spec or a
+/// node operated by the bridge
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checkpoint {
+	pub block_number: u32,
+	pub current_set: CheckpointSet,
+	pub next_set: CheckpointSet,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckpointSet {
+	pub id: u64,
+	pub len: u32,
+	/// Hex-encoded keccak-256 binary merkle root of the validator public keys
+	pub keyset_commitment: String,
+}
+
+impl Checkpoint {
+	pub fn read(path: &Path) -> Result<Self, Error> {
+		let json =
+			fs::read_to_string(path).map_err(|e| Error::IoError(path.display().to_string(), e))?;
+		serde_json::from_str(&json)
+			.map_err(|e| Error::InvalidCheckpoint(format!("{}: {e}", path.display())))
+	}
+
+	/// A light client trusting the validator sets of the checkpoint
+	pub fn light_client(&self) -> Result<LightClient, Error> {
+		Ok(LightClient::new(
+			self.block_number,
+			self.current_set.authority_set()?,
+			self.next_set.authority_set()?,
+		))
+	}
+}
+
+impl CheckpointSet {
+	fn authority_set(&self) -> Result<AuthoritySet, Error> {
+		let keyset_commitment = hex::decode(self.keyset_commitment.trim_start_matches("0x"))
+			.ok()
+			.and_then(|bytes| bytes.try_into().ok())
+			.ok_or_else(|| {
+				Error::InvalidCheckpoint(format!(
+					"invalid keyset commitment {}",
+					self.keyset_commitment
+				))
+			})?;
+		Ok(AuthoritySet { id: self.id, len: self.len, keyset_commitment })
+	}
+}
+
+impl From<&AuthoritySet> for CheckpointSet {
+	fn from(set: &AuthoritySet) -> Self {
+		Self {
+			id: set.id,
+			len: set.len,
+			keyset_commitment: format!("0x{}", hex::encode(set.keyset_commitment)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_checkpoint_light_client() {
+		let json = r#"{
+			"block_number": 200,
+			"current_set": { "id": 1, "len": 9, "keyset_commitment": "0x0202020202020202020202020202020202020202020202020202020202020202" },
+			"next_set": { "id": 2, "len": 9, "keyset_commitment": "0x0303030303030303030303030303030303030303030303030303030303030303" }
+		}"#;
+		let checkpoint: Checkpoint = serde_json::from_str(json).unwrap();
+		let light_client = checkpoint.light_client().unwrap();
+
+		assert_eq!(light_client.latest_block(), 200);
+		assert_eq!(
+			light_client.current_set(),
+			&AuthoritySet { id: 1, len: 9, keyset_commitment: [2; 32] }
+		);
+		assert_eq!(light_client.next_set().keyset_commitment, [3; 32]);
+		assert_eq!(CheckpointSet::from(light_client.next_set()), checkpoint.next_set);
+	}
+
+	#[test]
+	fn test_checkpoint_rejects_short_commitment() {
+		let set = CheckpointSet { id: 0, len: 1, keyset_commitment: "0x0102".to_string() };
+		assert!(matches!(set.authority_set(), Err(Error::InvalidCheckpoint(_))));
+	}
+}
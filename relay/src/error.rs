@@ -6,4 +6,26 @@ pub enum Error {
 
 	#[error("Failed to parse {0}")]
 	JsonDecodeError(String),
+
+	// --------- Relay errors ---------
+	#[error("RPC error: {0}")]
+	RpcError(#[from] subxt::ext::subxt_rpcs::Error),
+
+	#[error("Failed to decode SCALE data: {0}")]
+	CodecError(#[from] parity_scale_codec::Error),
+
+	#[error("Block {0} not found")]
+	BlockNotFound(u32),
+
+	#[error("Verification failed: {0}")]
+	VerificationFailed(String),
+
+	#[error("Invalid checkpoint: {0}")]
+	InvalidCheckpoint(String),
+
+	#[error("Failed to decode CBOR: {0}")]
+	CborDecodeError(String),
+
+	#[error("I/O error on {0}: {1}")]
+	IoError(String, std::io::Error),
 }
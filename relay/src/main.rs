@@ -1,8 +1,18 @@
 mod beefy_keys;
+mod cbor;
+mod checkpoint;
 mod error;
+mod message;
+mod relay;
+mod sink;
 
+use std::path::PathBuf;
+
+use checkpoint::Checkpoint;
 use clap::Parser;
 pub use error::Error;
+use relay::Relay;
+use sink::FileSink;
 
 /// BEEFY Relayer CLI
 #[derive(Parser, Debug)]
@@ -15,18 +25,53 @@ struct Cli {
 	/// File path of the beefy keys
 	#[arg(short, long)]
 	keys_path: Option<String>,
+
+	/// Relay BEEFY finality proofs, writing each one as CBOR to this directory
+	#[arg(short, long, requires = "checkpoint")]
+	output_dir: Option<PathBuf>,
+
+	/// JSON file with the BEEFY validator sets at a block, from a trusted source. Finality
+	/// proofs are verified from these sets on, not from the sets reported by the node.
+	#[arg(long, value_name = "FILE")]
+	checkpoint: Option<PathBuf>,
+
+	/// Print the checkpoint at this block, as reported by the node. Only use it with a node you
+	/// trust.
+	#[arg(long, value_name = "BLOCK_NUMBER", conflicts_with = "output_dir")]
+	export_checkpoint: Option<u32>,
+
+	/// Decode a relay message written to the output directory, and print it
+	#[arg(long, conflicts_with = "output_dir")]
+	decode: Option<PathBuf>,
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
 	let cli = Cli::parse();
 
+	env_logger::init_from_env(env_logger::Env::default().default_filter_or("info"));
+
 	// reading beefy keys from the given file path, and inserting to the chain
 	if let Some(keys_path) = &cli.keys_path
 		&& let Err(e) = beefy_keys::read_and_insert_to_chain(keys_path).await
 	{
-		println!("{e}");
+		log::error!("{e}");
 	};
 
+	if let Some(path) = &cli.decode {
+		println!("{:#?}", sink::read_message(path)?);
+	}
+
+	if let Some(block_number) = cli.export_checkpoint {
+		let checkpoint = relay::checkpoint(&cli.node_url, block_number).await?;
+		println!("{}", serde_json::to_string_pretty(&checkpoint)?);
+	}
+
+	if let (Some(output_dir), Some(checkpoint)) = (cli.output_dir, &cli.checkpoint) {
+		let mut sink = FileSink::new(output_dir)?;
+		let light_client = Checkpoint::read(checkpoint)?.light_client()?;
+		Relay::connect(&cli.node_url, light_client).await?.run(&mut sink).await?;
+	}
+
 	Ok(())
 }
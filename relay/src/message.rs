@@ -0,0 +1,238 @@
+//! Relay messages submitted to the Cardano bridge contract.
+//!
+//! Each message is a [`FinalityProof`] of the light client, encoded as Plutus data. Its layout is
+//! documented in the README. The commitment and MMR leaf keep the field order of their SCALE
+//! encoding, which the contract needs to hash them.
+
+pub use midnight_primitives_beefy_light_client::FinalityProof as RelayMessage;
+use midnight_primitives_beefy_light_client::{
+	AuthorityProof, AuthoritySet, Commitment, CommitmentSignature, MmrLeaf, MmrLeafProof,
+	SignedCommitment,
+};
+
+use crate::{Error, cbor::PlutusData};
+
+/// Conversion between the records of relay messages and Plutus data
+pub trait PlutusCodec: Sized {
+	fn to_plutus(&self) -> PlutusData;
+
+	fn from_plutus(data: &PlutusData) -> Result<Self, Error>;
+}
+
+impl PlutusCodec for RelayMessage {
+	fn to_plutus(&self) -> PlutusData {
+		constr(vec![
+			self.signed_commitment.to_plutus(),
+			self.leaf.to_plutus(),
+			self.leaf_proof.to_plutus(),
+			list(self.authority_proofs.iter().map(AuthorityProof::to_plutus)),
+		])
+	}
+
+	fn from_plutus(data: &PlutusData) -> Result<Self, Error> {
+		let [signed_commitment, leaf, leaf_proof, authority_proofs] = fields(data)?;
+		Ok(Self {
+			signed_commitment: SignedCommitment::from_plutus(signed_commitment)?,
+			leaf: MmrLeaf::from_plutus(leaf)?,
+			leaf_proof: MmrLeafProof::from_plutus(leaf_proof)?,
+			authority_proofs: authority_proofs
+				.as_list()?
+				.iter()
+				.map(AuthorityProof::from_plutus)
+				.collect::<Result<_, _>>()?,
+		})
+	}
+}
+
+impl PlutusCodec for SignedCommitment {
+	fn to_plutus(&self) -> PlutusData {
+		let Commitment { payload, block_number, validator_set_id } = &self.commitment;
+		let payload = payload.iter().map(|(id, data)| constr(vec![bytes(id), bytes(data)]));
+		let signatures = self.signatures.iter().map(|s| {
+			constr(vec![
+				bytes(&s.signature),
+				PlutusData::Int(s.validator_index.into()),
+				bytes(&s.public_key),
+			])
+		});
+		constr(vec![
+			constr(vec![
+				list(payload),
+				PlutusData::Int((*block_number).into()),
+				PlutusData::Int(*validator_set_id),
+			]),
+			list(signatures),
+		])
+	}
+
+	fn from_plutus(data: &PlutusData) -> Result<Self, Error> {
+		let [commitment, signatures] = fields(data)?;
+		let [payload, block_number, validator_set_id] = fields(commitment)?;
+		let payload = payload
+			.as_list()?
+			.iter()
+			.map(|item| {
+				let [id, data] = fields(item)?;
+				Ok((fixed(id)?, data.as_bytes()?.to_vec()))
+			})
+			.collect::<Result<_, Error>>()?;
+		let signatures = signatures
+			.as_list()?
+			.iter()
+			.map(|item| {
+				let [signature, validator_index, public_key] = fields(item)?;
+				Ok(CommitmentSignature {
+					signature: fixed(signature)?,
+					validator_index: int(validator_index)?,
+					public_key: fixed(public_key)?,
+				})
+			})
+			.collect::<Result<_, Error>>()?;
+		Ok(Self {
+			commitment: Commitment {
+				payload,
+				block_number: int(block_number)?,
+				validator_set_id: validator_set_id.as_int()?,
+			},
+			signatures,
+		})
+	}
+}
+
+impl PlutusCodec for MmrLeaf {
+	fn to_plutus(&self) -> PlutusData {
+		let (parent_number, parent_hash) = &self.parent_number_and_hash;
+		let AuthoritySet { id, len, keyset_commitment } = &self.next_authority_set;
+		constr(vec![
+			PlutusData::Int(self.version.into()),
+			PlutusData::Int((*parent_number).into()),
+			bytes(parent_hash),
+			constr(vec![
+				PlutusData::Int(*id),
+				PlutusData::Int((*len).into()),
+				bytes(keyset_commitment),
+			]),
+			bytes(&self.leaf_extra),
+		])
+	}
+
+	fn from_plutus(data: &PlutusData) -> Result<Self, Error> {
+		let [version, parent_number, parent_hash, next_authority_set, leaf_extra] = fields(data)?;
+		let [id, len, keyset_commitment] = fields(next_authority_set)?;
+		Ok(Self {
+			version: int(version)?,
+			parent_number_and_hash: (int(parent_number)?, fixed(parent_hash)?),
+			next_authority_set: AuthoritySet {
+				id: id.as_int()?,
+				len: int(len)?,
+				keyset_commitment: fixed(keyset_commitment)?,
+			},
+			leaf_extra: leaf_extra.as_bytes()?.to_vec(),
+		})
+	}
+}
+
+impl PlutusCodec for MmrLeafProof {
+	fn to_plutus(&self) -> PlutusData {
+		constr(vec![
+			PlutusData::Int(self.leaf_index),
+			PlutusData::Int(self.leaf_count),
+			list(self.items.iter().map(|item| bytes(item))),
+		])
+	}
+
+	fn from_plutus(data: &PlutusData) -> Result<Self, Error> {
+		let [leaf_index, leaf_count, items] = fields(data)?;
+		Ok(Self {
+			leaf_index: leaf_index.as_int()?,
+			leaf_count: leaf_count.as_int()?,
+			items: items.as_list()?.iter().map(fixed).collect::<Result<_, _>>()?,
+		})
+	}
+}
+
+impl PlutusCodec for AuthorityProof {
+	fn to_plutus(&self) -> PlutusData {
+		constr(vec![
+			PlutusData::Int(self.validator_index.into()),
+			list(self.proof.iter().map(|item| bytes(item))),
+		])
+	}
+
+	fn from_plutus(data: &PlutusData) -> Result<Self, Error> {
+		let [validator_index, proof] = fields(data)?;
+		Ok(Self {
+			validator_index: int(validator_index)?,
+			proof: proof.as_list()?.iter().map(fixed).collect::<Result<_, _>>()?,
+		})
+	}
+}
+
+fn constr(fields: Vec<PlutusData>) -> PlutusData {
+	PlutusData::Constr(0, fields)
+}
+
+fn list(items: impl Iterator<Item = PlutusData>) -> PlutusData {
+	PlutusData::List(items.collect())
+}
+
+fn bytes(data: &[u8]) -> PlutusData {
+	PlutusData::Bytes(data.to_vec())
+}
+
+fn fields<const N: usize>(data: &PlutusData) -> Result<&[PlutusData; N], Error> {
+	Ok(data.constr_fields(0, N)?.try_into().expect("number of fields is checked"))
+}
+
+fn int<T: TryFrom<u64>>(data: &PlutusData) -> Result<T, Error> {
+	let value = data.as_int()?;
+	T::try_from(value)
+		.map_err(|_| Error::CborDecodeError(format!("integer {value} is out of range")))
+}
+
+fn fixed<const N: usize>(data: &PlutusData) -> Result<[u8; N], Error> {
+	let value = data.as_bytes()?;
+	value
+		.try_into()
+		.map_err(|_| Error::CborDecodeError(format!("expected {N} bytes, found {}", value.len())))
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn sample_signed_commitment() -> SignedCommitment {
+		let hex_data = std::fs::read_to_string("commitment.cbor").unwrap();
+		let data = PlutusData::from_cbor(&hex::decode(hex_data.trim()).unwrap()).unwrap();
+		let [signed_commitment] = fields(&data).unwrap();
+		SignedCommitment::from_plutus(signed_commitment).unwrap()
+	}
+
+	#[test]
+	fn test_sample_signed_commitment() {
+		let signed_commitment = sample_signed_commitment();
+		let commitment = &signed_commitment.commitment;
+		assert_eq!(commitment.block_number, 201);
+		assert_eq!(commitment.validator_set_id, 0);
+		assert!(commitment.mmr_root().is_some());
+		assert_eq!(signed_commitment.signatures.len(), 9);
+	}
+
+	#[test]
+	fn test_relay_message_roundtrip() {
+		let message = RelayMessage {
+			signed_commitment: sample_signed_commitment(),
+			leaf: MmrLeaf {
+				version: 0,
+				parent_number_and_hash: (200, [1; 32]),
+				next_authority_set: AuthoritySet { id: 1, len: 9, keyset_commitment: [2; 32] },
+				leaf_extra: vec![3; 150],
+			},
+			leaf_proof: MmrLeafProof { leaf_index: 199, leaf_count: 200, items: vec![[4; 32]] },
+			authority_proofs: vec![AuthorityProof { validator_index: 0, proof: vec![[5; 32]] }],
+		};
+		let cbor = message.to_plutus().to_cbor();
+		let decoded = RelayMessage::from_plutus(&PlutusData::from_cbor(&cbor).unwrap()).unwrap();
+		assert_eq!(decoded, message);
+	}
+}
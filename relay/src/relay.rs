@@ -0,0 +1,235 @@
+//! Collection of BEEFY finality proofs from a node.
+//!
+//! For every justification received from `beefy_subscribeJustifications`, the relay fetches the
+//! validator set that signed it, the MMR leaf proof of the parent block and the merkle proofs of
+//! the signers. The node is not trusted: the resulting message is imported into a [`LightClient`]
+//! started from a trusted checkpoint, and only handed to the sink if it verifies.
+
+use binary_merkle_tree::merkle_proof;
+use midnight_primitives_beefy_light_client::{
+	AuthorityProof, AuthoritySet, Commitment, CommitmentSignature, LightClient, MmrLeaf,
+	MmrLeafProof, SignedCommitment,
+};
+use parity_scale_codec::{Decode, Encode};
+use serde::Deserialize;
+use sp_consensus_beefy::{
+	ValidatorSet, VersionedFinalityProof,
+	ecdsa_crypto::{AuthorityId, Signature},
+	mmr::BeefyAuthoritySet,
+};
+use sp_core::{ByteArray, Bytes, H256};
+use sp_runtime::traits::Keccak256;
+use subxt::{backend::rpc::RpcClient, ext::subxt_rpcs::rpc_params};
+
+use crate::{
+	Error,
+	checkpoint::{Checkpoint, CheckpointSet},
+	message::RelayMessage,
+	sink::Sink,
+};
+
+/// Response of `mmr_generateProof`
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LeavesProof {
+	leaves: Bytes,
+	proof: Bytes,
+}
+
+/// SCALE layout of `sp_mmr_primitives::LeafProof<H256>`
+#[derive(Decode)]
+struct LeafProof {
+	leaf_indices: Vec<u64>,
+	leaf_count: u64,
+	items: Vec<[u8; 32]>,
+}
+
+pub struct Relay {
+	rpc: RpcClient,
+	light_client: LightClient,
+}
+
+impl Relay {
+	pub async fn connect(node_url: &str, light_client: LightClient) -> Result<Self, Error> {
+		let rpc = RpcClient::from_url(node_url).await?;
+		Ok(Self { rpc, light_client })
+	}
+
+	/// Relays every BEEFY justification produced by the node, until the subscription ends.
+	/// Justifications that can't be verified are logged and skipped.
+	pub async fn run(&mut self, sink: &mut impl Sink) -> Result<(), Error> {
+		let mut justifications = self
+			.rpc
+			.subscribe::<Bytes>(
+				"beefy_subscribeJustifications",
+				rpc_params![],
+				"beefy_unsubscribeJustifications",
+			)
+			.await?;
+
+		log::info!(
+			"Subscribed to BEEFY justifications, verifying from block {}",
+			self.light_client.latest_block()
+		);
+		while let Some(justification) = justifications.next().await {
+			let message = match justification {
+				Ok(justification) => self.relay_message(&justification).await,
+				Err(e) => Err(e.into()),
+			};
+			let message = match message {
+				Ok(message) => message,
+				Err(e) => {
+					log::warn!("Skipping justification: {e}");
+					continue;
+				},
+			};
+			let block_number = message.signed_commitment.commitment.block_number;
+			if let Err(e) = self.light_client.import(&message) {
+				log::warn!("Skipping commitment of block {block_number}: {e}");
+				continue;
+			}
+			sink.submit(&message)?;
+			log::info!(
+				"Relayed commitment of block {block_number} with {} signature(s)",
+				message.signed_commitment.signatures.len()
+			);
+		}
+
+		Ok(())
+	}
+
+	/// Builds the relay message of a SCALE-encoded justification, with data from the node. The
+	/// message is not verified yet.
+	async fn relay_message(&self, justification: &[u8]) -> Result<RelayMessage, Error> {
+		let VersionedFinalityProof::V1(signed) =
+			VersionedFinalityProof::<u32, Signature>::decode(&mut &justification[..])?;
+		let commitment = Commitment::decode(&mut &signed.commitment.encode()[..])?;
+		let block_number = commitment.block_number;
+
+		let (at, validator_set) =
+			self.validator_set(block_number, commitment.validator_set_id).await?;
+		let validators = validator_set.validators();
+		if validators.len() != signed.signatures.len() {
+			return Err(Error::VerificationFailed(format!(
+				"{} signature slot(s) for {} validator(s)",
+				signed.signatures.len(),
+				validators.len()
+			)));
+		}
+
+		let leaves: Vec<Vec<u8>> = validators.iter().map(|id| id.to_raw_vec()).collect();
+		let mut signatures = Vec::new();
+		let mut authority_proofs = Vec::new();
+		for (index, signature) in signed.signatures.iter().enumerate() {
+			let Some(signature) = signature else { continue };
+			let merkle = merkle_proof::<Keccak256, _, _>(leaves.clone(), index as u32);
+			signatures.push(CommitmentSignature {
+				signature: signature.as_ref()[..64].try_into().expect("65 byte signature"),
+				validator_index: index as u32,
+				public_key: leaves[index][..].try_into().expect("33 byte public key"),
+			});
+			authority_proofs.push(AuthorityProof {
+				validator_index: index as u32,
+				proof: merkle.proof.iter().map(|item| item.0).collect(),
+			});
+		}
+
+		let (leaf, leaf_proof) = self.leaf_proof(block_number, at).await?;
+		Ok(RelayMessage {
+			signed_commitment: SignedCommitment { commitment, signatures },
+			leaf,
+			leaf_proof,
+			authority_proofs,
+		})
+	}
+
+	/// Validator set with id `set_id`, at the commitment block or its parent
+	///
+	/// The commitment of the block enacting a new validator set is signed by the previous set.
+	async fn validator_set(
+		&self,
+		block_number: u32,
+		set_id: u64,
+	) -> Result<(H256, ValidatorSet<AuthorityId>), Error> {
+		for number in [block_number, block_number.saturating_sub(1)] {
+			let at = block_hash(&self.rpc, number).await?;
+			let validator_set: Option<ValidatorSet<AuthorityId>> =
+				state_call(&self.rpc, "BeefyApi_validator_set", at).await?;
+			let Some(validator_set) = validator_set else { continue };
+			if validator_set.id() == set_id {
+				return Ok((at, validator_set));
+			}
+		}
+
+		Err(Error::VerificationFailed(format!(
+			"validator set {set_id} is not active at block {block_number}"
+		)))
+	}
+
+	/// MMR leaf of the parent of `block_number`, proven against the MMR root at that block
+	async fn leaf_proof(
+		&self,
+		block_number: u32,
+		at: H256,
+	) -> Result<(MmrLeaf, MmrLeafProof), Error> {
+		let parent_number = block_number
+			.checked_sub(1)
+			.ok_or_else(|| Error::VerificationFailed("no MMR leaf for block 0".to_string()))?;
+		let response: LeavesProof = self
+			.rpc
+			.request(
+				"mmr_generateProof",
+				rpc_params![vec![parent_number], Some(block_number), Some(at)],
+			)
+			.await?;
+
+		let leaves = Vec::<Vec<u8>>::decode(&mut &response.leaves[..])?;
+		let [leaf] = &leaves[..] else {
+			return Err(Error::VerificationFailed(format!(
+				"expected 1 MMR leaf, got {}",
+				leaves.len()
+			)));
+		};
+		let leaf = MmrLeaf::decode(&mut &leaf[..])?;
+
+		let proof = LeafProof::decode(&mut &response.proof[..])?;
+		let &[leaf_index] = &proof.leaf_indices[..] else {
+			return Err(Error::VerificationFailed("expected 1 MMR leaf index".to_string()));
+		};
+		let leaf_proof =
+			MmrLeafProof { leaf_index, leaf_count: proof.leaf_count, items: proof.items };
+		Ok((leaf, leaf_proof))
+	}
+}
+
+/// The validator sets at `block_number`, as reported by the node at `node_url`
+pub async fn checkpoint(node_url: &str, block_number: u32) -> Result<Checkpoint, Error> {
+	let rpc = RpcClient::from_url(node_url).await?;
+	let at = block_hash(&rpc, block_number).await?;
+	let current_set = authority_set(&rpc, "BeefyMmrApi_authority_set_proof", at).await?;
+	let next_set = authority_set(&rpc, "BeefyMmrApi_next_authority_set_proof", at).await?;
+	Ok(Checkpoint {
+		block_number,
+		current_set: CheckpointSet::from(&current_set),
+		next_set: CheckpointSet::from(&next_set),
+	})
+}
+
+async fn authority_set(rpc: &RpcClient, method: &str, at: H256) -> Result<AuthoritySet, Error> {
+	let authority_set: BeefyAuthoritySet<H256> = state_call(rpc, method, at).await?;
+	Ok(AuthoritySet {
+		id: authority_set.id,
+		len: authority_set.len,
+		keyset_commitment: authority_set.keyset_commitment.0,
+	})
+}
+
+async fn block_hash(rpc: &RpcClient, number: u32) -> Result<H256, Error> {
+	let hash: Option<H256> = rpc.request("chain_getBlockHash", rpc_params![number]).await?;
+	hash.ok_or(Error::BlockNotFound(number))
+}
+
+async fn state_call<T: Decode>(rpc: &RpcClient, method: &str, at: H256) -> Result<T, Error> {
+	let result: Bytes = rpc.request("state_call", rpc_params![method, Bytes(vec![]), at]).await?;
+	Ok(T::decode(&mut &result[..])?)
+}
@@ -0,0 +1,49 @@
+//! Destinations of relay messages.
+
+use std::{
+	fs,
+	path::{Path, PathBuf},
+};
+
+use crate::{
+	Error,
+	cbor::PlutusData,
+	message::{PlutusCodec, RelayMessage},
+};
+
+/// Receives the verified relay messages, in block order
+pub trait Sink {
+	fn submit(&mut self, message: &RelayMessage) -> Result<(), Error>;
+}
+
+/// Writes each message as hex encoded CBOR to `<dir>/<block_number>.cbor`
+pub struct FileSink {
+	dir: PathBuf,
+}
+
+impl FileSink {
+	pub fn new(dir: impl Into<PathBuf>) -> Result<Self, Error> {
+		let dir = dir.into();
+		fs::create_dir_all(&dir).map_err(|e| Error::IoError(dir.display().to_string(), e))?;
+		Ok(Self { dir })
+	}
+}
+
+impl Sink for FileSink {
+	fn submit(&mut self, message: &RelayMessage) -> Result<(), Error> {
+		let block_number = message.signed_commitment.commitment.block_number;
+		let path = self.dir.join(format!("{block_number}.cbor"));
+		let cbor = message.to_plutus().to_cbor();
+		fs::write(&path, hex::encode(cbor))
+			.map_err(|e| Error::IoError(path.display().to_string(), e))
+	}
+}
+
+/// Reads a message written by [`FileSink`]
+pub fn read_message(path: &Path) -> Result<RelayMessage, Error> {
+	let hex_data =
+		fs::read_to_string(path).map_err(|e| Error::IoError(path.display().to_string(), e))?;
+	let cbor = hex::decode(hex_data.trim())
+		.map_err(|e| Error::CborDecodeError(format!("{}: {e}", path.display())))?;
+	RelayMessage::from_plutus(&PlutusData::from_cbor(&cbor)?)
+}
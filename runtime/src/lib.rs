@@ -119,9 +119,10 @@ mod migrations;
 mod session_manager;
 
 use check_call_filter::CheckCallFilter;
-use constants::time_units::DAYS;
+use constants::time_units::{DAYS, HOURS};
 use pallet_federated_authority::{
-	AuthorityBody, FederatedAuthorityEnsureProportionAtLeast, FederatedAuthorityOriginManager,
+	AuthorityBody, EnactmentDelayByCategory, FederatedAuthorityEnsureProportionAtLeast,
+	FederatedAuthorityOriginManager,
 };
 use runtime_common::governance::{AlwaysNo, MembershipHandler, MembershipObservationHandler};
 
@@ -269,7 +270,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 000_018_001,
+	spec_version: 000_018_002,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 2,
@@ -798,6 +799,7 @@ impl pallet_block_rewards::Config for Runtime {
 impl pallet_midnight::Config for Runtime {
 	type BlockReward = LedgerBlockReward;
 	type SlotDuration = ConstU64<SLOT_DURATION>;
+	type WeightInfo = pallet_midnight::weights::SubstrateWeight<Runtime>;
 }
 
 /// Configure the pallet-midnight in pallets/midnight.
@@ -838,6 +840,10 @@ impl pallet_tx_pause::Config for Runtime {
 }
 
 pub const MOTION_DURATION: BlockNumber = 5 * DAYS;
+/// Time node operators have to react to an approved runtime upgrade before it can be enacted
+pub const RUNTIME_UPGRADE_ENACTMENT_DELAY: BlockNumber = DAYS;
+/// Time authority bodies have to veto any other approved motion before it can be enacted
+pub const PARAMETER_CHANGE_ENACTMENT_DELAY: BlockNumber = 6 * HOURS;
 pub const MAX_PROPOSALS: u32 = 100;
 pub const MAX_MEMBERS: u32 = 10;
 
@@ -912,18 +918,29 @@ impl pallet_membership::Config<TechnicalCommitteeMembershipInstance> for Runtime
 
 pub const MAX_NUM_BODIES: u32 = 2; // TechnicalCommittee + Council
 pub const MAX_MOTIONS_PER_BLOCK: u32 = 10;
+/// Proportion (numerator, denominator) of its members a body needs to approve a motion
+pub const BODY_APPROVAL_PROPORTION: (u32, u32) = (2, 3);
+
+parameter_types! {
+	pub const BodyApprovalProportion: (u32, u32) = BODY_APPROVAL_PROPORTION;
+}
 
 type CouncilApproval = AuthorityBody<
 	Council,
-	pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollectiveInstance, 2, 3>,
+	pallet_collective::EnsureProportionAtLeast<
+		AccountId,
+		CouncilCollectiveInstance,
+		{ BODY_APPROVAL_PROPORTION.0 },
+		{ BODY_APPROVAL_PROPORTION.1 },
+	>,
 >;
 type TechnicalCommitteeApproval = AuthorityBody<
 	TechnicalCommittee,
 	pallet_collective::EnsureProportionAtLeast<
 		AccountId,
 		TechnicalCommitteeCollectiveInstance,
-		2,
-		3,
+		{ BODY_APPROVAL_PROPORTION.0 },
+		{ BODY_APPROVAL_PROPORTION.1 },
 	>,
 >;
 
@@ -941,15 +958,53 @@ type TechnicalCommitteeRevoke = AuthorityBody<
 	>,
 >;
 
+type CouncilVeto = AuthorityBody<
+	Council,
+	pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollectiveInstance, 2, 3>,
+>;
+type TechnicalCommitteeVeto = AuthorityBody<
+	TechnicalCommittee,
+	pallet_collective::EnsureProportionAtLeast<
+		AccountId,
+		TechnicalCommitteeCollectiveInstance,
+		2,
+		3,
+	>,
+>;
+
+/// Calls able to dispatch another call. Motions for them are given the runtime upgrade enactment
+/// delay, since the call they wrap may be a runtime upgrade
+pub struct MotionWrapperCalls;
+impl Contains<RuntimeCall> for MotionWrapperCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Sudo(_)
+				| RuntimeCall::Scheduler(_)
+				| RuntimeCall::Council(_)
+				| RuntimeCall::TechnicalCommittee(_)
+				| RuntimeCall::FederatedAuthority(_)
+		)
+	}
+}
+
 impl pallet_federated_authority::Config for Runtime {
 	type MotionCall = RuntimeCall;
 	type MaxAuthorityBodies = ConstU32<MAX_NUM_BODIES>;
 	type MotionDuration = ConstU32<MOTION_DURATION>;
 	type MotionApprovalProportion = FederatedAuthorityEnsureProportionAtLeast<1, 1>;
+	type BodyApprovalProportion = BodyApprovalProportion;
 	type MotionApprovalOrigin =
 		FederatedAuthorityOriginManager<(CouncilApproval, TechnicalCommitteeApproval)>;
 	type MotionRevokeOrigin =
 		FederatedAuthorityOriginManager<(CouncilRevoke, TechnicalCommitteeRevoke)>;
+	type EnactmentDelay = EnactmentDelayByCategory<
+		Runtime,
+		MotionWrapperCalls,
+		ConstU32<RUNTIME_UPGRADE_ENACTMENT_DELAY>,
+		ConstU32<PARAMETER_CHANGE_ENACTMENT_DELAY>,
+	>;
+	type MotionVetoOrigin = FederatedAuthorityOriginManager<(CouncilVeto, TechnicalCommitteeVeto)>;
 	type WeightInfo = ();
 }
 
@@ -960,6 +1015,7 @@ impl pallet_federated_authority_observation::Config for Runtime {
 		MembershipObservationHandler<Runtime, CouncilMembershipInstance>;
 	type TechnicalCommitteeMembershipHandler =
 		MembershipObservationHandler<Runtime, TechnicalCommitteeMembershipInstance>;
+	type MaxMembershipHistory = ConstU32<100>;
 	type WeightInfo = ();
 }
 
@@ -967,6 +1023,7 @@ pub struct MidnightTokenTransferHandler;
 
 impl pallet_cnight_observation::Config for Runtime {
 	type MidnightSystemTransactionExecutor = MidnightSystem;
+	type WeightInfo = pallet_cnight_observation::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -1139,6 +1196,7 @@ mod benches {
 		[pallet_migrations, MultiBlockMigrations]
 		[pallet_session_validator_management, SessionCommitteeManagement]
 		[pallet_midnight, Midnight]
+		[pallet_cnight_observation, CNightObservation]
 		[pallet_federated_authority, FederatedAuthority]
 		[pallet_federated_authority_observation, FederatedAuthorityObservation]
 	);
@@ -1202,6 +1260,12 @@ impl_runtime_apis! {
 		fn get_zswap_state_root() -> Result<Vec<u8>, LedgerApiError> {
 			Midnight::get_zswap_state_root()
 		}
+		fn get_midnight_transaction(extrinsic: <Block as BlockT>::Extrinsic) -> Option<Vec<u8>> {
+			match extrinsic.function {
+				RuntimeCall::Midnight(MidnightCall::send_mn_transaction { midnight_tx }) => Some(midnight_tx),
+				_ => None,
+			}
+		}
 	}
 
 	impl sp_api::Metadata<Block> for Runtime {
@@ -1607,6 +1671,16 @@ impl_runtime_apis! {
 			pallet_federated_authority_observation::MainChainTechnicalCommitteePolicyId::<Runtime>::get()
 		}
 	}
+
+	impl pallet_federated_authority::FederatedAuthorityApi<Block, Hash, BlockNumber> for Runtime {
+		fn pending_motions() -> Vec<pallet_federated_authority::MotionDetails<Hash, BlockNumber>> {
+			FederatedAuthority::pending_motions()
+		}
+
+		fn motion_status(motion_hash: Hash) -> Option<pallet_federated_authority::MotionDetails<Hash, BlockNumber>> {
+			FederatedAuthority::motion_details(motion_hash)
+		}
+	}
 }
 
 #[cfg(test)]
@@ -0,0 +1,112 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pass/fail reports of pre-flight checks, printed by the node's `doctor` subcommand and the
+//! upgrader's `verify` mode.
+
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+	Pass,
+	Warn,
+	Fail,
+	Skip,
+}
+
+impl Display for Status {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		match self {
+			Status::Pass => write!(f, "PASS"),
+			Status::Warn => write!(f, "WARN"),
+			Status::Fail => write!(f, "FAIL"),
+			Status::Skip => write!(f, "SKIP"),
+		}
+	}
+}
+
+#[derive(Default)]
+pub struct Report {
+	checks: Vec<(Status, String, String)>,
+}
+
+impl Report {
+	pub fn add(&mut self, status: Status, name: impl Into<String>, detail: impl Into<String>) {
+		self.checks.push((status, name.into(), detail.into()));
+	}
+
+	/// Records `result` as passed or failed, returning its value if it passed
+	pub fn check<T, E: Display>(
+		&mut self,
+		name: impl Into<String>,
+		result: Result<T, E>,
+		detail: impl FnOnce(&T) -> String,
+	) -> Option<T> {
+		match result {
+			Ok(value) => {
+				self.add(Status::Pass, name, detail(&value));
+				Some(value)
+			},
+			Err(e) => {
+				self.add(Status::Fail, name, e.to_string());
+				None
+			},
+		}
+	}
+
+	pub fn count(&self, status: Status) -> usize {
+		self.checks.iter().filter(|(s, _, _)| *s == status).count()
+	}
+
+	pub fn print(&self) {
+		print!("{self}");
+	}
+}
+
+impl Display for Report {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		for (status, name, detail) in &self.checks {
+			writeln!(f, "[{status}] {name}: {detail}")?;
+		}
+		writeln!(
+			f,
+			"\n{} passed, {} warnings, {} failed, {} skipped",
+			self.count(Status::Pass),
+			self.count(Status::Warn),
+			self.count(Status::Fail),
+			self.count(Status::Skip),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn report_counts_and_prints_checks() {
+		let mut report = Report::default();
+		report.add(Status::Warn, "a", "careful");
+		assert_eq!(report.check("b", Ok::<_, String>(2), |v| format!("got {v}")), Some(2));
+		assert_eq!(report.check("c", Err::<u32, _>("broken"), |_| unreachable!()), None);
+
+		assert_eq!(report.count(Status::Pass), 1);
+		assert_eq!(report.count(Status::Fail), 1);
+		assert_eq!(report.count(Status::Skip), 0);
+		assert_eq!(
+			report.to_string(),
+			"[WARN] a: careful\n[PASS] b: got 2\n[FAIL] c: broken\n\n\
+			 1 passed, 1 warnings, 1 failed, 0 skipped\n"
+		);
+	}
+}
@@ -0,0 +1,69 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use subxt_signer::SecretUriError;
+use thiserror::Error;
+
+use crate::Body;
+
+#[derive(Error, Debug)]
+pub enum GovernanceError {
+	#[error("Secret URI parse error: {0}")]
+	UriParseFailed(#[from] SecretUriError),
+	#[error("Subxt signer error: {0}")]
+	SubxtSignerError(#[from] subxt_signer::sr25519::Error),
+	#[error("Subxt error: {0}")]
+	SubxtError(#[from] subxt::Error),
+	#[error("BIP error: {0}")]
+	BipError(#[from] bip39::Error),
+	#[error("IO error: {0}")]
+	IoError(std::io::Error),
+	#[error("Hex error: {0}")]
+	HexError(#[from] hex::FromHexError),
+	#[error("Code upgrade failed: Missing code updated event")]
+	CodeUpgradeFailed,
+	#[error("Proposal index not found in events")]
+	ProposalIndexNotFound,
+	#[error("Encoding error: {0}")]
+	EncodingError(String),
+	#[error("{0} has no members")]
+	NoMembers(Body),
+	#[error("{body} signer {account} is not a member")]
+	NotAMember { body: Body, account: String },
+	#[error("{body} needs {required} signers to approve a motion, but {provided} were given")]
+	NotEnoughSigners { body: Body, required: u32, provided: usize },
+	#[error("JSON error: {0}")]
+	JsonError(#[from] serde_json::Error),
+	#[error("Invalid account {0}")]
+	InvalidAccount(String),
+	#[error("{path} must be signed by {expected}, not {found}")]
+	WrongSigner { path: String, expected: String, found: String },
+	#[error("Invalid signature in {0}")]
+	InvalidSignature(String),
+	#[error("{0} is not signed")]
+	Unsigned(String),
+	#[error("Signing payload of {0} changed since it was exported, export the motion again")]
+	PayloadChanged(String),
+	#[error("{path} has nonce {expected}, but the next nonce of its signer is {found}")]
+	NonceGap { path: String, expected: u64, found: u64 },
+	#[error(
+		"{0} was not included, but its nonce was used by another transaction, export the motion again"
+	)]
+	NonceUsed(String),
+	#[error("{0} refers to a proposal that is not open")]
+	ProposalNotOpen(String),
+	#[error(
+		"{pallet} proposal has index {index}, its vote and close extrinsics were updated and must be signed again"
+	)]
+	ProposalIndexChanged { pallet: String, index: u32 },
+}
@@ -0,0 +1,577 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Drives runtime calls through Midnight's federated authority governance.
+//!
+//! A motion is a runtime call executed with Root origin once every body has approved it. Each body
+//! (`Council` and `TechnicalCommittee`) approves it by proposing, voting for and closing a
+//! `FederatedAuthority::motion_approve` proposal in its `pallet_collective` instance. Once all
+//! bodies have approved it and the enactment delay has passed, the motion is closed, and runtime
+//! upgrades are applied with `System::apply_authorized_upgrade`.
+
+use std::{fmt, path::Path, str::FromStr};
+
+use bip39::Mnemonic;
+use error::GovernanceError;
+use subxt::{
+	Metadata, OnlineClient, SubstrateConfig,
+	blocks::ExtrinsicEvents,
+	dynamic::{self, Value},
+	ext::{scale_decode::DecodeAsType, scale_value},
+	tx::{DynamicPayload, Payload},
+	utils::{AccountId32, H256},
+};
+use subxt_signer::SecretUri;
+use subxt_signer::sr25519::Keypair;
+
+pub mod error;
+pub mod offline;
+
+/// Weight bound (`ref_time`, `proof_size`) for executing `motion_approve` when closing a proposal
+const PROPOSAL_WEIGHT_BOUND: (u128, u128) = (10_000_000_000, 65536);
+
+pub fn get_signer(key_str: &str) -> Result<Keypair, GovernanceError> {
+	// Supports seed phrases
+	if key_str.contains('/') {
+		let uri = SecretUri::from_str(key_str)?;
+		Ok(Keypair::from_uri(&uri)?)
+	} else {
+		let phrase = Mnemonic::parse(key_str)?;
+		Ok(Keypair::from_phrase(&phrase, None)?)
+	}
+}
+
+/// Reads a seed phrase or secret URI from a file, e.g. a member's mnemonic file
+pub fn get_signer_from_file(path: &Path) -> Result<Keypair, GovernanceError> {
+	let key_str = std::fs::read_to_string(path).map_err(GovernanceError::IoError)?;
+	get_signer(key_str.trim())
+}
+
+/// The proportion (numerator, denominator) of its members a body needs to approve a motion, read
+/// from the runtime's `FederatedAuthority::BodyApprovalProportion` constant
+pub fn approval_proportion(
+	api: &OnlineClient<SubstrateConfig>,
+) -> Result<(u32, u32), GovernanceError> {
+	let address = dynamic::constant("FederatedAuthority", "BodyApprovalProportion");
+	api.constants()
+		.at(&address)?
+		.as_type()
+		.map_err(|e| GovernanceError::EncodingError(format!("{:?}", e)))
+}
+
+/// The number of a body's `members` that need to vote for a motion to approve it, given the
+/// approval `proportion`
+pub fn approval_threshold(members: u32, (numerator, denominator): (u32, u32)) -> u32 {
+	(members * numerator).div_ceil(denominator)
+}
+
+/// A collective whose approval is required for federated authority motions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Body {
+	Council,
+	TechnicalCommittee,
+}
+
+impl Body {
+	pub fn pallet(self) -> &'static str {
+		match self {
+			Body::Council => "Council",
+			Body::TechnicalCommittee => "TechnicalCommittee",
+		}
+	}
+}
+
+impl fmt::Display for Body {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.pallet())
+	}
+}
+
+/// An account signing governance extrinsics: a keypair, or only its account when the extrinsics
+/// are signed offline
+pub trait GovernanceSigner {
+	fn account_id(&self) -> AccountId32;
+}
+
+impl GovernanceSigner for Keypair {
+	fn account_id(&self) -> AccountId32 {
+		self.public_key().to_account_id()
+	}
+}
+
+impl GovernanceSigner for AccountId32 {
+	fn account_id(&self) -> AccountId32 {
+		self.clone()
+	}
+}
+
+/// Members of a body signing its proposal, votes and close
+pub struct BodySigners<S = Keypair> {
+	pub body: Body,
+	pub signers: Vec<S>,
+}
+
+/// A runtime call to be executed by the federated authority
+pub struct Motion {
+	call: Value,
+	call_data: Vec<u8>,
+	/// Code applied with `System::apply_authorized_upgrade` once the motion is enacted
+	upgrade_code: Option<Vec<u8>>,
+}
+
+impl Motion {
+	/// A motion executing `pallet::call` with `fields`
+	pub fn new(
+		metadata: &Metadata,
+		pallet: &str,
+		call: &str,
+		fields: Vec<Value>,
+	) -> Result<Self, GovernanceError> {
+		let payload = dynamic::tx(pallet, call, fields);
+		let call_data = encode_call_data(&payload, metadata)?;
+		Ok(Self { call: payload.into_value(), call_data, upgrade_code: None })
+	}
+
+	/// A motion executing a SCALE-encoded `RuntimeCall`
+	pub fn from_call_data(
+		metadata: &Metadata,
+		call_data: Vec<u8>,
+	) -> Result<Self, GovernanceError> {
+		let call = decode_call(metadata, &call_data)?;
+		Ok(Self { call, call_data, upgrade_code: None })
+	}
+
+	/// A motion authorizing a runtime upgrade to `code`, which is applied once it is enacted
+	pub fn runtime_upgrade(metadata: &Metadata, code: Vec<u8>) -> Result<Self, GovernanceError> {
+		let code_hash = sp_crypto_hashing::blake2_256(&code);
+		log::info!("Code hash: 0x{}", hex::encode(code_hash));
+		let motion =
+			Self::new(metadata, "System", "authorize_upgrade", vec![Value::from_bytes(code_hash)])?;
+		Ok(Self { upgrade_code: Some(code), ..motion })
+	}
+
+	/// The hash the federated authority identifies the motion by
+	pub fn hash(&self) -> H256 {
+		H256(sp_crypto_hashing::blake2_256(&self.call_data))
+	}
+
+	pub fn call_data(&self) -> &[u8] {
+		&self.call_data
+	}
+
+	fn approval(&self) -> DynamicPayload {
+		dynamic::tx("FederatedAuthority", "motion_approve", vec![self.call.clone()])
+	}
+}
+
+/// An extrinsic submitted while driving a motion through governance
+pub struct PlannedCall {
+	pub signer: AccountId32,
+	pub pallet: &'static str,
+	pub call: &'static str,
+	pub fields: Vec<Value>,
+}
+
+impl PlannedCall {
+	fn new(
+		signer: &impl GovernanceSigner,
+		pallet: &'static str,
+		call: &'static str,
+		fields: Vec<Value>,
+	) -> Self {
+		Self { signer: signer.account_id(), pallet, call, fields }
+	}
+
+	pub fn payload(&self) -> DynamicPayload {
+		dynamic::tx(self.pallet, self.call, self.fields.clone())
+	}
+
+	pub fn call_data(&self, metadata: &Metadata) -> Result<Vec<u8>, GovernanceError> {
+		encode_call_data(&self.payload(), metadata)
+	}
+
+	/// The index of the proposal a `vote` or `close` call refers to
+	pub fn proposal_index(&self) -> Option<u32> {
+		if self.call != "vote" && self.call != "close" {
+			return None;
+		}
+		self.fields.get(PROPOSAL_INDEX_FIELD)?.as_u128().map(|index| index as u32)
+	}
+}
+
+/// Position of the proposal index in the fields of `pallet_collective`'s `vote` and `close`
+const PROPOSAL_INDEX_FIELD: usize = 1;
+
+/// A body's `motion_approve` proposal, identified as in `pallet_collective`
+struct Proposal {
+	call: Value,
+	hash: H256,
+	length: u32,
+}
+
+impl Proposal {
+	fn new(metadata: &Metadata, motion: &Motion) -> Result<Self, GovernanceError> {
+		let approval = motion.approval();
+		let call_data = encode_call_data(&approval, metadata)?;
+		Ok(Self {
+			call: approval.into_value(),
+			hash: H256(sp_crypto_hashing::blake2_256(&call_data)),
+			length: call_data.len() as u32,
+		})
+	}
+
+	fn propose(&self, body: Body, threshold: u32, signer: &impl GovernanceSigner) -> PlannedCall {
+		let fields = vec![
+			Value::u128(threshold as u128),
+			self.call.clone(),
+			Value::u128(self.length as u128),
+		];
+		PlannedCall::new(signer, body.pallet(), "propose", fields)
+	}
+
+	fn vote(&self, body: Body, index: u32, signer: &impl GovernanceSigner) -> PlannedCall {
+		let fields =
+			vec![Value::from_bytes(self.hash.0), Value::u128(index as u128), Value::bool(true)];
+		PlannedCall::new(signer, body.pallet(), "vote", fields)
+	}
+
+	fn close(&self, body: Body, index: u32, signer: &impl GovernanceSigner) -> PlannedCall {
+		let (ref_time, proof_size) = PROPOSAL_WEIGHT_BOUND;
+		let weight_bound = Value::named_composite(vec![
+			("ref_time", Value::u128(ref_time)),
+			("proof_size", Value::u128(proof_size)),
+		]);
+		let fields = vec![
+			Value::from_bytes(self.hash.0),
+			Value::u128(index as u128),
+			weight_bound,
+			Value::u128(self.length as u128),
+		];
+		PlannedCall::new(signer, body.pallet(), "close", fields)
+	}
+}
+
+/// Submits the extrinsics approving, enacting and applying motions
+pub struct Governance<S = Keypair> {
+	api: OnlineClient<SubstrateConfig>,
+	bodies: Vec<BodySigners<S>>,
+	/// Closes motions and applies authorized upgrades, can be any account
+	signer: S,
+}
+
+impl<S: GovernanceSigner> Governance<S> {
+	pub async fn connect(
+		rpc_url: &str,
+		bodies: Vec<BodySigners<S>>,
+		signer: S,
+	) -> Result<Self, GovernanceError> {
+		let api = OnlineClient::<SubstrateConfig>::from_insecure_url(rpc_url).await?;
+		Ok(Self { api, bodies, signer })
+	}
+
+	pub fn metadata(&self) -> Metadata {
+		self.api.metadata()
+	}
+
+	/// Lists the extrinsics `execute` would submit for `motion`, without submitting them.
+	/// Proposal indices are predicted from the current `ProposalCount` of each body.
+	pub async fn plan(&self, motion: &Motion) -> Result<Vec<PlannedCall>, GovernanceError> {
+		let proposal = Proposal::new(&self.metadata(), motion)?;
+		let mut calls = Vec::new();
+		for BodySigners { body, signers } in &self.bodies {
+			let approvers = self.approvers(*body, signers).await?;
+			let threshold = approvers.len() as u32;
+			calls.push(proposal.propose(*body, threshold, &approvers[0]));
+			if threshold < 2 {
+				continue;
+			}
+			let index = fetch::<u32>(&self.api, body.pallet(), "ProposalCount", vec![])
+				.await?
+				.unwrap_or(0);
+			for approver in approvers {
+				calls.push(proposal.vote(*body, index, approver));
+			}
+			calls.push(proposal.close(*body, index, &approvers[0]));
+		}
+		calls.push(self.close_motion(motion));
+		calls.extend(self.apply_upgrade(motion));
+		Ok(calls)
+	}
+
+	fn close_motion(&self, motion: &Motion) -> PlannedCall {
+		let fields = vec![Value::from_bytes(motion.hash().0)];
+		PlannedCall::new(&self.signer, "FederatedAuthority", "motion_close", fields)
+	}
+
+	fn apply_upgrade(&self, motion: &Motion) -> Option<PlannedCall> {
+		let code = motion.upgrade_code.as_ref()?;
+		let fields = vec![Value::from_bytes(code)];
+		Some(PlannedCall::new(&self.signer, "System", "apply_authorized_upgrade", fields))
+	}
+
+	/// The signers of `body` voting for a motion: as many as its approval threshold requires,
+	/// given its current members
+	async fn approvers<'a>(
+		&self,
+		body: Body,
+		signers: &'a [S],
+	) -> Result<&'a [S], GovernanceError> {
+		let members: Vec<AccountId32> =
+			fetch(&self.api, body.pallet(), "Members", vec![]).await?.unwrap_or_default();
+		if members.is_empty() {
+			return Err(GovernanceError::NoMembers(body));
+		}
+		for signer in signers {
+			let account = signer.account_id();
+			if !members.contains(&account) {
+				return Err(GovernanceError::NotAMember { body, account: account.to_string() });
+			}
+		}
+		let required = approval_threshold(members.len() as u32, approval_proportion(&self.api)?);
+		if signers.len() < required as usize {
+			return Err(GovernanceError::NotEnoughSigners {
+				body,
+				required,
+				provided: signers.len(),
+			});
+		}
+		log::info!("{body} needs {required} of its {} members to approve", members.len());
+		Ok(&signers[..required as usize])
+	}
+}
+
+impl Governance<Keypair> {
+	/// Approves `motion` by every body, then closes it once it can be enacted
+	pub async fn execute(&self, motion: &Motion) -> Result<(), GovernanceError> {
+		let proposal = Proposal::new(&self.metadata(), motion)?;
+		for BodySigners { body, signers } in &self.bodies {
+			let approvers = self.approvers(*body, signers).await?;
+			let threshold = approvers.len() as u32;
+
+			log::info!("{body} proposing federated motion approval...");
+			let events = self
+				.submit(&proposal.propose(*body, threshold, &approvers[0]), &approvers[0])
+				.await?;
+			if threshold < 2 {
+				// `pallet_collective` executes proposals with a threshold below 2 directly
+				log::info!("{body} approved the federated motion");
+				continue;
+			}
+			let index = extract_proposal_index(&events, body.pallet())?;
+			log::info!(
+				"{body} proposal created with hash: 0x{} and index: {index}",
+				hex::encode(proposal.hash.0)
+			);
+
+			log::info!("{body} members voting...");
+			for approver in approvers {
+				self.submit(&proposal.vote(*body, index, approver), approver).await?;
+			}
+
+			log::info!("Closing {body} proposal...");
+			self.submit(&proposal.close(*body, index, &approvers[0]), &approvers[0]).await?;
+		}
+		log::info!("Federated authority motion approved by all bodies!");
+
+		let motion_hash = motion.hash();
+		log::info!("Motion hash: 0x{}", hex::encode(motion_hash.0));
+		wait_for_enactment(&self.api, motion_hash).await?;
+
+		// Unless it was already enacted in `on_idle`
+		if motion_exists(&self.api, motion_hash).await? {
+			log::info!("Closing federated motion...");
+			self.submit(&self.close_motion(motion), &self.signer).await?;
+			log::info!("Federated motion closed, call executed with Root origin!");
+		} else {
+			log::info!("Federated motion already enacted, call executed with Root origin!");
+		}
+
+		if let Some(apply) = self.apply_upgrade(motion) {
+			log::info!("Applying authorized upgrade...");
+			let events = self.submit(&apply, &self.signer).await?;
+			check_code_updated(&events)?;
+		}
+		Ok(())
+	}
+
+	async fn submit(
+		&self,
+		call: &PlannedCall,
+		signer: &Keypair,
+	) -> Result<ExtrinsicEvents<SubstrateConfig>, GovernanceError> {
+		Ok(self
+			.api
+			.tx()
+			.sign_and_submit_then_watch_default(&call.payload(), signer)
+			.await?
+			.wait_for_finalized_success()
+			.await?)
+	}
+}
+
+async fn fetch<T: DecodeAsType>(
+	api: &OnlineClient<SubstrateConfig>,
+	pallet: &str,
+	entry: &str,
+	keys: Vec<Value>,
+) -> Result<Option<T>, GovernanceError> {
+	let query = dynamic::storage(pallet, entry, keys);
+	let Some(value) = api.storage().at_latest().await?.fetch(&query).await? else {
+		return Ok(None);
+	};
+	let value = value
+		.as_type()
+		.map_err(|e| GovernanceError::EncodingError(format!("{:?}", e)))?;
+	Ok(Some(value))
+}
+
+/// Waits until the enactment block of an approved motion is finalized.
+/// Runtimes without enactment delays don't have `ScheduledMotions`, so there is nothing to
+/// wait for.
+async fn wait_for_enactment(
+	api: &OnlineClient<SubstrateConfig>,
+	motion_hash: H256,
+) -> Result<(), GovernanceError> {
+	let metadata = api.metadata();
+	let has_enactment_delay = metadata
+		.pallet_by_name("FederatedAuthority")
+		.and_then(|pallet| pallet.storage())
+		.and_then(|storage| storage.entry_by_name("ScheduledMotions"))
+		.is_some();
+	if !has_enactment_delay {
+		return Ok(());
+	}
+
+	let Some(enactment_block) = fetch::<u32>(
+		api,
+		"FederatedAuthority",
+		"ScheduledMotions",
+		vec![Value::from_bytes(motion_hash.0)],
+	)
+	.await?
+	else {
+		return Ok(());
+	};
+
+	log::info!("Federated motion can be enacted from block {enactment_block}, waiting...");
+
+	let mut blocks_sub = api.blocks().subscribe_finalized().await?;
+	while let Some(block) = blocks_sub.next().await {
+		if u64::from(block?.number()) >= u64::from(enactment_block) {
+			break;
+		}
+	}
+
+	Ok(())
+}
+
+async fn motion_exists(
+	api: &OnlineClient<SubstrateConfig>,
+	motion_hash: H256,
+) -> Result<bool, GovernanceError> {
+	let query =
+		dynamic::storage("FederatedAuthority", "Motions", vec![Value::from_bytes(motion_hash.0)]);
+	Ok(api.storage().at_latest().await?.fetch(&query).await?.is_some())
+}
+
+fn encode_call_data(
+	payload: &impl Payload,
+	metadata: &Metadata,
+) -> Result<Vec<u8>, GovernanceError> {
+	payload
+		.encode_call_data(metadata)
+		.map_err(|e| GovernanceError::EncodingError(format!("Failed to encode call: {:?}", e)))
+}
+
+/// Decodes a SCALE-encoded `RuntimeCall`
+fn decode_call(metadata: &Metadata, call_data: &[u8]) -> Result<Value, GovernanceError> {
+	let cursor = &mut &call_data[..];
+	let call = scale_value::scale::decode_as_type(
+		cursor,
+		metadata.outer_enums().call_enum_ty(),
+		metadata.types(),
+	)
+	.map_err(|e| GovernanceError::EncodingError(format!("Failed to decode call: {:?}", e)))?
+	.remove_context();
+	if !cursor.is_empty() {
+		return Err(GovernanceError::EncodingError(format!(
+			"{} trailing bytes after call",
+			cursor.len()
+		)));
+	}
+	Ok(call)
+}
+
+fn check_code_updated(events: &ExtrinsicEvents<SubstrateConfig>) -> Result<(), GovernanceError> {
+	for event in events.iter() {
+		let event = event?;
+		if event.pallet_name() == "System" && event.variant_name() == "CodeUpdated" {
+			log::info!("Code update success: {:?}", event);
+			log::info!("Runtime upgrade completed successfully!");
+			return Ok(());
+		}
+	}
+	Err(GovernanceError::CodeUpgradeFailed)
+}
+
+fn extract_proposal_index(
+	events: &ExtrinsicEvents<SubstrateConfig>,
+	pallet: &str,
+) -> Result<u32, GovernanceError> {
+	use parity_scale_codec::Decode;
+
+	for event in events.iter() {
+		let event = event?;
+		if event.pallet_name() == pallet && event.variant_name() == "Proposed" {
+			// Get the raw field bytes
+			let field_bytes = event.field_bytes();
+
+			// Parse the raw bytes manually
+			// The Proposed event has: (account_id: 32 bytes, proposal_index: compact u32, ...)
+			let mut cursor = field_bytes;
+
+			// Skip account_id (32 bytes)
+			if cursor.len() < 32 {
+				continue;
+			}
+			cursor = &cursor[32..];
+
+			// Read proposal_index (compact encoded u32)
+			if let Ok(parity_scale_codec::Compact(index)) =
+				parity_scale_codec::Compact::<u32>::decode(&mut cursor)
+			{
+				return Ok(index);
+			}
+		}
+	}
+	Err(GovernanceError::ProposalIndexNotFound)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn approval_threshold_matches_ensure_proportion_at_least() {
+		for (numerator, denominator) in [(2, 3), (1, 2), (1, 1)] {
+			for members in 1..=20 {
+				let threshold = approval_threshold(members, (numerator, denominator));
+				// `EnsureProportionAtLeast` accepts `yes * denominator >= numerator * members`
+				assert!(threshold * denominator >= numerator * members);
+				assert!((threshold - 1) * denominator < numerator * members);
+			}
+		}
+		assert_eq!(approval_threshold(3, (2, 3)), 2);
+		assert_eq!(approval_threshold(4, (1, 2)), 2);
+	}
+}
@@ -0,0 +1,251 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use clap::{ArgGroup, Args, Parser, Subcommand};
+use governance::error::GovernanceError;
+use governance::offline::{self, OfflineMotion};
+use governance::{
+	Body, BodySigners, Governance, GovernanceSigner, Motion, get_signer, get_signer_from_file,
+};
+use std::path::PathBuf;
+use std::str::FromStr;
+use subxt::{Metadata, utils::AccountId32};
+
+/// Call data longer than this is printed as its hash in plans
+const MAX_PRINTED_CALL_DATA: usize = 1024;
+
+#[derive(Parser)]
+#[command(version, about, long_about = None)]
+struct Cli {
+	/// RPC URL for sending the extrinsics
+	#[arg(short, long, default_value = "ws://localhost:9944", env, global = true)]
+	rpc_url: String,
+
+	#[command(subcommand)]
+	command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+	/// Approve and enact a motion, signing every extrinsic with the given seeds
+	Execute(ExecuteArgs),
+	/// Write the extrinsics of a motion to a directory, to be signed offline by their signers
+	Export(ExportArgs),
+	/// Sign an exported extrinsic
+	Sign(SignArgs),
+	/// Submit the signed extrinsics of an export directory, skipping those already included
+	Submit(DirArgs),
+	/// Show the progress of an exported motion on chain
+	Status(DirArgs),
+}
+
+#[derive(Args)]
+struct ExecuteArgs {
+	/// Seeds of the Council members approving the motion, comma separated
+	#[arg(long, env, value_delimiter = ',', required = true)]
+	council_signers: Vec<String>,
+
+	/// Seeds of the Technical Committee members approving the motion, comma separated
+	#[arg(long, env, value_delimiter = ',', required = true)]
+	technical_committee_signers: Vec<String>,
+
+	/// Seed for closing the motion and applying authorized upgrades (can be any account)
+	#[arg(short, long, env)]
+	signer_key: String,
+
+	/// Print the extrinsics that would be submitted, without submitting them
+	#[arg(long)]
+	plan: bool,
+
+	#[command(subcommand)]
+	motion: MotionCmd,
+}
+
+#[derive(Args)]
+struct ExportArgs {
+	/// SS58 addresses of the Council members approving the motion, comma separated
+	#[arg(long, value_delimiter = ',', required = true)]
+	council_members: Vec<String>,
+
+	/// SS58 addresses of the Technical Committee members approving the motion, comma separated
+	#[arg(long, value_delimiter = ',', required = true)]
+	technical_committee_members: Vec<String>,
+
+	/// SS58 address of the account closing the motion and applying authorized upgrades
+	#[arg(long)]
+	signer: String,
+
+	/// Directory to write the extrinsics to
+	#[arg(long, value_name = "DIR")]
+	out_dir: PathBuf,
+
+	#[command(subcommand)]
+	motion: MotionCmd,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("signing").required(true).args(["key", "key_file", "signature"])))]
+struct SignArgs {
+	/// The exported extrinsic file
+	#[arg(long, value_name = "FILE")]
+	file: PathBuf,
+
+	/// Seed phrase or secret URI of the signer
+	#[arg(long)]
+	key: Option<String>,
+
+	/// File containing the seed phrase or secret URI of the signer
+	#[arg(long, value_name = "FILE")]
+	key_file: Option<PathBuf>,
+
+	/// Hex-encoded sr25519 signature of the signing payload, made e.g. by a hardware wallet
+	#[arg(long)]
+	signature: Option<String>,
+}
+
+#[derive(Args)]
+struct DirArgs {
+	/// Directory the extrinsics were exported to
+	#[arg(long, value_name = "DIR")]
+	dir: PathBuf,
+}
+
+#[derive(Subcommand)]
+enum MotionCmd {
+	/// Authorize a runtime upgrade, and apply it once the motion is enacted
+	Upgrade {
+		/// The path to the new runtime WASM file
+		#[arg(long, value_name = "FILE", env)]
+		runtime_path: PathBuf,
+	},
+	/// Execute a runtime call with Root origin
+	Call {
+		/// The SCALE-encoded `RuntimeCall`, hex encoded
+		#[arg(long)]
+		call_data: String,
+	},
+}
+
+impl MotionCmd {
+	fn motion(self, metadata: &Metadata) -> Result<Motion, GovernanceError> {
+		match self {
+			MotionCmd::Upgrade { runtime_path } => {
+				let code = std::fs::read(&runtime_path).map_err(GovernanceError::IoError)?;
+				log::info!("Loaded new runtime code from path: {}", runtime_path.display());
+				Motion::runtime_upgrade(metadata, code)
+			},
+			MotionCmd::Call { call_data } => {
+				let call_data = hex::decode(call_data.trim_start_matches("0x"))?;
+				Motion::from_call_data(metadata, call_data)
+			},
+		}
+	}
+}
+
+fn body_signers(body: Body, keys: &[String]) -> Result<BodySigners, GovernanceError> {
+	let signers = keys.iter().map(|key| get_signer(key)).collect::<Result<_, _>>()?;
+	Ok(BodySigners { body, signers })
+}
+
+fn body_members(
+	body: Body,
+	addresses: &[String],
+) -> Result<BodySigners<AccountId32>, GovernanceError> {
+	let signers = addresses.iter().map(|address| account(address)).collect::<Result<_, _>>()?;
+	Ok(BodySigners { body, signers })
+}
+
+fn account(address: &str) -> Result<AccountId32, GovernanceError> {
+	AccountId32::from_str(address)
+		.map_err(|e| GovernanceError::InvalidAccount(format!("{address}: {:?}", e)))
+}
+
+async fn execute(rpc_url: &str, args: ExecuteArgs) -> Result<(), GovernanceError> {
+	let bodies = vec![
+		body_signers(Body::Council, &args.council_signers)?,
+		body_signers(Body::TechnicalCommittee, &args.technical_committee_signers)?,
+	];
+	let signer = get_signer(&args.signer_key)?;
+	let governance = Governance::connect(rpc_url, bodies, signer).await?;
+
+	let metadata = governance.metadata();
+	let motion = args.motion.motion(&metadata)?;
+	if !args.plan {
+		return governance.execute(&motion).await;
+	}
+
+	println!("Motion hash: 0x{}", hex::encode(motion.hash().0));
+	for (i, call) in governance.plan(&motion).await?.iter().enumerate() {
+		let call_data = call.call_data(&metadata)?;
+		println!("{}. {}::{} signed by {}", i + 1, call.pallet, call.call, call.signer);
+		if call_data.len() > MAX_PRINTED_CALL_DATA {
+			let hash = sp_crypto_hashing::blake2_256(&call_data);
+			println!("   call data: {} bytes, hash 0x{}", call_data.len(), hex::encode(hash));
+		} else {
+			println!("   call data: 0x{}", hex::encode(call_data));
+		}
+	}
+	Ok(())
+}
+
+async fn export(rpc_url: &str, args: ExportArgs) -> Result<(), GovernanceError> {
+	let bodies = vec![
+		body_members(Body::Council, &args.council_members)?,
+		body_members(Body::TechnicalCommittee, &args.technical_committee_members)?,
+	];
+	let governance = Governance::connect(rpc_url, bodies, account(&args.signer)?).await?;
+
+	let motion = args.motion.motion(&governance.metadata())?;
+	println!("Motion hash: 0x{}", hex::encode(motion.hash().0));
+	for path in governance.export(&motion, &args.out_dir).await? {
+		println!("{}", path.display());
+	}
+	Ok(())
+}
+
+fn sign(args: SignArgs) -> Result<(), GovernanceError> {
+	let keypair = match (args.key, args.key_file, args.signature) {
+		(Some(key), _, _) => get_signer(&key)?,
+		(_, Some(key_file), _) => get_signer_from_file(&key_file)?,
+		(_, _, Some(signature)) => {
+			let signature = hex::decode(signature.trim_start_matches("0x"))?;
+			offline::add_signature(&args.file, &signature)?;
+			println!("Added signature to {}", args.file.display());
+			return Ok(());
+		},
+		(None, None, None) => unreachable!("clap requires one of the signing arguments"),
+	};
+	offline::sign(&args.file, &keypair)?;
+	println!("Signed {} as {}", args.file.display(), keypair.account_id());
+	Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<(), GovernanceError> {
+	let cli = Cli::parse();
+
+	env_logger::init_from_env(env_logger::Env::default().default_filter_or("info"));
+
+	match cli.command {
+		Command::Execute(args) => execute(&cli.rpc_url, args).await,
+		Command::Export(args) => export(&cli.rpc_url, args).await,
+		Command::Sign(args) => sign(args),
+		Command::Submit(args) => OfflineMotion::load(&cli.rpc_url, &args.dir).await?.submit().await,
+		Command::Status(args) => {
+			for line in OfflineMotion::load(&cli.rpc_url, &args.dir).await?.status().await? {
+				println!("{line}");
+			}
+			Ok(())
+		},
+	}
+}
@@ -0,0 +1,826 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Offline signing of governance extrinsics, so members never hand their seeds to a single process.
+//!
+//! `export` writes each extrinsic of a motion's plan to its own JSON file, next to a `motion.json`
+//! manifest and the metadata of the runtime they are built for. Extrinsics are immortal and carry
+//! explicit nonces, so they can be signed at any time before being submitted. `sign` rebuilds the
+//! signing payload from the call data, nonce, genesis hash and runtime versions, and refuses to
+//! sign a file whose payload doesn't match; `add_signature` checks signatures made with any wallet
+//! signing raw sr25519 payloads the same way. `submit` then sends the signed extrinsics in order,
+//! skipping those found in finalized blocks by hash, and `status` reports the progress of the
+//! motion in `pallet_collective` and `pallet_federated_authority`.
+//!
+//! The index of a body's proposal is only known once it is proposed, so `vote` and `close`
+//! extrinsics are exported with the index predicted from `ProposalCount`. If another proposal took
+//! that index, `submit` points them at the actual one, and they must be signed again.
+
+use std::{
+	collections::{HashMap, hash_map::Entry},
+	fs,
+	path::{Path, PathBuf},
+	str::FromStr,
+};
+
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use subxt::{
+	Metadata, OfflineClient, OnlineClient, SubstrateConfig,
+	client::{OfflineClientT, RuntimeVersion},
+	config::DefaultExtrinsicParamsBuilder,
+	dynamic::{self, Value},
+	ext::scale_value::{At, ValueDef},
+	tx::DynamicPayload,
+	utils::{AccountId32, H256, MultiSignature},
+};
+use subxt_signer::sr25519::{self, Keypair};
+
+use crate::{
+	Governance, GovernanceSigner, Motion, PROPOSAL_INDEX_FIELD, Proposal, approval_proportion,
+	approval_threshold, check_code_updated, decode_call, encode_call_data, error::GovernanceError,
+	fetch, motion_exists, wait_for_enactment,
+};
+
+/// Name of the manifest file in an export directory
+pub const MANIFEST_FILE: &str = "motion.json";
+
+/// Name of the file holding the SCALE-encoded metadata the extrinsics are built with
+pub const METADATA_FILE: &str = "metadata.scale";
+
+/// Version of the metadata written to `METADATA_FILE`
+const METADATA_VERSION: u32 = 15;
+
+/// Number of finalized blocks searched for a submitted extrinsic whose block wasn't recorded
+const INCLUSION_SEARCH_DEPTH: usize = 256;
+
+/// The motion the extrinsics of an export directory drive
+#[derive(Serialize, Deserialize)]
+pub struct Manifest {
+	/// Hex-encoded hash of the motion call
+	pub motion_hash: String,
+	/// Hex-encoded hash of the `motion_approve` proposal of each body
+	pub proposal_hash: String,
+	/// Pallets of the bodies approving the motion
+	pub bodies: Vec<String>,
+	/// Hex-encoded genesis hash of the chain the extrinsics are built for
+	pub genesis_hash: String,
+	pub spec_version: u32,
+	pub transaction_version: u32,
+}
+
+impl Manifest {
+	pub fn read(dir: &Path) -> Result<Self, GovernanceError> {
+		let json = fs::read_to_string(dir.join(MANIFEST_FILE)).map_err(GovernanceError::IoError)?;
+		Ok(serde_json::from_str(&json)?)
+	}
+
+	/// A client for the chain and runtime the extrinsics of `dir` are built for, without
+	/// connecting to a node
+	fn offline_client(
+		&self,
+		dir: &Path,
+	) -> Result<OfflineClient<SubstrateConfig>, GovernanceError> {
+		let bytes = fs::read(dir.join(METADATA_FILE)).map_err(GovernanceError::IoError)?;
+		let metadata = Metadata::decode(&mut &bytes[..])
+			.map_err(|e| GovernanceError::EncodingError(format!("Invalid metadata: {e}")))?;
+		let runtime_version = RuntimeVersion {
+			spec_version: self.spec_version,
+			transaction_version: self.transaction_version,
+		};
+		Ok(OfflineClient::new(hash_from_hex(&self.genesis_hash)?, runtime_version, metadata))
+	}
+}
+
+/// An extrinsic of a motion's plan, signed offline by `signer`
+#[derive(Clone, Serialize, Deserialize)]
+pub struct OfflineExtrinsic {
+	/// Position in the plan, extrinsics are submitted in this order
+	pub step: usize,
+	pub pallet: String,
+	pub call: String,
+	/// SS58 address of the account signing the extrinsic
+	pub signer: String,
+	pub nonce: u64,
+	/// Hex-encoded call data
+	pub call_data: String,
+	/// Hex-encoded bytes to sign with sr25519: the signer payload, or its blake2-256 hash if it
+	/// is longer than 256 bytes
+	pub signing_payload: String,
+	/// Hex-encoded sr25519 signature of `signing_payload`
+	pub signature: Option<String>,
+	/// Index of the proposal a `vote` or `close` extrinsic refers to
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub proposal_index: Option<u32>,
+	/// Hex-encoded hash of the signed extrinsic, recorded when it is submitted
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub extrinsic_hash: Option<String>,
+	/// Hex-encoded hash of the finalized block including the extrinsic
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub block_hash: Option<String>,
+}
+
+impl OfflineExtrinsic {
+	pub fn read(path: &Path) -> Result<Self, GovernanceError> {
+		let json = fs::read_to_string(path).map_err(GovernanceError::IoError)?;
+		Ok(serde_json::from_str(&json)?)
+	}
+
+	pub fn write(&self, path: &Path) -> Result<(), GovernanceError> {
+		fs::write(path, serde_json::to_string_pretty(self)?).map_err(GovernanceError::IoError)
+	}
+
+	pub fn account(&self) -> Result<AccountId32, GovernanceError> {
+		AccountId32::from_str(&self.signer)
+			.map_err(|e| GovernanceError::InvalidAccount(format!("{}: {:?}", self.signer, e)))
+	}
+
+	fn is_call(&self, pallet: &str, call: &str) -> bool {
+		self.pallet == pallet && self.call == call
+	}
+
+	fn signature(&self, path: &Path) -> Result<[u8; 64], GovernanceError> {
+		let Some(signature) = &self.signature else {
+			return Err(GovernanceError::Unsigned(path.display().to_string()));
+		};
+		decode_hex(signature)?
+			.try_into()
+			.map_err(|_| GovernanceError::InvalidSignature(path.display().to_string()))
+	}
+
+	/// Checks `signature` was made by `signer` over `signing_payload`
+	fn verify(&self, path: &Path, signature: [u8; 64]) -> Result<(), GovernanceError> {
+		let public_key = sr25519::PublicKey(self.account()?.0);
+		let payload = decode_hex(&self.signing_payload)?;
+		if !sr25519::verify(&sr25519::Signature(signature), payload, &public_key) {
+			return Err(GovernanceError::InvalidSignature(path.display().to_string()));
+		}
+		Ok(())
+	}
+
+	/// Rebuilds the signing payload from the call data and nonce, for the chain and runtime of the
+	/// manifest next to `path`, and checks it is `signing_payload`
+	fn check_signing_payload(&self, path: &Path) -> Result<(), GovernanceError> {
+		let changed = || GovernanceError::PayloadChanged(path.display().to_string());
+		let dir = path.parent().unwrap_or(Path::new("."));
+		let client = Manifest::read(dir)?.offline_client(dir)?;
+		let (pallet, call, fields) = call_parts(&client.metadata(), &decode_hex(&self.call_data)?)?;
+		if pallet != self.pallet || call != self.call {
+			return Err(changed());
+		}
+		let payload = dynamic::tx(pallet, call, fields);
+		if signing_payload(&client, &payload, self.nonce)? != decode_hex(&self.signing_payload)? {
+			return Err(changed());
+		}
+		Ok(())
+	}
+}
+
+/// Signs the extrinsic in `path` with `keypair`, which must be its signer
+pub fn sign(path: &Path, keypair: &Keypair) -> Result<(), GovernanceError> {
+	let mut extrinsic = OfflineExtrinsic::read(path)?;
+	let account = keypair.account_id();
+	if account != extrinsic.account()? {
+		return Err(GovernanceError::WrongSigner {
+			path: path.display().to_string(),
+			expected: extrinsic.signer,
+			found: account.to_string(),
+		});
+	}
+	extrinsic.check_signing_payload(path)?;
+	let signature = keypair.sign(&decode_hex(&extrinsic.signing_payload)?);
+	extrinsic.signature = Some(encode_hex(&signature.0));
+	extrinsic.write(path)
+}
+
+/// Adds a signature made elsewhere, e.g. by a hardware wallet, to the extrinsic in `path`
+pub fn add_signature(path: &Path, signature: &[u8]) -> Result<(), GovernanceError> {
+	let mut extrinsic = OfflineExtrinsic::read(path)?;
+	let signature: [u8; 64] = signature
+		.try_into()
+		.map_err(|_| GovernanceError::InvalidSignature(path.display().to_string()))?;
+	extrinsic.check_signing_payload(path)?;
+	extrinsic.verify(path, signature)?;
+	extrinsic.signature = Some(encode_hex(&signature));
+	extrinsic.write(path)
+}
+
+/// Points the `vote` or `close` extrinsic in `path` at proposal `index`. Its call data and signing
+/// payload change, so its signature is cleared.
+fn set_proposal_index(
+	client: &OfflineClient<SubstrateConfig>,
+	path: &Path,
+	extrinsic: &OfflineExtrinsic,
+	index: u32,
+) -> Result<(), GovernanceError> {
+	let metadata = client.metadata();
+	let (pallet, call, mut fields) = call_parts(&metadata, &decode_hex(&extrinsic.call_data)?)?;
+	let Some(field) = fields.get_mut(PROPOSAL_INDEX_FIELD) else {
+		return Err(GovernanceError::EncodingError(format!(
+			"{pallet}::{call} has no proposal index"
+		)));
+	};
+	*field = Value::u128(index as u128);
+	let payload = dynamic::tx(pallet, call, fields);
+
+	let mut extrinsic = extrinsic.clone();
+	extrinsic.call_data = encode_hex(&encode_call_data(&payload, &metadata)?);
+	extrinsic.signing_payload = encode_hex(&signing_payload(client, &payload, extrinsic.nonce)?);
+	extrinsic.proposal_index = Some(index);
+	extrinsic.signature = None;
+	extrinsic.write(path)
+}
+
+impl<S: GovernanceSigner> Governance<S> {
+	/// Writes the extrinsics of `motion`'s plan to `dir`, to be signed offline by their signers.
+	/// Nonces are assigned from the current nonce of each signer.
+	pub async fn export(
+		&self,
+		motion: &Motion,
+		dir: &Path,
+	) -> Result<Vec<PathBuf>, GovernanceError> {
+		fs::create_dir_all(dir).map_err(GovernanceError::IoError)?;
+		let metadata_bytes = self
+			.api
+			.runtime_api()
+			.at_latest()
+			.await?
+			.call_raw::<Option<Vec<u8>>>(
+				"Metadata_metadata_at_version",
+				Some(&METADATA_VERSION.encode()),
+			)
+			.await?
+			.ok_or_else(|| {
+				GovernanceError::EncodingError(format!(
+					"metadata version {METADATA_VERSION} is not supported by the runtime"
+				))
+			})?;
+		fs::write(dir.join(METADATA_FILE), &metadata_bytes).map_err(GovernanceError::IoError)?;
+
+		let metadata = self.metadata();
+		let runtime_version = self.api.runtime_version();
+		let manifest = Manifest {
+			motion_hash: encode_hex(&motion.hash().0),
+			proposal_hash: encode_hex(&Proposal::new(&metadata, motion)?.hash.0),
+			bodies: self.bodies.iter().map(|body| body.body.pallet().to_string()).collect(),
+			genesis_hash: encode_hex(&self.api.genesis_hash().0),
+			spec_version: runtime_version.spec_version,
+			transaction_version: runtime_version.transaction_version,
+		};
+		fs::write(dir.join(MANIFEST_FILE), serde_json::to_string_pretty(&manifest)?)
+			.map_err(GovernanceError::IoError)?;
+		// Signing payloads are built as `sign` rebuilds them, from the exported metadata
+		let client = manifest.offline_client(dir)?;
+
+		let mut nonces = HashMap::new();
+		let mut paths = Vec::new();
+		for (step, call) in self.plan(motion).await?.into_iter().enumerate() {
+			let nonce = match nonces.entry(call.signer.clone()) {
+				Entry::Occupied(mut entry) => {
+					*entry.get_mut() += 1;
+					*entry.get()
+				},
+				Entry::Vacant(entry) => {
+					*entry.insert(self.api.tx().account_nonce(&call.signer).await?)
+				},
+			};
+			let payload = call.payload();
+			let extrinsic = OfflineExtrinsic {
+				step,
+				pallet: call.pallet.to_string(),
+				call: call.call.to_string(),
+				signer: call.signer.to_string(),
+				nonce,
+				call_data: encode_hex(&call.call_data(&metadata)?),
+				signing_payload: encode_hex(&signing_payload(&client, &payload, nonce)?),
+				signature: None,
+				proposal_index: call.proposal_index(),
+				extrinsic_hash: None,
+				block_hash: None,
+			};
+			let path = dir.join(format!("{:02}-{}-{}.json", step + 1, call.pallet, call.call));
+			extrinsic.write(&path)?;
+			paths.push(path);
+		}
+		Ok(paths)
+	}
+}
+
+/// The extrinsics of an export directory
+pub struct OfflineMotion {
+	api: OnlineClient<SubstrateConfig>,
+	dir: PathBuf,
+	manifest: Manifest,
+	extrinsics: Vec<(PathBuf, OfflineExtrinsic)>,
+}
+
+/// Where a submitted extrinsic stands on chain
+enum Inclusion {
+	/// Its nonce wasn't used yet, the next nonce of its signer is given
+	Pending(u64),
+	/// Included in the given finalized block
+	Included(H256),
+	/// Its nonce was used by another transaction
+	NonceUsed,
+}
+
+impl OfflineMotion {
+	pub async fn load(rpc_url: &str, dir: &Path) -> Result<Self, GovernanceError> {
+		let manifest = Manifest::read(dir)?;
+
+		let mut extrinsics = Vec::new();
+		for entry in fs::read_dir(dir).map_err(GovernanceError::IoError)? {
+			let path = entry.map_err(GovernanceError::IoError)?.path();
+			if path.extension().is_some_and(|ext| ext == "json")
+				&& path.file_name().is_some_and(|name| name != MANIFEST_FILE)
+			{
+				let extrinsic = OfflineExtrinsic::read(&path)?;
+				extrinsics.push((path, extrinsic));
+			}
+		}
+		extrinsics.sort_by_key(|(_, extrinsic)| extrinsic.step);
+
+		let api = OnlineClient::<SubstrateConfig>::from_insecure_url(rpc_url).await?;
+		Ok(Self { api, dir: dir.to_path_buf(), manifest, extrinsics })
+	}
+
+	fn motion_hash(&self) -> Result<H256, GovernanceError> {
+		hash_from_hex(&self.manifest.motion_hash)
+	}
+
+	/// Submits the signed extrinsics in order. Those already included are skipped, so an
+	/// interrupted submission can be resumed.
+	pub async fn submit(&self) -> Result<(), GovernanceError> {
+		let metadata = self.api.metadata();
+		let motion_hash = self.motion_hash()?;
+		for (path, extrinsic) in &self.extrinsics {
+			match self.inclusion(extrinsic).await? {
+				Inclusion::Included(block_hash) => {
+					log::info!("{} was included in block {:?}", path.display(), block_hash);
+					continue;
+				},
+				Inclusion::NonceUsed => {
+					return Err(GovernanceError::NonceUsed(path.display().to_string()));
+				},
+				Inclusion::Pending(next_nonce) if next_nonce < extrinsic.nonce => {
+					return Err(GovernanceError::NonceGap {
+						path: path.display().to_string(),
+						expected: extrinsic.nonce,
+						found: next_nonce,
+					});
+				},
+				Inclusion::Pending(_) => {},
+			}
+			if let Some(exported_index) = extrinsic.proposal_index {
+				let index = self
+					.proposal_index(&extrinsic.pallet)
+					.await?
+					.ok_or_else(|| GovernanceError::ProposalNotOpen(path.display().to_string()))?;
+				if index != exported_index {
+					self.update_proposal_index(&extrinsic.pallet, index)?;
+					return Err(GovernanceError::ProposalIndexChanged {
+						pallet: extrinsic.pallet.clone(),
+						index,
+					});
+				}
+			}
+			let account = extrinsic.account()?;
+			let signature = extrinsic.signature(path)?;
+			extrinsic.verify(path, signature)?;
+
+			let payload = call_payload(&metadata, &decode_hex(&extrinsic.call_data)?)?;
+			let mut partial = self
+				.api
+				.tx()
+				.create_partial_offline(&payload, offline_params(extrinsic.nonce))?;
+			if partial.signer_payload() != decode_hex(&extrinsic.signing_payload)? {
+				return Err(GovernanceError::PayloadChanged(path.display().to_string()));
+			}
+			let tx = partial
+				.sign_with_account_and_signature(&account, &MultiSignature::Sr25519(signature));
+
+			let is_motion_close = extrinsic.is_call("FederatedAuthority", "motion_close");
+			if is_motion_close {
+				wait_for_enactment(&self.api, motion_hash).await?;
+			}
+
+			log::info!(
+				"Submitting {}::{} signed by {}...",
+				extrinsic.pallet,
+				extrinsic.call,
+				account
+			);
+			// Recorded before submitting, so an interrupted submission can still be found
+			let mut submitted = extrinsic.clone();
+			submitted.extrinsic_hash = Some(encode_hex(&tx.hash().0));
+			submitted.write(path)?;
+			let in_block = tx.submit_and_watch().await?.wait_for_finalized().await?;
+			submitted.block_hash = Some(encode_hex(&in_block.block_hash().0));
+			submitted.write(path)?;
+
+			let events = match in_block.wait_for_success().await {
+				Ok(events) => events,
+				Err(e) => {
+					// The motion may have been enacted in `on_idle` already. The failed close
+					// still uses up its nonce, so the following extrinsics of the signer stay valid
+					if is_motion_close && !motion_exists(&self.api, motion_hash).await? {
+						log::info!("Federated motion already enacted: {e}");
+						continue;
+					}
+					return Err(e.into());
+				},
+			};
+			if extrinsic.is_call("System", "apply_authorized_upgrade") {
+				check_code_updated(&events)?;
+			}
+		}
+		log::info!("All extrinsics submitted!");
+		Ok(())
+	}
+
+	/// Finds whether `extrinsic` was included: a used nonce alone doesn't tell, as another
+	/// transaction of the signer may have used it
+	async fn inclusion(&self, extrinsic: &OfflineExtrinsic) -> Result<Inclusion, GovernanceError> {
+		let next_nonce = self.api.tx().account_nonce(&extrinsic.account()?).await?;
+		if next_nonce <= extrinsic.nonce {
+			return Ok(Inclusion::Pending(next_nonce));
+		}
+		let Some(extrinsic_hash) = &extrinsic.extrinsic_hash else {
+			return Ok(Inclusion::NonceUsed);
+		};
+		let extrinsic_hash = hash_from_hex(extrinsic_hash)?;
+		if let Some(block_hash) = &extrinsic.block_hash {
+			let block_hash = hash_from_hex(block_hash)?;
+			let block = self.api.blocks().at(block_hash).await?;
+			if block.extrinsics().await?.iter().any(|ext| ext.hash() == extrinsic_hash) {
+				return Ok(Inclusion::Included(block_hash));
+			}
+		}
+
+		// The submission was interrupted before the block including the extrinsic was recorded
+		let mut block = self.api.blocks().at_latest().await?;
+		for _ in 0..INCLUSION_SEARCH_DEPTH {
+			if block.extrinsics().await?.iter().any(|ext| ext.hash() == extrinsic_hash) {
+				return Ok(Inclusion::Included(block.hash()));
+			}
+			if block.header().number == 0 {
+				break;
+			}
+			block = self.api.blocks().at(block.header().parent_hash).await?;
+		}
+		Ok(Inclusion::NonceUsed)
+	}
+
+	/// The index of the open `motion_approve` proposal of the body `pallet`
+	async fn proposal_index(&self, pallet: &str) -> Result<Option<u32>, GovernanceError> {
+		let proposal_hash = hash_from_hex(&self.manifest.proposal_hash)?;
+		let voting =
+			fetch_value(&self.api, pallet, "Voting", vec![Value::from_bytes(proposal_hash.0)])
+				.await?;
+		Ok(voting
+			.and_then(|votes| votes.at("index").and_then(|v| v.as_u128()))
+			.map(|i| i as u32))
+	}
+
+	/// Points the `vote` and `close` extrinsics of the body `pallet` at proposal `index`
+	fn update_proposal_index(&self, pallet: &str, index: u32) -> Result<(), GovernanceError> {
+		let client = self.manifest.offline_client(&self.dir)?;
+		for (path, extrinsic) in &self.extrinsics {
+			if extrinsic.pallet == pallet
+				&& extrinsic.proposal_index.is_some_and(|exported| exported != index)
+			{
+				set_proposal_index(&client, path, extrinsic, index)?;
+				log::info!("{} now refers to proposal #{index}, sign it again", path.display());
+			}
+		}
+		Ok(())
+	}
+
+	/// Describes the progress of the motion on chain, and of each of its extrinsics
+	pub async fn status(&self) -> Result<Vec<String>, GovernanceError> {
+		let metadata = self.api.metadata();
+		let proposal_hash = hash_from_hex(&self.manifest.proposal_hash)?;
+		let motion_hash = self.motion_hash()?;
+		let mut lines = Vec::new();
+
+		for pallet in &self.manifest.bodies {
+			let proposal_key = vec![Value::from_bytes(proposal_hash.0)];
+			let voting = fetch_value(&self.api, pallet, "Voting", proposal_key).await?;
+			lines.push(match voting {
+				Some(votes) => format!(
+					"{pallet}: proposal #{} has {} of {} ayes needed ({} nays)",
+					votes.at("index").and_then(|v| v.as_u128()).unwrap_or_default(),
+					composite_len(votes.at("ayes")),
+					votes.at("threshold").and_then(|v| v.as_u128()).unwrap_or_default(),
+					composite_len(votes.at("nays")),
+				),
+				None => {
+					let members: Vec<AccountId32> =
+						fetch(&self.api, pallet, "Members", vec![]).await?.unwrap_or_default();
+					format!(
+						"{pallet}: no open proposal, {} of its {} members are needed to approve",
+						approval_threshold(members.len() as u32, approval_proportion(&self.api)?),
+						members.len()
+					)
+				},
+			});
+		}
+
+		let motion_key = vec![Value::from_bytes(motion_hash.0)];
+		match fetch_value(&self.api, "FederatedAuthority", "Motions", motion_key).await? {
+			Some(motion) => {
+				let approvals = motion.at("approvals").map(composite_values).unwrap_or_default();
+				let approved_by = approvals
+					.iter()
+					.map(|index| {
+						metadata
+							.pallets()
+							.find(|pallet| u128::from(pallet.index()) == *index)
+							.map_or_else(|| index.to_string(), |pallet| pallet.name().to_string())
+					})
+					.collect::<Vec<_>>();
+				lines.push(format!(
+					"FederatedAuthority: motion approved by [{}], approval period ends at block {}",
+					approved_by.join(", "),
+					motion.at("ends_block").and_then(|v| v.as_u128()).unwrap_or_default(),
+				));
+				let motion_key = vec![Value::from_bytes(motion_hash.0)];
+				if let Some(enactment_block) =
+					fetch::<u32>(&self.api, "FederatedAuthority", "ScheduledMotions", motion_key)
+						.await?
+				{
+					lines.push(format!(
+						"FederatedAuthority: motion can be enacted from block {enactment_block}"
+					));
+				}
+			},
+			None => lines.push(
+				"FederatedAuthority: no motion, it was not approved by any body yet or was closed"
+					.to_string(),
+			),
+		}
+
+		for (path, extrinsic) in &self.extrinsics {
+			let state = match self.inclusion(extrinsic).await? {
+				Inclusion::Included(block_hash) => format!("included in block {block_hash:?}"),
+				Inclusion::NonceUsed => {
+					"not included, its nonce was used by another transaction".to_string()
+				},
+				Inclusion::Pending(_) if extrinsic.signature.is_some() => "signed".to_string(),
+				Inclusion::Pending(_) => "unsigned".to_string(),
+			};
+			lines.push(format!("{}: {state}", path.display()));
+		}
+		Ok(lines)
+	}
+}
+
+/// Offline extrinsics are immortal, so they can be signed at any time before being submitted
+fn offline_params(
+	nonce: u64,
+) -> <subxt::config::DefaultExtrinsicParams<SubstrateConfig> as subxt::config::ExtrinsicParams<
+	SubstrateConfig,
+>>::Params {
+	DefaultExtrinsicParamsBuilder::<SubstrateConfig>::new()
+		.immortal()
+		.nonce(nonce)
+		.build()
+}
+
+fn signing_payload(
+	client: &impl OfflineClientT<SubstrateConfig>,
+	payload: &DynamicPayload,
+	nonce: u64,
+) -> Result<Vec<u8>, GovernanceError> {
+	Ok(client
+		.tx()
+		.create_partial_offline(payload, offline_params(nonce))?
+		.signer_payload())
+}
+
+/// Rebuilds the payload of a SCALE-encoded `RuntimeCall`
+fn call_payload(metadata: &Metadata, call_data: &[u8]) -> Result<DynamicPayload, GovernanceError> {
+	let (pallet, call, fields) = call_parts(metadata, call_data)?;
+	Ok(dynamic::tx(pallet, call, fields))
+}
+
+/// Splits a SCALE-encoded `RuntimeCall` into its pallet, call and fields
+fn call_parts(
+	metadata: &Metadata,
+	call_data: &[u8],
+) -> Result<(String, String, Vec<Value>), GovernanceError> {
+	let invalid = || GovernanceError::EncodingError("call is not a pallet call".to_string());
+	let ValueDef::Variant(pallet) = decode_call(metadata, call_data)?.value else {
+		return Err(invalid());
+	};
+	let Some(ValueDef::Variant(call)) = pallet.values.into_values().next().map(|call| call.value)
+	else {
+		return Err(invalid());
+	};
+	Ok((pallet.name, call.name, call.values.into_values().collect()))
+}
+
+/// Fetches a storage value without knowing its type
+async fn fetch_value(
+	api: &OnlineClient<SubstrateConfig>,
+	pallet: &str,
+	entry: &str,
+	keys: Vec<Value>,
+) -> Result<Option<Value<u32>>, GovernanceError> {
+	let query = dynamic::storage(pallet, entry, keys);
+	let Some(value) = api.storage().at_latest().await?.fetch(&query).await? else {
+		return Ok(None);
+	};
+	let value = value
+		.to_value()
+		.map_err(|e| GovernanceError::EncodingError(format!("{:?}", e)))?;
+	Ok(Some(value))
+}
+
+fn composite_values(value: &Value<u32>) -> Vec<u128> {
+	match &value.value {
+		ValueDef::Composite(composite) => composite.values().filter_map(Value::as_u128).collect(),
+		_ => Vec::new(),
+	}
+}
+
+fn composite_len(value: Option<&Value<u32>>) -> usize {
+	match value.map(|value| &value.value) {
+		Some(ValueDef::Composite(composite)) => composite.values().count(),
+		_ => 0,
+	}
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+	format!("0x{}", hex::encode(bytes))
+}
+
+fn decode_hex(hex_str: &str) -> Result<Vec<u8>, GovernanceError> {
+	Ok(hex::decode(hex_str.trim_start_matches("0x"))?)
+}
+
+fn hash_from_hex(hex_str: &str) -> Result<H256, GovernanceError> {
+	let bytes: [u8; 32] = decode_hex(hex_str)?
+		.try_into()
+		.map_err(|_| GovernanceError::EncodingError(format!("invalid hash {hex_str}")))?;
+	Ok(H256(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use subxt_signer::sr25519::dev;
+
+	/// Exports a `System::remark` signed by Alice to `dir`, as `export` does
+	fn export_remark(dir: &Path, nonce: u64) -> PathBuf {
+		let manifest = Manifest {
+			motion_hash: encode_hex(&[1; 32]),
+			proposal_hash: encode_hex(&[2; 32]),
+			bodies: vec!["Council".to_string()],
+			genesis_hash: encode_hex(&[3; 32]),
+			spec_version: 18_002,
+			transaction_version: 2,
+		};
+		fs::write(dir.join(MANIFEST_FILE), serde_json::to_string_pretty(&manifest).unwrap())
+			.unwrap();
+		fs::write(dir.join(METADATA_FILE), midnight_node_metadata::MIDNIGHT_METADATA_LATEST)
+			.unwrap();
+		let client = manifest.offline_client(dir).unwrap();
+
+		let payload = dynamic::tx("System", "remark", vec![Value::from_bytes(b"motion")]);
+		let extrinsic = OfflineExtrinsic {
+			step: 0,
+			pallet: "System".to_string(),
+			call: "remark".to_string(),
+			signer: dev::alice().account_id().to_string(),
+			nonce,
+			call_data: encode_hex(&encode_call_data(&payload, &client.metadata()).unwrap()),
+			signing_payload: encode_hex(&signing_payload(&client, &payload, nonce).unwrap()),
+			signature: None,
+			proposal_index: None,
+			extrinsic_hash: None,
+			block_hash: None,
+		};
+		let path = dir.join("01-System-remark.json");
+		extrinsic.write(&path).unwrap();
+		path
+	}
+
+	fn assert_payload_changed(result: Result<(), GovernanceError>) {
+		assert!(matches!(result, Err(GovernanceError::PayloadChanged(_))), "{result:?}");
+	}
+
+	#[test]
+	fn sign_signs_rebuilt_payload() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = export_remark(dir.path(), 5);
+
+		sign(&path, &dev::alice()).unwrap();
+		let extrinsic = OfflineExtrinsic::read(&path).unwrap();
+		let signature = extrinsic.signature(&path).unwrap();
+		extrinsic.verify(&path, signature).unwrap();
+		add_signature(&path, &signature).unwrap();
+	}
+
+	#[test]
+	fn sign_refuses_wrong_signer() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = export_remark(dir.path(), 5);
+		let result = sign(&path, &dev::bob());
+		assert!(matches!(result, Err(GovernanceError::WrongSigner { .. })), "{result:?}");
+	}
+
+	#[test]
+	fn sign_refuses_tampered_call_data() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = export_remark(dir.path(), 5);
+		let mut extrinsic = OfflineExtrinsic::read(&path).unwrap();
+		let metadata = Manifest::read(dir.path())
+			.unwrap()
+			.offline_client(dir.path())
+			.unwrap()
+			.metadata();
+		let payload = dynamic::tx("System", "remark", vec![Value::from_bytes(b"other")]);
+		extrinsic.call_data = encode_hex(&encode_call_data(&payload, &metadata).unwrap());
+		extrinsic.write(&path).unwrap();
+
+		assert_payload_changed(sign(&path, &dev::alice()));
+		assert!(OfflineExtrinsic::read(&path).unwrap().signature.is_none());
+	}
+
+	#[test]
+	fn sign_refuses_tampered_nonce() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = export_remark(dir.path(), 5);
+		let mut extrinsic = OfflineExtrinsic::read(&path).unwrap();
+		extrinsic.nonce = 6;
+		extrinsic.write(&path).unwrap();
+
+		assert_payload_changed(sign(&path, &dev::alice()));
+	}
+
+	#[test]
+	fn sign_refuses_payload_for_another_chain() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = export_remark(dir.path(), 5);
+		let mut manifest = Manifest::read(dir.path()).unwrap();
+		manifest.genesis_hash = encode_hex(&[4; 32]);
+		fs::write(dir.path().join(MANIFEST_FILE), serde_json::to_string(&manifest).unwrap())
+			.unwrap();
+
+		assert_payload_changed(sign(&path, &dev::alice()));
+	}
+
+	#[test]
+	fn add_signature_refuses_tampered_payload() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = export_remark(dir.path(), 5);
+		let mut extrinsic = OfflineExtrinsic::read(&path).unwrap();
+		let other_payload = encode_hex(b"not the signer payload");
+		let signature = dev::alice().sign(&decode_hex(&other_payload).unwrap());
+		extrinsic.signing_payload = other_payload;
+		extrinsic.write(&path).unwrap();
+
+		assert_payload_changed(add_signature(&path, &signature.0));
+	}
+
+	#[test]
+	fn set_proposal_index_rewrites_vote() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = export_remark(dir.path(), 5);
+		let client = Manifest::read(dir.path()).unwrap().offline_client(dir.path()).unwrap();
+		let metadata = client.metadata();
+
+		let vote = |index: u128| {
+			dynamic::tx(
+				"Council",
+				"vote",
+				vec![Value::from_bytes([2; 32]), Value::u128(index), Value::bool(true)],
+			)
+		};
+		let mut extrinsic = OfflineExtrinsic::read(&path).unwrap();
+		extrinsic.pallet = "Council".to_string();
+		extrinsic.call = "vote".to_string();
+		extrinsic.call_data = encode_hex(&encode_call_data(&vote(3), &metadata).unwrap());
+		extrinsic.signing_payload = encode_hex(&signing_payload(&client, &vote(3), 5).unwrap());
+		extrinsic.signature = Some(encode_hex(&[0; 64]));
+		extrinsic.proposal_index = Some(3);
+
+		set_proposal_index(&client, &path, &extrinsic, 4).unwrap();
+		let updated = OfflineExtrinsic::read(&path).unwrap();
+		assert_eq!(updated.proposal_index, Some(4));
+		assert_eq!(updated.signature, None);
+		assert_eq!(updated.call_data, encode_hex(&encode_call_data(&vote(4), &metadata).unwrap()));
+		sign(&path, &dev::alice()).unwrap();
+	}
+}
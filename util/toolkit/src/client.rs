@@ -14,6 +14,7 @@
 use midnight_node_metadata::midnight_metadata_latest as mn_meta;
 use subxt::backend::legacy::rpc_methods::BlockNumber;
 use subxt::config::HashFor;
+use subxt::ext::subxt_rpcs::rpc_params;
 use subxt::utils::{AccountId32, MultiAddress, MultiSignature};
 use subxt::{
 	Config, OnlineClient,
@@ -37,6 +38,7 @@ impl Config for MidnightNodeClientConfig {
 pub struct MidnightNodeClient {
 	pub api: OnlineClient<MidnightNodeClientConfig>,
 	pub rpc: LegacyRpcMethods<MidnightNodeClientConfig>,
+	pub rpc_client: RpcClient,
 }
 
 impl MidnightNodeClient {
@@ -44,7 +46,7 @@ impl MidnightNodeClient {
 		let rpc_client = RpcClient::from_insecure_url(rpc_url).await?;
 		let rpc = LegacyRpcMethods::<MidnightNodeClientConfig>::new(rpc_client.clone());
 		let api = OnlineClient::<MidnightNodeClientConfig>::from_insecure_url(rpc_url).await?;
-		Ok(MidnightNodeClient { rpc, api })
+		Ok(MidnightNodeClient { rpc, api, rpc_client })
 	}
 
 	pub async fn get_network_id(&self) -> Result<String, ClientError> {
@@ -70,6 +72,16 @@ impl MidnightNodeClient {
 		Ok(state_key.map(|bounded| bounded.0))
 	}
 
+	/// Hex encoded, tagged serialization of the state of a contract at the best block. Empty if
+	/// the contract does not exist.
+	pub async fn get_contract_state(&self, untagged_address: &str) -> Result<String, ClientError> {
+		let state = self
+			.rpc_client
+			.request("midnight_contractState", rpc_params![untagged_address])
+			.await?;
+		Ok(state)
+	}
+
 	pub async fn get_block_one_hash(
 		&self,
 	) -> Result<HashFor<MidnightNodeClientConfig>, ClientError> {
@@ -0,0 +1,65 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use clap::Args;
+use midnight_node_ledger_helpers::{
+	ContractAddress, ContractState, DefaultDB, deserialize, persistent_hash, serialize_untagged,
+};
+use midnight_node_toolkit::{cli_parsers as cli, client::MidnightNodeClient};
+use serde::Serialize;
+
+#[derive(Args)]
+pub struct ContractOperationsArgs {
+	/// RPC URL of node instance
+	#[arg(long, short = 'u', default_value = "ws://127.0.0.1:9944")]
+	rpc_url: String,
+	/// Address of the contract (tagged hex)
+	#[arg(long, value_parser = cli::contract_address_decode)]
+	contract_address: ContractAddress,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractOperationInfo {
+	entry_point: String,
+	/// Hash of the operation
+	operation_hash: String,
+	/// Untagged serialization of the operation, holding its versioned verifier key
+	operation: String,
+}
+
+/// Entry points of a deployed contract, read with `midnight_contractState`
+pub async fn execute(
+	args: ContractOperationsArgs,
+) -> Result<Vec<ContractOperationInfo>, Box<dyn std::error::Error + Send + Sync>> {
+	let client = MidnightNodeClient::new(&args.rpc_url).await?;
+	let address = hex::encode(serialize_untagged(&args.contract_address)?);
+	let state = hex::decode(client.get_contract_state(&address).await?)?;
+	if state.is_empty() {
+		return Err(format!("contract {address} does not exist").into());
+	}
+	let state: ContractState<DefaultDB> = deserialize(&mut &state[..])?;
+
+	let mut operations = Vec::new();
+	for entry_point in state.operations.keys() {
+		let operation = state.operations.get(&entry_point).expect("entry point is present");
+		let operation = serialize_untagged(&*operation)?;
+		operations.push(ContractOperationInfo {
+			entry_point: String::from_utf8_lossy(&entry_point.0).to_string(),
+			operation_hash: hex::encode(persistent_hash(&operation).0),
+			operation: hex::encode(operation),
+		});
+	}
+	operations.sort_by(|a, b| a.entry_point.cmp(&b.entry_point));
+	Ok(operations)
+}
@@ -0,0 +1,80 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand};
+use midnight_node_ledger_helpers::ContractAddress;
+use midnight_node_toolkit::{
+	cli_parsers as cli,
+	contract_registry::{ContractManifest, ContractRegistry},
+};
+
+#[derive(Args)]
+pub struct ContractsArgs {
+	#[command(subcommand)]
+	command: ContractsCommand,
+	/// Registry directory. Defaults to `$MIDNIGHT_TOOLKIT_HOME`, or `~/.cache/midnight-toolkit`
+	#[arg(long, global = true)]
+	registry_dir: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+pub enum ContractsCommand {
+	/// Register the keys and zkir of a compiled contract
+	Add(AddArgs),
+	/// List the registered contracts
+	List,
+	/// Remove a registered contract
+	Remove {
+		/// Name or untagged hex address of the contract
+		contract: String,
+	},
+}
+
+#[derive(Args)]
+pub struct AddArgs {
+	/// Name used to refer to the contract
+	#[arg(long)]
+	name: String,
+	/// Address of the deployed contract (untagged hex)
+	#[arg(long, value_parser = cli::hex_ledger_untagged_decode::<ContractAddress>)]
+	address: Option<ContractAddress>,
+	/// Output directory of the contract compiler
+	compiled_contract_dir: PathBuf,
+}
+
+pub enum ContractsResult {
+	Added(ContractManifest),
+	List(Vec<ContractManifest>),
+	Removed(ContractManifest),
+}
+
+pub fn execute(
+	args: ContractsArgs,
+) -> Result<ContractsResult, Box<dyn std::error::Error + Send + Sync>> {
+	let root = args.registry_dir.unwrap_or_else(ContractRegistry::default_root);
+	let registry = ContractRegistry::open(root)?;
+
+	match args.command {
+		ContractsCommand::Add(add) => {
+			let manifest =
+				registry.add(&add.name, add.address.as_ref(), &add.compiled_contract_dir)?;
+			Ok(ContractsResult::Added(manifest))
+		},
+		ContractsCommand::List => Ok(ContractsResult::List(registry.list()?)),
+		ContractsCommand::Remove { contract } => {
+			Ok(ContractsResult::Removed(registry.remove(&contract)?))
+		},
+	}
+}
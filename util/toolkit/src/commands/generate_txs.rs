@@ -35,19 +35,34 @@ pub struct GenerateTxsArgs {
 	/// Dry-run - don't generate any txs, just print out the settings
 	#[arg(long, global = true)]
 	dry_run: bool,
+	/// With `--dry-run` and the `send` builder, have the destination node(s) validate the source
+	/// txs without submitting them, and print their transaction pool priority
+	#[arg(long, global = true, requires = "dry_run")]
+	validate: bool,
 }
 
 pub async fn execute(args: GenerateTxsArgs) -> Result<(), GenerateTxsError> {
+	let validate_only = args.dry_run
+		&& args.validate
+		&& TxGenerator::<SignatureType, ProofType>::validates_on_dry_run(&args.builder);
 	let generator = TxGenerator::<SignatureType, ProofType>::new(
 		args.source,
 		args.destination,
 		args.builder,
 		args.proof_server,
 		args.dry_run,
+		validate_only,
 	)
 	.await?;
 
 	if args.dry_run {
+		if validate_only {
+			// Validating needs readable source txs and a reachable node; failing that, the
+			// dry-run only prints out the settings
+			if let Err(e) = validate_txs(&generator).await {
+				println!("Dry-run: unable to validate txs: {e}");
+			}
+		}
 		return Ok(());
 	}
 
@@ -57,6 +72,15 @@ pub async fn execute(args: GenerateTxsArgs) -> Result<(), GenerateTxsError> {
 	send_txs(&generator, generate_txs(&generator, received_txs).await?).await
 }
 
+async fn validate_txs(
+	generator: &TxGenerator<SignatureType, ProofType>,
+) -> Result<(), GenerateTxsError> {
+	let received_txs =
+		generator.get_txs().await.map_err(|e| GenerateTxsError::GetTransactions(e))?;
+
+	send_txs(generator, generate_txs(generator, received_txs).await?).await
+}
+
 async fn generate_txs(
 	generator: &TxGenerator<SignatureType, ProofType>,
 	received_txs: SourceTransactions<Signature, ProofMarker>,
@@ -118,6 +142,7 @@ mod tests {
 				},
 				proof_server: None,
 				dry_run: false,
+				validate: false,
 			}
 		};
 	}
@@ -202,6 +227,7 @@ mod tests {
 			args.builder,
 			args.proof_server,
 			args.dry_run,
+			false,
 		)
 		.await?;
 		let received_txs =
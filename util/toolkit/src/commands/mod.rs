@@ -12,12 +12,15 @@
 // limitations under the License.
 
 pub mod contract_address;
+pub mod contract_operations;
 pub mod contract_state;
+pub mod contracts;
 pub mod dust_balance;
 pub mod generate_genesis;
 pub mod generate_sample_intent;
 pub mod generate_txs;
 pub mod get_tx_from_context;
+pub mod proof_server;
 pub mod random_address;
 pub mod send_intent;
 pub mod show_address;
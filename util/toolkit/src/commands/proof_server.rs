@@ -0,0 +1,64 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use clap::Args;
+use midnight_node_toolkit::{
+	contract_registry::resolve_artifact_dirs,
+	proof_server::{ProofServer, ProofServerConfig},
+};
+use std::net::SocketAddr;
+use tokio::net::TcpListener;
+
+#[derive(Args)]
+pub struct ProofServerArgs {
+	/// Address to listen on
+	#[arg(long, default_value = "127.0.0.1:6300")]
+	bind: SocketAddr,
+	/// Number of transactions proven concurrently
+	#[arg(long, default_value_t = 2)]
+	workers: usize,
+	/// Number of requests waiting for a worker before new requests are rejected
+	#[arg(long, default_value_t = 64)]
+	queue_size: usize,
+	/// Maximum size of a request body, in bytes
+	#[arg(long, default_value_t = 64 * 1024 * 1024)]
+	max_request_size: usize,
+	/// Compiled contract directories holding the proving keys of contract circuits
+	#[arg(long = "compiled-contract-dir")]
+	compiled_contract_dirs: Vec<String>,
+	/// Contract of the registry whose proving keys are served, by name or address
+	#[arg(long = "contract")]
+	contracts: Vec<String>,
+}
+
+/// Serves `/prove-tx` until interrupted
+pub async fn execute(
+	args: ProofServerArgs,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+	let mut artifact_dirs = args.compiled_contract_dirs;
+	artifact_dirs.extend(resolve_artifact_dirs(&args.contracts)?);
+	let server = ProofServer::new(ProofServerConfig {
+		workers: args.workers,
+		queue_size: args.queue_size,
+		max_request_size: args.max_request_size,
+		artifact_dirs,
+	})?;
+
+	let listener = TcpListener::bind(args.bind).await?;
+	server
+		.serve(listener, async {
+			let _ = tokio::signal::ctrl_c().await;
+		})
+		.await?;
+	Ok(())
+}
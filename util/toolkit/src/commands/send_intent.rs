@@ -34,6 +34,7 @@ pub async fn execute(args: SendIntentArgs) -> Result<(), Box<dyn std::error::Err
 		builder,
 		args.proof_server,
 		args.dry_run,
+		false,
 	)
 	.await?;
 
@@ -111,6 +112,7 @@ mod test {
 			funding_seed: FUNDING_SEED.to_string(),
 			rng_seed: Some(rng_seed),
 			compiled_contract_dirs: vec![compiled_contract_dir.to_string()],
+			contracts: vec![],
 			intent_files: vec![intent_file],
 			utxo_inputs: vec![],
 			zswap_state_file: None,
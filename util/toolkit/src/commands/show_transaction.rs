@@ -1,15 +1,22 @@
-use std::fmt;
+use std::{collections::BTreeMap, fmt, path::PathBuf};
 
 use crate::{
 	DefaultDB, ProofType, SignatureType, Transaction, TransactionWithContext, deserialize,
 };
-use clap::Args;
-use midnight_node_ledger_helpers::PureGeneratorPedersen;
+use clap::{Args, Subcommand};
+use midnight_node_ledger_helpers::{
+	ContractAction, LedgerState, PureGeneratorPedersen, SerdeTransaction, Timestamp, Utxo,
+	UtxoOutput, WellFormedStrictness,
+};
+use serde::Serialize;
+use serde_json::Value;
 
 type InnerReturnType = Result<ShowTransactionResult, Box<dyn std::error::Error + Send + Sync>>;
 
+type Tx = Transaction<SignatureType, ProofType, PureGeneratorPedersen, DefaultDB>;
+
 pub enum TransactionInfo {
-	Transaction(Transaction<SignatureType, ProofType, PureGeneratorPedersen, DefaultDB>),
+	Transaction(Tx),
 	TransactionWithContext(TransactionWithContext<SignatureType, ProofType, DefaultDB>),
 }
 pub struct ShowTransactionResult {
@@ -36,24 +43,102 @@ impl fmt::Display for TransactionInfo {
 }
 
 #[derive(Args)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct ShowTransactionArgs {
+	#[command(subcommand)]
+	command: Option<ShowTransactionCommand>,
 	/// Serialized Transaction
-	#[arg(long, short)]
-	src_file: String,
+	#[arg(long, short, required = true)]
+	src_file: Option<String>,
 	/// Select if the transactions to show is saved as bytes
 	#[arg(long, default_value = "false")]
 	from_bytes: bool,
 	/// Select if the transaction should be shown with context
 	#[arg(long, default_value = "false")]
 	with_context: bool,
+	/// Print a JSON summary of the transaction instead of its debug representation
+	#[arg(long)]
+	json: bool,
+	/// Serialized ledger state (e.g. a genesis state file), used to compute fees
+	#[arg(long)]
+	ledger_state: Option<PathBuf>,
+	/// Check the proofs and signatures of the transaction against the ledger state, at the time
+	/// given by `--verify-at` or else the transaction's block context. Implies `--json`
+	#[arg(long, requires = "ledger_state")]
+	verify: bool,
+	/// Time to verify the transaction at, in seconds since the Unix epoch
+	#[arg(long, value_name = "SECONDS", requires = "verify")]
+	verify_at: Option<u64>,
+}
+
+#[derive(Subcommand)]
+pub enum ShowTransactionCommand {
+	/// Compare the summaries of two transactions field by field
+	Diff(DiffArgs),
+}
+
+#[derive(Args)]
+pub struct DiffArgs {
+	/// First serialized transaction
+	left: String,
+	/// Second serialized transaction
+	right: String,
+	/// Select if the transactions are shown with context
+	#[arg(long, default_value = "false")]
+	with_context: bool,
+}
+
+pub enum ShowTransactionOutput {
+	Debug(ShowTransactionResult),
+	Json(Value),
+}
+
+impl fmt::Display for ShowTransactionOutput {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ShowTransactionOutput::Debug(result) => write!(f, "{result}"),
+			ShowTransactionOutput::Json(json) => {
+				let json = serde_json::to_string_pretty(json).map_err(|_| fmt::Error)?;
+				write!(f, "{json}")
+			},
+		}
+	}
 }
 
-pub fn execute(args: ShowTransactionArgs) -> InnerReturnType {
-	if !args.from_bytes {
-		tx_from_bytes(args.src_file, args.with_context)
+pub fn execute(
+	args: ShowTransactionArgs,
+) -> Result<ShowTransactionOutput, Box<dyn std::error::Error + Send + Sync>> {
+	if let Some(ShowTransactionCommand::Diff(diff)) = args.command {
+		let left = tx_from_file(diff.left, diff.with_context)?.summary(None, None);
+		let right = tx_from_file(diff.right, diff.with_context)?.summary(None, None);
+		let differences = diff_values(&serde_json::to_value(left)?, &serde_json::to_value(right)?);
+		return Ok(ShowTransactionOutput::Json(serde_json::to_value(differences)?));
+	}
+
+	let src_file = args.src_file.expect("src_file is required without a subcommand");
+	let result = if !args.from_bytes {
+		tx_from_bytes(src_file, args.with_context)?
 	} else {
-		tx_from_file(args.src_file, args.with_context)
+		tx_from_file(src_file, args.with_context)?
+	};
+
+	if !args.json && !args.verify {
+		return Ok(ShowTransactionOutput::Debug(result));
 	}
+	let ledger_state: Option<LedgerState<DefaultDB>> = match args.ledger_state {
+		Some(path) => Some(deserialize(std::fs::read(path)?.as_slice())?),
+		None => None,
+	};
+	let verify_at = match args.verify {
+		true => Some(
+			args.verify_at
+				.or(result.block_timestamp())
+				.ok_or("--verify needs --verify-at for a transaction without context")?,
+		),
+		false => None,
+	};
+	let summary = result.summary(ledger_state.as_ref(), verify_at.map(Timestamp::from_secs));
+	Ok(ShowTransactionOutput::Json(serde_json::to_value(summary)?))
 }
 
 fn tx_from_bytes(src_file: String, with_context: bool) -> InnerReturnType {
@@ -80,11 +165,338 @@ fn tx_from_file(src_file: String, with_context: bool) -> InnerReturnType {
 	})
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionSummary {
+	kind: &'static str,
+	hash: Option<String>,
+	size: usize,
+	/// Block timestamp in seconds, for transactions with context
+	block_timestamp: Option<u64>,
+	/// Fees, if a ledger state was supplied
+	fees: Option<u128>,
+	intents: Vec<IntentSummary>,
+	zswap: Option<ZswapSummary>,
+	claim_rewards: Option<ClaimRewardsSummary>,
+	verification: Option<VerificationSummary>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntentSummary {
+	segment: u16,
+	ttl: u64,
+	guaranteed_unshielded_offer: Option<UnshieldedOfferSummary>,
+	fallible_unshielded_offer: Option<UnshieldedOfferSummary>,
+	actions: Vec<ActionSummary>,
+	dust_spends: Vec<DustSpendSummary>,
+	dust_registrations: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnshieldedOfferSummary {
+	inputs: Vec<UtxoSummary>,
+	outputs: Vec<UtxoSummary>,
+	signatures: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UtxoSummary {
+	owner: String,
+	token_type: String,
+	value: u128,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum ActionSummary {
+	#[serde(rename_all = "camelCase")]
+	Call {
+		address: String,
+		entry_point: String,
+	},
+	Deploy {
+		address: String,
+	},
+	Maintain {
+		address: String,
+		updates: usize,
+	},
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustSpendSummary {
+	fee: u128,
+}
+
+/// Number of shielded inputs, outputs and transients
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZswapSummary {
+	guaranteed_inputs: usize,
+	guaranteed_outputs: usize,
+	guaranteed_transients: usize,
+	fallible_inputs: usize,
+	fallible_outputs: usize,
+	fallible_transients: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClaimRewardsSummary {
+	kind: String,
+	value: u128,
+}
+
+/// Result of the well-formedness check, covering proofs, signatures and the binding commitment
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationSummary {
+	valid: bool,
+	error: Option<String>,
+	binding_signature: BindingSignatureSummary,
+}
+
+/// Result of the well-formedness check without proofs, signatures, balancing or limits, which
+/// leaves the binding commitments and the transaction's structure
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingSignatureSummary {
+	valid: bool,
+	error: Option<String>,
+}
+
+/// A field whose value differs between two transactions
+#[derive(Debug, Serialize, PartialEq)]
+pub struct Difference {
+	path: String,
+	left: Option<Value>,
+	right: Option<Value>,
+}
+
+impl ShowTransactionResult {
+	/// Block timestamp in seconds, for transactions with context
+	pub fn block_timestamp(&self) -> Option<u64> {
+		match &self.transaction {
+			TransactionInfo::Transaction(_) => None,
+			TransactionInfo::TransactionWithContext(tx_ctx) => {
+				Some(tx_ctx.block_context.tblock.to_secs())
+			},
+		}
+	}
+
+	/// Summary of the transaction. Fees need a ledger state, and verification a ledger state and
+	/// the time to verify at.
+	pub fn summary(
+		&self,
+		ledger_state: Option<&LedgerState<DefaultDB>>,
+		verify_at: Option<Timestamp>,
+	) -> TransactionSummary {
+		let tx = match &self.transaction {
+			TransactionInfo::Transaction(tx) => Some(tx),
+			TransactionInfo::TransactionWithContext(tx_ctx) => match &tx_ctx.tx {
+				SerdeTransaction::Midnight(tx) => Some(tx),
+				SerdeTransaction::System(_) => None,
+			},
+		};
+
+		let mut summary = TransactionSummary {
+			kind: "system",
+			hash: None,
+			size: self.size,
+			block_timestamp: self.block_timestamp(),
+			fees: None,
+			intents: vec![],
+			zswap: None,
+			claim_rewards: None,
+			verification: None,
+		};
+		let Some(tx) = tx else { return summary };
+
+		summary.hash = Some(hex::encode(tx.transaction_hash().0.0));
+		summary.fees = ledger_state.and_then(|state| tx.fees(&state.parameters, false).ok());
+		match tx {
+			Transaction::Standard(stx) => {
+				summary.kind = "standard";
+				summary.intents = stx
+					.intents
+					.iter()
+					.map(|entry| {
+						let (segment, intent) = (*entry.0, &*entry.1);
+						let [guaranteed_unshielded_offer, fallible_unshielded_offer] = [
+							&intent.guaranteed_unshielded_offer,
+							&intent.fallible_unshielded_offer,
+						]
+						.map(|offer| {
+							offer.as_ref().map(|offer| UnshieldedOfferSummary {
+								inputs: offer
+									.inputs
+									.iter()
+									.map(|input| utxo_summary(&Utxo::from((*input).clone())))
+									.collect(),
+								outputs: offer
+									.outputs
+									.iter()
+									.map(|output| output_summary(&output))
+									.collect(),
+								signatures: offer.signatures.iter().count(),
+							})
+						});
+						IntentSummary {
+							segment,
+							ttl: intent.ttl.to_secs(),
+							guaranteed_unshielded_offer,
+							fallible_unshielded_offer,
+							actions: intent
+								.actions
+								.iter()
+								.map(|action| action_summary(&action))
+								.collect(),
+							dust_spends: intent
+								.dust_actions
+								.as_ref()
+								.map(|dust| {
+									dust.spends
+										.iter()
+										.map(|spend| DustSpendSummary { fee: spend.v_fee })
+										.collect()
+								})
+								.unwrap_or_default(),
+							dust_registrations: intent
+								.dust_actions
+								.as_ref()
+								.map_or(0, |dust| dust.registrations.iter().count()),
+						}
+					})
+					.collect();
+				summary.intents.sort_by_key(|intent| intent.segment);
+				summary.zswap = Some(ZswapSummary {
+					guaranteed_inputs: stx.guaranteed_inputs().count(),
+					guaranteed_outputs: stx.guaranteed_outputs().count(),
+					guaranteed_transients: stx.guaranteed_transients().count(),
+					fallible_inputs: stx.fallible_inputs().count(),
+					fallible_outputs: stx.fallible_outputs().count(),
+					fallible_transients: stx.fallible_transients().count(),
+				});
+			},
+			Transaction::ClaimRewards(claim) => {
+				summary.kind = "claimRewards";
+				summary.claim_rewards = Some(ClaimRewardsSummary {
+					kind: format!("{:?}", claim.kind),
+					value: claim.value,
+				});
+			},
+		}
+
+		if let (Some(ledger_state), Some(verify_at)) = (ledger_state, verify_at) {
+			let result = tx.well_formed(ledger_state, WellFormedStrictness::default(), verify_at);
+
+			let mut binding_only: WellFormedStrictness = Default::default();
+			binding_only.enforce_balancing = false;
+			binding_only.enforce_limits = false;
+			binding_only.verify_native_proofs = false;
+			binding_only.verify_contract_proofs = false;
+			binding_only.verify_signatures = false;
+			let binding_result = tx.well_formed(ledger_state, binding_only, verify_at);
+
+			summary.verification = Some(VerificationSummary {
+				valid: result.is_ok(),
+				error: result.err().map(|e| e.to_string()),
+				binding_signature: BindingSignatureSummary {
+					valid: binding_result.is_ok(),
+					error: binding_result.err().map(|e| e.to_string()),
+				},
+			});
+		}
+
+		summary
+	}
+}
+
+fn utxo_summary(utxo: &Utxo) -> UtxoSummary {
+	UtxoSummary {
+		owner: hex::encode(utxo.owner.0.0),
+		token_type: hex::encode(utxo.type_.0.0),
+		value: utxo.value,
+	}
+}
+
+fn output_summary(output: &UtxoOutput) -> UtxoSummary {
+	UtxoSummary {
+		owner: hex::encode(output.owner.0.0),
+		token_type: hex::encode(output.type_.0.0),
+		value: output.value,
+	}
+}
+
+fn action_summary(action: &ContractAction<ProofType, DefaultDB>) -> ActionSummary {
+	match action {
+		ContractAction::Call(call) => ActionSummary::Call {
+			address: hex::encode(call.address.0.0),
+			entry_point: String::from_utf8_lossy(&call.entry_point.0).to_string(),
+		},
+		ContractAction::Deploy(deploy) => {
+			ActionSummary::Deploy { address: hex::encode(deploy.address().0.0) }
+		},
+		ContractAction::Maintain(update) => ActionSummary::Maintain {
+			address: hex::encode(update.address.0.0),
+			updates: update.updates.iter().count(),
+		},
+	}
+}
+
+/// Differences between two JSON values, with `a.b[0]` style paths
+pub fn diff_values(left: &Value, right: &Value) -> Vec<Difference> {
+	let mut differences = vec![];
+	diff_into(String::new(), Some(left), Some(right), &mut differences);
+	differences
+}
+
+fn diff_into(
+	path: String,
+	left: Option<&Value>,
+	right: Option<&Value>,
+	differences: &mut Vec<Difference>,
+) {
+	match (left, right) {
+		(Some(Value::Object(l)), Some(Value::Object(r))) => {
+			let keys: BTreeMap<&String, ()> = l.keys().chain(r.keys()).map(|k| (k, ())).collect();
+			for key in keys.into_keys() {
+				let path = if path.is_empty() { key.clone() } else { format!("{path}.{key}") };
+				diff_into(path, l.get(key), r.get(key), differences);
+			}
+		},
+		(Some(Value::Array(l)), Some(Value::Array(r))) => {
+			for index in 0..l.len().max(r.len()) {
+				diff_into(format!("{path}[{index}]"), l.get(index), r.get(index), differences);
+			}
+		},
+		(l, r) if l != r => {
+			differences.push(Difference { path, left: l.cloned(), right: r.cloned() })
+		},
+		_ => {},
+	}
+}
+
 #[cfg(test)]
 mod test {
-	use super::{InnerReturnType, TransactionInfo, tx_from_file};
+	use super::{
+		Difference, InnerReturnType, ShowTransactionResult, TransactionInfo, diff_values,
+		tx_from_file,
+	};
+	use crate::{Transaction, deserialize};
+	use midnight_node_ledger_helpers::{SerdeTransaction, Timestamp};
+	use serde_json::json;
 	use test_case::test_case;
 
+	const TX_NO_CONTEXT: &str = "../../res/test-tx-deserialize/serialized_tx_no_context.mn";
+	const TX_WITH_CONTEXT: &str = "../../res/test-tx-deserialize/serialized_tx_with_context.mn";
+	/// Deploy applied right after the undeployed genesis
+	const DEPLOY_TX: &str = "../../res/test-contract/contract_tx_1_deploy_undeployed.mn";
+
 	#[test_case(
 		"../../res/test-tx-deserialize/serialized_tx_no_context.mn",
 		false,
@@ -111,4 +523,96 @@ mod test {
 			_ => assert!(false),
 		}
 	}
+
+	#[test]
+	fn test_summary() {
+		let result = tx_from_file(TX_NO_CONTEXT.to_string(), false).expect("should be ok");
+		let summary = serde_json::to_value(result.summary(None, None)).unwrap();
+		assert_eq!(summary["kind"], "standard");
+		assert!(summary["hash"].is_string());
+		assert!(summary["blockTimestamp"].is_null());
+		assert!(summary["fees"].is_null());
+		assert!(summary["intents"].is_array());
+
+		let result = tx_from_file(TX_WITH_CONTEXT.to_string(), true).expect("should be ok");
+		let summary = serde_json::to_value(result.summary(None, None)).unwrap();
+		assert!(summary["blockTimestamp"].is_u64());
+	}
+
+	/// Moves the TTL of the first intent, which its signatures and binding commitment cover
+	fn tampered(result: ShowTransactionResult) -> ShowTransactionResult {
+		let TransactionInfo::TransactionWithContext(mut tx_ctx) = result.transaction else {
+			panic!("expected a transaction with context")
+		};
+		let SerdeTransaction::Midnight(Transaction::Standard(stx)) = &mut tx_ctx.tx else {
+			panic!("expected a standard transaction")
+		};
+		let (segment, mut intent) =
+			stx.intents.iter().map(|entry| (*entry.0, (*entry.1).clone())).next().unwrap();
+		intent.ttl = Timestamp::from_secs(intent.ttl.to_secs() - 1);
+		stx.intents = stx.intents.insert(segment, intent);
+		ShowTransactionResult {
+			transaction: TransactionInfo::TransactionWithContext(tx_ctx),
+			size: result.size,
+		}
+	}
+
+	#[test]
+	fn test_summary_verification() {
+		let bytes = std::fs::read("../../res/genesis/genesis_state_undeployed.mn").unwrap();
+		let ledger_state = deserialize(bytes.as_slice()).unwrap();
+		let result = tx_from_file(DEPLOY_TX.to_string(), true).expect("should be ok");
+		let verify_at = result.block_timestamp().map(Timestamp::from_secs);
+		assert!(verify_at.is_some());
+
+		let summary = serde_json::to_value(result.summary(Some(&ledger_state), verify_at)).unwrap();
+		assert_eq!(summary["verification"]["valid"], true, "{}", summary["verification"]);
+		assert_eq!(summary["verification"]["bindingSignature"]["valid"], true);
+
+		let summary = tampered(result).summary(Some(&ledger_state), verify_at);
+		let summary = serde_json::to_value(summary).unwrap();
+		assert_eq!(summary["verification"]["valid"], false);
+		assert_eq!(summary["verification"]["bindingSignature"]["valid"], false);
+	}
+
+	#[test]
+	fn test_summary_without_verification_time() {
+		let bytes = std::fs::read("../../res/genesis/genesis_state_undeployed.mn").unwrap();
+		let ledger_state = deserialize(bytes.as_slice()).unwrap();
+		let result = tx_from_file(TX_NO_CONTEXT.to_string(), false).expect("should be ok");
+		let summary = serde_json::to_value(result.summary(Some(&ledger_state), None)).unwrap();
+		assert!(summary["verification"].is_null());
+	}
+
+	#[test]
+	fn test_diff_same_transaction() {
+		let left = tx_from_file(TX_NO_CONTEXT.to_string(), false).unwrap().summary(None, None);
+		let right = tx_from_file(TX_NO_CONTEXT.to_string(), false).unwrap().summary(None, None);
+		let differences = diff_values(
+			&serde_json::to_value(left).unwrap(),
+			&serde_json::to_value(right).unwrap(),
+		);
+		assert!(differences.is_empty());
+	}
+
+	#[test]
+	fn test_diff_values() {
+		let left = json!({"fees": 10, "intents": [{"ttl": 1}], "hash": "aa"});
+		let right = json!({"fees": 12, "intents": [{"ttl": 1}, {"ttl": 2}], "hash": "aa"});
+		assert_eq!(
+			diff_values(&left, &right),
+			vec![
+				Difference {
+					path: "fees".to_string(),
+					left: Some(json!(10)),
+					right: Some(json!(12))
+				},
+				Difference {
+					path: "intents[1]".to_string(),
+					left: None,
+					right: Some(json!({"ttl": 2}))
+				},
+			]
+		);
+	}
 }
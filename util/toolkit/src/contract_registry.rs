@@ -0,0 +1,326 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Local registry of compiled contract artifacts.
+//!
+//! Artifacts are stored once under `blobs/`, named by their persistent hash, and shared between
+//! contracts. Blobs are read-only, since contract directories hard link them. Each registered contract has a manifest listing its files, and a directory with the
+//! `keys/`, `zkir/` and `compiler/` layout of a compiled contract. That directory can be passed to
+//! anything taking a `--compiled-contract-dir`. Public parameters are cached under `params/`.
+
+use std::{
+	collections::BTreeMap,
+	fs,
+	path::{Path, PathBuf},
+};
+
+use midnight_node_ledger_helpers::{ContractAddress, persistent_hash, serialize_untagged};
+use serde::{Deserialize, Serialize};
+
+/// Environment variable overriding the registry location
+pub const REGISTRY_DIR_ENV: &str = "MIDNIGHT_TOOLKIT_HOME";
+/// Environment variable read by the ledger to locate the public parameters
+pub const PARAMS_DIR_ENV: &str = "MIDNIGHT_PP";
+
+const MANIFEST_FILE: &str = "manifest.json";
+const PARAMS_DIR: &str = "params";
+/// Sub-directories of a compiled contract holding the artifacts needed for proving
+const ARTIFACT_DIRS: [&str; 3] = ["keys", "zkir", "compiler"];
+
+#[derive(Debug, thiserror::Error)]
+pub enum RegistryError {
+	#[error("i/o error on {path}: {source}")]
+	Io { path: PathBuf, source: std::io::Error },
+	#[error("invalid manifest {path}: {source}")]
+	InvalidManifest { path: PathBuf, source: serde_json::Error },
+	#[error("invalid contract name {0:?}: use letters, digits, '-', '_' or '.'")]
+	InvalidName(String),
+	#[error("contract {0} is already registered")]
+	AlreadyRegistered(String),
+	#[error("no contract registered with name or address {0}")]
+	NotFound(String),
+	#[error("no keys, zkir or compiler artifacts found in {0}")]
+	NoArtifacts(PathBuf),
+}
+
+/// Registered contract, and the blob hash of each of its artifact files
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractManifest {
+	pub name: String,
+	/// Hex-encoded untagged contract address, once deployed
+	pub address: Option<String>,
+	/// Relative path of each file, e.g. `keys/store.verifier`, to its blob hash
+	pub files: BTreeMap<String, String>,
+}
+
+impl ContractManifest {
+	/// Names of the circuits with a verifier key
+	pub fn circuits(&self) -> Vec<&str> {
+		self.files
+			.keys()
+			.filter_map(|path| path.strip_prefix("keys/")?.strip_suffix(".verifier"))
+			.collect()
+	}
+
+	fn matches(&self, name_or_address: &str) -> bool {
+		let needle = name_or_address.trim_start_matches("0x").to_lowercase();
+		self.name == name_or_address || self.address.as_deref() == Some(needle.as_str())
+	}
+}
+
+pub struct ContractRegistry {
+	root: PathBuf,
+}
+
+impl ContractRegistry {
+	/// `$MIDNIGHT_TOOLKIT_HOME`, or `$HOME/.cache/midnight-toolkit`
+	pub fn default_root() -> PathBuf {
+		if let Some(dir) = std::env::var_os(REGISTRY_DIR_ENV) {
+			return PathBuf::from(dir);
+		}
+		let home = std::env::var_os("HOME").map(PathBuf::from).unwrap_or_default();
+		home.join(".cache").join("midnight-toolkit")
+	}
+
+	pub fn open(root: impl Into<PathBuf>) -> Result<Self, RegistryError> {
+		let registry = Self { root: root.into() };
+		for dir in [registry.blobs_dir(), registry.contracts_dir(), registry.params_dir()] {
+			create_dir(&dir)?;
+		}
+		Ok(registry)
+	}
+
+	/// Cache directory of the public parameters
+	pub fn params_dir(&self) -> PathBuf {
+		self.root.join(PARAMS_DIR)
+	}
+
+	/// Cache directory of the public parameters in the default registry
+	pub fn default_params_dir() -> PathBuf {
+		Self::default_root().join(PARAMS_DIR)
+	}
+
+	/// Copies the artifacts of the compiled contract in `compiled_dir` into the registry
+	pub fn add(
+		&self,
+		name: &str,
+		address: Option<&ContractAddress>,
+		compiled_dir: &Path,
+	) -> Result<ContractManifest, RegistryError> {
+		let valid_name = !name.is_empty()
+			&& name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+			&& !name.starts_with('.');
+		if !valid_name {
+			return Err(RegistryError::InvalidName(name.to_string()));
+		}
+		let address = address
+			.map(serialize_untagged)
+			.transpose()
+			.map_err(|e| io_error(compiled_dir, e))?
+			.map(hex::encode);
+		for manifest in self.list()? {
+			if manifest.name == name || (address.is_some() && manifest.address == address) {
+				return Err(RegistryError::AlreadyRegistered(manifest.name));
+			}
+		}
+
+		let mut files = BTreeMap::new();
+		for dir in ARTIFACT_DIRS {
+			let source_dir = compiled_dir.join(dir);
+			if !source_dir.is_dir() {
+				continue;
+			}
+			for entry in read_dir(&source_dir)? {
+				if entry.is_file() {
+					let file_name = entry.file_name().unwrap_or_default().to_string_lossy();
+					files.insert(format!("{dir}/{file_name}"), self.store_blob(&entry)?);
+				}
+			}
+		}
+		if files.is_empty() {
+			return Err(RegistryError::NoArtifacts(compiled_dir.to_path_buf()));
+		}
+
+		let manifest = ContractManifest { name: name.to_string(), address, files };
+		let contract_dir = self.contracts_dir().join(name);
+		for (path, hash) in &manifest.files {
+			let target = contract_dir.join(path);
+			create_dir(target.parent().expect("artifact paths have a directory"))?;
+			link_blob(&self.blobs_dir().join(hash), &target)?;
+		}
+		let manifest_path = contract_dir.join(MANIFEST_FILE);
+		let json = serde_json::to_vec_pretty(&manifest).expect("manifest is serializable");
+		fs::write(&manifest_path, json).map_err(|e| io_error(&manifest_path, e))?;
+		Ok(manifest)
+	}
+
+	pub fn list(&self) -> Result<Vec<ContractManifest>, RegistryError> {
+		let mut manifests = Vec::new();
+		for dir in read_dir(&self.contracts_dir())? {
+			let path = dir.join(MANIFEST_FILE);
+			if !path.is_file() {
+				continue;
+			}
+			let bytes = fs::read(&path).map_err(|e| io_error(&path, e))?;
+			let manifest = serde_json::from_slice(&bytes)
+				.map_err(|source| RegistryError::InvalidManifest { path, source })?;
+			manifests.push(manifest);
+		}
+		manifests.sort_by(|a: &ContractManifest, b| a.name.cmp(&b.name));
+		Ok(manifests)
+	}
+
+	pub fn find(&self, name_or_address: &str) -> Result<ContractManifest, RegistryError> {
+		self.list()?
+			.into_iter()
+			.find(|manifest| manifest.matches(name_or_address))
+			.ok_or_else(|| RegistryError::NotFound(name_or_address.to_string()))
+	}
+
+	/// Compiled contract directory of a registered contract
+	pub fn artifact_dir(&self, name_or_address: &str) -> Result<PathBuf, RegistryError> {
+		let manifest = self.find(name_or_address)?;
+		Ok(self.contracts_dir().join(manifest.name))
+	}
+
+	/// Removes a contract, and the blobs no other contract uses
+	pub fn remove(&self, name_or_address: &str) -> Result<ContractManifest, RegistryError> {
+		let manifest = self.find(name_or_address)?;
+		let contract_dir = self.contracts_dir().join(&manifest.name);
+		fs::remove_dir_all(&contract_dir).map_err(|e| io_error(&contract_dir, e))?;
+
+		let used: Vec<String> =
+			self.list()?.into_iter().flat_map(|m| m.files.into_values()).collect();
+		for hash in manifest.files.values() {
+			let blob = self.blobs_dir().join(hash);
+			if !used.contains(hash) && blob.exists() {
+				fs::remove_file(&blob).map_err(|e| io_error(&blob, e))?;
+			}
+		}
+		Ok(manifest)
+	}
+
+	fn blobs_dir(&self) -> PathBuf {
+		self.root.join("blobs")
+	}
+
+	fn contracts_dir(&self) -> PathBuf {
+		self.root.join("contracts")
+	}
+
+	/// Stores a file under its hash, returning the hash
+	fn store_blob(&self, path: &Path) -> Result<String, RegistryError> {
+		let bytes = fs::read(path).map_err(|e| io_error(path, e))?;
+		let hash = hex::encode(persistent_hash(&bytes).0);
+		let blob = self.blobs_dir().join(&hash);
+		if !blob.exists() {
+			fs::write(&blob, &bytes).map_err(|e| io_error(&blob, e))?;
+		}
+		// An edit through one contract directory would otherwise change the artifacts of all
+		// contracts sharing the blob, and no longer match its hash
+		let mut permissions = fs::metadata(&blob).map_err(|e| io_error(&blob, e))?.permissions();
+		permissions.set_readonly(true);
+		fs::set_permissions(&blob, permissions).map_err(|e| io_error(&blob, e))?;
+		Ok(hash)
+	}
+}
+
+/// Compiled contract directories of contracts in the default registry, by name or address
+pub fn resolve_artifact_dirs(contracts: &[String]) -> Result<Vec<String>, RegistryError> {
+	if contracts.is_empty() {
+		return Ok(vec![]);
+	}
+	let registry = ContractRegistry::open(ContractRegistry::default_root())?;
+	contracts
+		.iter()
+		.map(|contract| Ok(registry.artifact_dir(contract)?.display().to_string()))
+		.collect()
+}
+
+/// Hard links a blob into a contract directory, copying it if the file system can't link. Both
+/// keep the blob's read-only permissions.
+fn link_blob(blob: &Path, target: &Path) -> Result<(), RegistryError> {
+	if fs::hard_link(blob, target).is_err() {
+		fs::copy(blob, target).map_err(|e| io_error(target, e))?;
+	}
+	Ok(())
+}
+
+fn read_dir(dir: &Path) -> Result<Vec<PathBuf>, RegistryError> {
+	let entries = fs::read_dir(dir).map_err(|e| io_error(dir, e))?;
+	entries
+		.map(|entry| entry.map(|e| e.path()).map_err(|e| io_error(dir, e)))
+		.collect()
+}
+
+fn create_dir(dir: &Path) -> Result<(), RegistryError> {
+	fs::create_dir_all(dir).map_err(|e| io_error(dir, e))
+}
+
+fn io_error(path: &Path, source: std::io::Error) -> RegistryError {
+	RegistryError::Io { path: path.to_path_buf(), source }
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	const MERKLE_TREE_DIR: &str = "../../static/contracts/simple-merkle-tree";
+
+	#[test]
+	fn test_add_list_remove() {
+		let root = tempfile::tempdir().unwrap();
+		let registry = ContractRegistry::open(root.path()).unwrap();
+
+		let manifest = registry.add("merkle-tree", None, Path::new(MERKLE_TREE_DIR)).unwrap();
+		assert_eq!(manifest.circuits(), vec!["check", "store"]);
+		assert!(manifest.files.contains_key("zkir/store.bzkir"));
+		assert!(!manifest.files.keys().any(|path| path.starts_with("contract/")));
+
+		let dir = registry.artifact_dir("merkle-tree").unwrap();
+		assert_eq!(
+			fs::read(dir.join("keys/store.verifier")).unwrap(),
+			fs::read(Path::new(MERKLE_TREE_DIR).join("keys/store.verifier")).unwrap()
+		);
+		// Shared blobs can't be edited through a contract directory
+		let permissions = fs::metadata(dir.join("keys/store.verifier")).unwrap().permissions();
+		assert!(permissions.readonly());
+
+		// Identical artifacts are stored once
+		let copy = registry.add("merkle-tree-copy", None, Path::new(MERKLE_TREE_DIR)).unwrap();
+		assert_eq!(copy.files, manifest.files);
+		assert_eq!(fs::read_dir(root.path().join("blobs")).unwrap().count(), manifest.files.len());
+		assert!(matches!(
+			registry.add("merkle-tree", None, Path::new(MERKLE_TREE_DIR)),
+			Err(RegistryError::AlreadyRegistered(_))
+		));
+
+		registry.remove("merkle-tree").unwrap();
+		assert_eq!(registry.list().unwrap(), vec![copy.clone()]);
+		assert!(root.path().join("blobs").join(&copy.files["keys/store.verifier"]).exists());
+
+		registry.remove("merkle-tree-copy").unwrap();
+		assert_eq!(fs::read_dir(root.path().join("blobs")).unwrap().count(), 0);
+		assert!(matches!(registry.find("merkle-tree"), Err(RegistryError::NotFound(_))));
+	}
+
+	#[test]
+	fn test_invalid_name() {
+		let root = tempfile::tempdir().unwrap();
+		let registry = ContractRegistry::open(root.path()).unwrap();
+		assert!(matches!(
+			registry.add("../escape", None, Path::new(MERKLE_TREE_DIR)),
+			Err(RegistryError::InvalidName(_))
+		));
+	}
+}
@@ -28,6 +28,9 @@ impl TryFrom<u32> for RuntimeVersion {
 			000_017_001 => Ok(Self::V0_17_1),
 			000_018_000 => Ok(Self::V0_18_0),
 			000_018_001 => Ok(Self::V0_18_1),
+			// 0.18.2 only adds calls to 0.18.1. The indexer skips the extrinsics it can't decode,
+			// and the calls it reads are unchanged, so the 0.18.1 metadata reads 0.18.2 blocks
+			000_018_002 => Ok(Self::V0_18_1),
 			_ => Err(IndexerError::UnsupportedBlockVersion(value)),
 		}
 	}
@@ -13,9 +13,11 @@
 
 pub mod cli_parsers;
 pub mod client;
+pub mod contract_registry;
 pub mod genesis_generator;
 pub mod indexer;
 pub mod progress;
+pub mod proof_server;
 pub mod remote_prover;
 pub mod sender;
 pub mod serde_def;
@@ -14,11 +14,14 @@
 use clap::{Args, Parser, Subcommand};
 use commands::{
 	contract_address::{self, ContractAddressArgs},
+	contract_operations::{self, ContractOperationsArgs},
+	contracts::{self, ContractsArgs, ContractsResult},
 	generate_genesis::{self, GenerateGenesisArgs},
 	generate_intent::{self, GenerateIntentArgs},
 	generate_sample_intent::{self, GenerateSampleIntentArgs},
 	generate_txs::{self, GenerateTxsArgs},
 	get_tx_from_context::{self, GetTxFromContextArgs},
+	proof_server::{self, ProofServerArgs},
 	random_address::{self, RandomAddressArgs},
 	send_intent::{self, SendIntentArgs},
 	show_address::{self, ShowAddressArgs},
@@ -32,10 +35,12 @@ use std::{
 	error::Error,
 	fmt,
 	panic::{self, AssertUnwindSafe},
+	path::PathBuf,
 };
 
 use midnight_node_toolkit::{
 	ProofType, SignatureType,
+	contract_registry::{ContractRegistry, PARAMS_DIR_ENV},
 	tx_generator::{TxGenerator, source::Source},
 };
 
@@ -55,6 +60,10 @@ mod utils;
 struct Cli {
 	#[command(subcommand)]
 	command: Commands,
+	/// Directory caching the public parameters fetched on demand [default: the contract
+	/// registry's params directory]
+	#[arg(long, global = true, env = PARAMS_DIR_ENV, value_name = "DIR")]
+	params_dir: Option<PathBuf>,
 }
 
 #[derive(Subcommand)]
@@ -97,8 +106,14 @@ enum Commands {
 	ContractAddress(ContractAddressArgs),
 	/// Show and save a Contract state
 	ContractState(ContractStateArgs),
+	/// List the entry points of a deployed contract and their verifier keys
+	ContractOperations(ContractOperationsArgs),
+	/// Manage the local registry of compiled contract artifacts
+	Contracts(ContractsArgs),
 	/// Extract `Transaction` from `TransactionWithContext`
 	GetTxFromContext(GetTxFromContextArgs),
+	/// Serve the proof server `/prove-tx` protocol, proving transactions locally
+	ProofServer(ProofServerArgs),
 	/// Generate a random `UserAddress` for a given `NetworkId`
 	RandomAddress(RandomAddressArgs),
 	/// Get the version information
@@ -131,6 +146,13 @@ impl fmt::Display for PanicError {
 impl Error for PanicError {}
 
 fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+	let cli = Cli::parse();
+
+	// The ledger reads the public parameters' directory from the environment
+	let params_dir = cli.params_dir.unwrap_or_else(ContractRegistry::default_params_dir);
+	// SAFETY: no other thread is running yet
+	unsafe { std::env::set_var(PARAMS_DIR_ENV, params_dir) };
+
 	let result = panic::catch_unwind(AssertUnwindSafe(|| {
 		tokio::runtime::Builder::new_current_thread()
 			.enable_all()
@@ -153,8 +175,6 @@ fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 					tracing_subscriber::fmt().with_max_level(tracing::Level::WARN).finish();
 				tracing::subscriber::set_global_default(subscriber)?;
 
-				let cli = Cli::parse();
-
 				let res = run_command(cli.command).await;
 
 				if let Err(ref e) = res {
@@ -252,12 +272,33 @@ pub(crate) async fn run_command(
 			Ok(())
 		},
 		Commands::ContractState(args) => contract_state::execute(args).await,
+		Commands::ContractOperations(args) => {
+			let operations = contract_operations::execute(args).await?;
+			println!("{}", serde_json::to_string_pretty(&operations)?);
+			Ok(())
+		},
+		Commands::Contracts(args) => {
+			match contracts::execute(args)? {
+				ContractsResult::Added(manifest) => {
+					println!("Registered {} ({} file(s))", manifest.name, manifest.files.len())
+				},
+				ContractsResult::List(manifests) => {
+					println!("{}", serde_json::to_string_pretty(&manifests)?)
+				},
+				ContractsResult::Removed(manifest) => println!("Removed {}", manifest.name),
+			}
+			Ok(())
+		},
 		Commands::GetTxFromContext(args) => {
 			let (serialized_tx, timestamp) = get_tx_from_context::execute(&args)?;
 			std::fs::write(args.dest_file, serialized_tx)?;
 			println!("{}", timestamp);
 			Ok(())
 		},
+		Commands::ProofServer(args) => {
+			proof_server::execute(args).await?;
+			Ok(())
+		},
 		Commands::RandomAddress(args) => {
 			let address = random_address::execute(args);
 			println!("{}", address);
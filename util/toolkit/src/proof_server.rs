@@ -0,0 +1,586 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Proof server serving the `/prove-tx` protocol of the Midnight proof server.
+//!
+//! Requests are proven by a pool of workers, fed by a bounded job queue. Each job runs on a
+//! blocking thread. A queued job is dropped if its connection fails before a worker takes it, or
+//! when the server shuts down; once started, a proof runs to completion, since the prover can't be
+//! interrupted. The endpoints are:
+//!
+//! - `POST /prove-tx`: tagged unproven `Transaction`, answered with the tagged proven transaction.
+//!   Transactions whose proofs were already computed with `/prove` are returned as is.
+//! - `POST /prove`: tagged `(ProofPreimage, Option<ProvingKeyMaterial>, Option<Fr>)`, answered with
+//!   the tagged proof.
+//! - `GET /health`: liveness, with the state of the job queue.
+//! - `GET /metrics`: job counters in the Prometheus text format.
+
+use hyper::{
+	Body, Method, Request, Response, StatusCode,
+	body::HttpBody,
+	header::CONTENT_TYPE,
+	server::conn::AddrIncoming,
+	service::{make_service_fn, service_fn},
+};
+use midnight_node_ledger_helpers::*;
+use std::{
+	collections::HashMap,
+	convert::Infallible,
+	io,
+	sync::{
+		Arc, Mutex,
+		atomic::{AtomicU64, Ordering},
+	},
+};
+use tokio::{
+	net::TcpListener,
+	sync::{mpsc, oneshot, watch},
+};
+
+type UnprovenTransaction =
+	Transaction<Signature, ProofPreimageMarker, PedersenRandomness, DefaultDB>;
+type ProvenTransaction = Transaction<Signature, ProofMarker, PedersenRandomness, DefaultDB>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProofServerError {
+	#[error("io error: {0}")]
+	Io(#[from] io::Error),
+	#[error("http error: {0}")]
+	Http(#[from] hyper::Error),
+	#[error("malformed request: {0}")]
+	Malformed(String),
+	#[error("request body exceeds the limit of {0} bytes")]
+	TooLarge(usize),
+	#[error("job queue is full")]
+	QueueFull,
+	#[error("proving failed: {0}")]
+	Proving(String),
+	#[error("proving job was dropped")]
+	Dropped,
+}
+
+impl ProofServerError {
+	fn status(&self) -> StatusCode {
+		match self {
+			Self::Malformed(_) | Self::Http(_) => StatusCode::BAD_REQUEST,
+			Self::TooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+			Self::QueueFull => StatusCode::SERVICE_UNAVAILABLE,
+			Self::Io(_) | Self::Proving(_) | Self::Dropped => StatusCode::INTERNAL_SERVER_ERROR,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct ProofServerConfig {
+	/// Number of jobs proven concurrently
+	pub workers: usize,
+	/// Number of jobs waiting for a worker before requests are rejected
+	pub queue_size: usize,
+	/// Maximum size of a request body, in bytes
+	pub max_request_size: usize,
+	/// Compiled contract directories holding the proving keys of contract circuits
+	pub artifact_dirs: Vec<String>,
+}
+
+/// Job counters, exposed on `/metrics`
+#[derive(Debug, Default)]
+pub struct Metrics {
+	pub requests: AtomicU64,
+	pub queued: AtomicU64,
+	pub active: AtomicU64,
+	pub completed: AtomicU64,
+	pub failed: AtomicU64,
+	pub rejected: AtomicU64,
+	pub cancelled: AtomicU64,
+	pub key_cache_hits: AtomicU64,
+	pub key_cache_misses: AtomicU64,
+}
+
+impl Metrics {
+	/// Renders the metrics in the Prometheus text format
+	pub fn render(&self) -> String {
+		let metrics = [
+			("requests_total", "counter", "HTTP requests received", &self.requests),
+			("jobs_queued", "gauge", "Jobs waiting for a worker", &self.queued),
+			("jobs_active", "gauge", "Jobs being proven", &self.active),
+			("jobs_completed_total", "counter", "Jobs proven successfully", &self.completed),
+			("jobs_failed_total", "counter", "Jobs which failed", &self.failed),
+			("jobs_rejected_total", "counter", "Jobs rejected on a full queue", &self.rejected),
+			(
+				"jobs_cancelled_total",
+				"counter",
+				"Jobs whose connection failed before they were answered",
+				&self.cancelled,
+			),
+			(
+				"key_cache_hits_total",
+				"counter",
+				"Proving keys served from memory",
+				&self.key_cache_hits,
+			),
+			(
+				"key_cache_misses_total",
+				"counter",
+				"Proving keys read from disk",
+				&self.key_cache_misses,
+			),
+		];
+		let mut out = String::new();
+		for (name, kind, help, value) in metrics {
+			out += &format!("# HELP proof_server_{name} {help}\n");
+			out += &format!("# TYPE proof_server_{name} {kind}\n");
+			out += &format!("proof_server_{name} {}\n", value.load(Ordering::Relaxed));
+		}
+		out
+	}
+}
+
+/// Proving key material of contract circuits, read once from the compiled contract directories
+struct KeyCache {
+	artifact_dirs: Vec<String>,
+	keys: Mutex<HashMap<String, ProvingKeyMaterial>>,
+}
+
+impl KeyCache {
+	fn get(&self, loc: &str, metrics: &Metrics) -> Result<Option<ProvingKeyMaterial>, io::Error> {
+		if let Some(key) = self.keys.lock().expect("key cache lock was poisoned").get(loc) {
+			metrics.key_cache_hits.fetch_add(1, Ordering::Relaxed);
+			return Ok(Some(key.clone()));
+		}
+		// Read without holding the lock, so jobs needing other keys aren't held up by the disk.
+		// Jobs missing the same key may both read it; the first one read is kept.
+		metrics.key_cache_misses.fetch_add(1, Ordering::Relaxed);
+		let Some(key) = read_key_material(&self.artifact_dirs, loc)? else { return Ok(None) };
+		let mut keys = self.keys.lock().expect("key cache lock was poisoned");
+		Ok(Some(keys.entry(loc.to_string()).or_insert(key).clone()))
+	}
+}
+
+enum ProveRequest {
+	Transaction(UnprovenTransaction),
+	Proof { preimage: ProofPreimage, key: Option<ProvingKeyMaterial>, binding_input: Option<Fr> },
+}
+
+struct Job {
+	request: ProveRequest,
+	reply: oneshot::Sender<Result<Vec<u8>, ProofServerError>>,
+}
+
+struct State {
+	config: ProofServerConfig,
+	metrics: Arc<Metrics>,
+	keys: Arc<KeyCache>,
+	resolver: Resolver,
+	cost_model: CostModel,
+	jobs: mpsc::Sender<Job>,
+}
+
+pub struct ProofServer {
+	state: Arc<State>,
+	queue: mpsc::Receiver<Job>,
+}
+
+impl ProofServer {
+	pub fn new(config: ProofServerConfig) -> Result<Self, ProofServerError> {
+		let (jobs, queue) = mpsc::channel(config.queue_size.max(1));
+		let metrics = Arc::new(Metrics::default());
+		let keys = Arc::new(KeyCache {
+			artifact_dirs: config.artifact_dirs.clone(),
+			keys: Mutex::new(HashMap::new()),
+		});
+		let resolver = job_resolver(keys.clone(), metrics.clone(), None)?;
+		let cost_model = LedgerState::<DefaultDB>::new("undeployed")
+			.parameters
+			.cost_model
+			.runtime_cost_model;
+		let state = Arc::new(State { config, metrics, keys, resolver, cost_model, jobs });
+		Ok(Self { state, queue })
+	}
+
+	pub fn metrics(&self) -> Arc<Metrics> {
+		self.state.metrics.clone()
+	}
+
+	/// Serves requests until `shutdown` completes. Queued jobs are then dropped, and running jobs
+	/// completed.
+	pub async fn serve(
+		self,
+		listener: TcpListener,
+		shutdown: impl Future<Output = ()>,
+	) -> Result<(), ProofServerError> {
+		let Self { state, queue } = self;
+
+		println!("Fetching public parameters and zswap keys...");
+		fetch_zswap_keys(&state.resolver).await;
+
+		let (stop, stopped) = watch::channel(false);
+		let queue = Arc::new(tokio::sync::Mutex::new(queue));
+		let workers: Vec<_> = (0..state.config.workers.max(1))
+			.map(|_| tokio::spawn(worker(state.clone(), queue.clone(), stopped.clone())))
+			.collect();
+
+		let result = serve_http(state, listener, shutdown).await;
+
+		println!("Proof server shutting down");
+		let _ = stop.send(true);
+		for worker in workers {
+			let _ = worker.await;
+		}
+		result
+	}
+}
+
+/// Serves the HTTP endpoints until `shutdown` completes, letting in-flight requests finish
+async fn serve_http(
+	state: Arc<State>,
+	listener: TcpListener,
+	shutdown: impl Future<Output = ()>,
+) -> Result<(), ProofServerError> {
+	println!("Proof server listening on {}", listener.local_addr()?);
+	let make_service = make_service_fn(move |_| {
+		let state = state.clone();
+		async move {
+			Ok::<_, Infallible>(service_fn(move |request| handle_request(state.clone(), request)))
+		}
+	});
+	hyper::Server::builder(AddrIncoming::from_listener(listener)?)
+		// A client may close its side of the connection once it has sent its request, and still
+		// wait for the proof
+		.http1_half_close(true)
+		.serve(make_service)
+		.with_graceful_shutdown(shutdown)
+		.await?;
+	Ok(())
+}
+
+/// Resolver of the proving keys of a job, with the key material sent along with the request taking
+/// precedence over the key cache
+fn job_resolver(
+	keys: Arc<KeyCache>,
+	metrics: Arc<Metrics>,
+	request_key: Option<(String, ProvingKeyMaterial)>,
+) -> Result<Resolver, io::Error> {
+	Ok(Resolver::new(
+		PUBLIC_PARAMS.clone(),
+		DustResolver(MidnightDataProvider::new(
+			FetchMode::OnDemand,
+			OutputMode::Log,
+			DUST_EXPECTED_FILES.to_owned(),
+		)?),
+		Box::new(move |KeyLocation(loc)| {
+			let res = match &request_key {
+				Some((key_loc, key)) if *key_loc == loc => Ok(Some(key.clone())),
+				_ => keys.get(&loc, &metrics),
+			};
+			Box::pin(std::future::ready(res))
+		}),
+	))
+}
+
+async fn worker(
+	state: Arc<State>,
+	queue: Arc<tokio::sync::Mutex<mpsc::Receiver<Job>>>,
+	mut stopped: watch::Receiver<bool>,
+) {
+	loop {
+		let job = tokio::select! {
+			job = async { queue.lock().await.recv().await } => job,
+			_ = stopped.changed() => None,
+		};
+		let Some(Job { request, reply }) = job else { break };
+		state.metrics.queued.fetch_sub(1, Ordering::Relaxed);
+		if reply.is_closed() {
+			state.metrics.cancelled.fetch_add(1, Ordering::Relaxed);
+			continue;
+		}
+
+		state.metrics.active.fetch_add(1, Ordering::Relaxed);
+		// Proving is CPU bound, so each job gets its own blocking thread
+		let handle = tokio::runtime::Handle::current();
+		let job_state = state.clone();
+		let outcome =
+			tokio::task::spawn_blocking(move || handle.block_on(job_state.prove(request))).await;
+		state.metrics.active.fetch_sub(1, Ordering::Relaxed);
+
+		match outcome {
+			Ok(result) => {
+				let counter =
+					if result.is_ok() { &state.metrics.completed } else { &state.metrics.failed };
+				counter.fetch_add(1, Ordering::Relaxed);
+				if reply.send(result).is_err() {
+					state.metrics.cancelled.fetch_add(1, Ordering::Relaxed);
+				}
+			},
+			// The prover panicked; the reply is dropped along with the job
+			Err(_) => {
+				state.metrics.failed.fetch_add(1, Ordering::Relaxed);
+			},
+		}
+	}
+}
+
+impl State {
+	async fn prove(&self, request: ProveRequest) -> Result<Vec<u8>, ProofServerError> {
+		let rng = StdRng::from_entropy();
+		match request {
+			ProveRequest::Transaction(tx) => {
+				let provider =
+					LocalProvingProvider { rng, resolver: &self.resolver, params: &*PUBLIC_PARAMS };
+				let tx = tx
+					.prove(provider, &self.cost_model)
+					.await
+					.map_err(|e| ProofServerError::Proving(format!("{e:?}")))?;
+				Ok(serialize(&tx)?)
+			},
+			ProveRequest::Proof { preimage, key, binding_input } => {
+				let request_resolver;
+				let resolver = match key {
+					Some(key) => {
+						let loc = preimage.key_location.0.to_string();
+						request_resolver = job_resolver(
+							self.keys.clone(),
+							self.metrics.clone(),
+							Some((loc, key)),
+						)?;
+						&request_resolver
+					},
+					None => &self.resolver,
+				};
+				let provider = LocalProvingProvider { rng, resolver, params: &*PUBLIC_PARAMS };
+				let proof = provider
+					.prove(&preimage, binding_input)
+					.await
+					.map_err(|e| ProofServerError::Proving(format!("{e:?}")))?;
+				Ok(serialize(&proof)?)
+			},
+		}
+	}
+
+	/// Queues a job, failing if the queue is full
+	fn submit(
+		&self,
+		request: ProveRequest,
+	) -> Result<oneshot::Receiver<Result<Vec<u8>, ProofServerError>>, ProofServerError> {
+		let (reply, receiver) = oneshot::channel();
+		self.metrics.queued.fetch_add(1, Ordering::Relaxed);
+		if self.jobs.try_send(Job { request, reply }).is_err() {
+			self.metrics.queued.fetch_sub(1, Ordering::Relaxed);
+			self.metrics.rejected.fetch_add(1, Ordering::Relaxed);
+			return Err(ProofServerError::QueueFull);
+		}
+		Ok(receiver)
+	}
+}
+
+fn response(
+	status: StatusCode,
+	content_type: &'static str,
+	body: impl Into<Body>,
+) -> Response<Body> {
+	let mut response = Response::new(body.into());
+	*response.status_mut() = status;
+	response
+		.headers_mut()
+		.insert(CONTENT_TYPE, content_type.parse().expect("valid content type"));
+	response
+}
+
+/// Reads the body of a request, failing as soon as it exceeds `limit` bytes
+async fn read_body(mut body: Body, limit: usize) -> Result<Vec<u8>, ProofServerError> {
+	if body.size_hint().lower() > limit as u64 {
+		return Err(ProofServerError::TooLarge(limit));
+	}
+	let mut bytes = Vec::new();
+	while let Some(chunk) = body.data().await {
+		let chunk = chunk?;
+		if bytes.len() + chunk.len() > limit {
+			return Err(ProofServerError::TooLarge(limit));
+		}
+		bytes.extend_from_slice(&chunk);
+	}
+	Ok(bytes)
+}
+
+async fn handle_request(
+	state: Arc<State>,
+	request: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+	state.metrics.requests.fetch_add(1, Ordering::Relaxed);
+	Ok(route(&state, request).await.unwrap_or_else(|e| {
+		println!("Proof server request failed: {e}");
+		response(e.status(), "text/plain", e.to_string())
+	}))
+}
+
+async fn route(state: &State, request: Request<Body>) -> Result<Response<Body>, ProofServerError> {
+	let (parts, body) = request.into_parts();
+	let request = match (&parts.method, parts.uri.path()) {
+		(&Method::GET, "/health") => {
+			let metrics = &state.metrics;
+			let health = serde_json::json!({
+				"status": "ok",
+				"workers": state.config.workers,
+				"jobsQueued": metrics.queued.load(Ordering::Relaxed),
+				"jobsActive": metrics.active.load(Ordering::Relaxed),
+			});
+			return Ok(response(StatusCode::OK, "application/json", health.to_string()));
+		},
+		(&Method::GET, "/metrics") => {
+			return Ok(response(
+				StatusCode::OK,
+				"text/plain; version=0.0.4",
+				state.metrics.render(),
+			));
+		},
+		(&Method::POST, "/prove-tx") => {
+			let body = read_body(body, state.config.max_request_size).await?;
+			match deserialize::<UnprovenTransaction, _>(&body[..]) {
+				Ok(tx) => ProveRequest::Transaction(tx),
+				Err(unproven_err) => {
+					let tx: ProvenTransaction = deserialize(&body[..]).map_err(|_| {
+						ProofServerError::Malformed(format!("invalid transaction: {unproven_err}"))
+					})?;
+					return Ok(response(
+						StatusCode::OK,
+						"application/octet-stream",
+						serialize(&tx)?,
+					));
+				},
+			}
+		},
+		(&Method::POST, "/prove") => {
+			let body = read_body(body, state.config.max_request_size).await?;
+			let (preimage, key, binding_input) = deserialize(&body[..])
+				.map_err(|e| ProofServerError::Malformed(format!("invalid proof request: {e}")))?;
+			ProveRequest::Proof { preimage, key, binding_input }
+		},
+		(_, "/health" | "/metrics" | "/prove-tx" | "/prove") => {
+			return Ok(response(
+				StatusCode::METHOD_NOT_ALLOWED,
+				"text/plain",
+				"method not allowed",
+			));
+		},
+		_ => return Ok(response(StatusCode::NOT_FOUND, "text/plain", "not found")),
+	};
+
+	// If the connection fails, hyper drops this future and with it the reply receiver, so a worker
+	// skips the job if it hasn't started it yet
+	let proof = state.submit(request)?.await.map_err(|_| ProofServerError::Dropped)??;
+	Ok(response(StatusCode::OK, "application/octet-stream", proof))
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::remote_prover::RemoteProofServer;
+
+	fn config(queue_size: usize, max_request_size: usize) -> ProofServerConfig {
+		ProofServerConfig { workers: 1, queue_size, max_request_size, artifact_dirs: vec![] }
+	}
+
+	/// Transaction without intents or offers, so proving it needs no keys
+	fn empty_tx() -> UnprovenTransaction {
+		Transaction::new("undeployed", HashMapStorage::new(), None, HashMap::new())
+	}
+
+	/// Serves the HTTP endpoints on a free port, returning their base URL. Unlike
+	/// [`ProofServer::serve`], the zswap keys aren't fetched, since the test transactions don't need
+	/// them.
+	async fn serve_on_free_port(state: Arc<State>) -> String {
+		let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+		let url = format!("http://{}/", listener.local_addr().unwrap());
+		tokio::spawn(serve_http(state, listener, std::future::pending()));
+		url
+	}
+
+	async fn post(url: String, body: Vec<u8>) -> reqwest::Response {
+		reqwest::Client::new().post(url).body(body).send().await.unwrap()
+	}
+
+	#[tokio::test]
+	async fn test_prove_tx_round_trip() {
+		let ProofServer { state, queue } = ProofServer::new(config(4, 1024 * 1024)).unwrap();
+		let (_stop, stopped) = watch::channel(false);
+		tokio::spawn(worker(state.clone(), Arc::new(tokio::sync::Mutex::new(queue)), stopped));
+		let url = serve_on_free_port(state.clone()).await;
+
+		// Unproven transactions are proven by a worker
+		let response = post(format!("{url}prove-tx"), serialize(&empty_tx()).unwrap()).await;
+		assert_eq!(response.status(), reqwest::StatusCode::OK);
+		let proven: ProvenTransaction = deserialize(&response.bytes().await.unwrap()[..]).unwrap();
+		assert_eq!(state.metrics.completed.load(Ordering::Relaxed), 1);
+
+		// The remote prover sends the transaction proven with `/prove`, which is returned as is
+		let remote = RemoteProofServer::new(url)
+			.prove(empty_tx(), StdRng::seed_from_u64(0), &state.resolver, &state.cost_model)
+			.await;
+		assert_eq!(serialize(&remote).unwrap(), serialize(&proven).unwrap());
+		assert_eq!(state.metrics.requests.load(Ordering::Relaxed), 2);
+	}
+
+	#[tokio::test]
+	async fn test_full_queue_is_rejected() {
+		// Without workers, the first job stays queued
+		let ProofServer { state, queue: _queue } =
+			ProofServer::new(config(1, 1024 * 1024)).unwrap();
+		let _reply = state.submit(ProveRequest::Transaction(empty_tx())).unwrap();
+		let url = serve_on_free_port(state.clone()).await;
+
+		let response = post(format!("{url}prove-tx"), serialize(&empty_tx()).unwrap()).await;
+		assert_eq!(response.status(), reqwest::StatusCode::SERVICE_UNAVAILABLE);
+		assert_eq!(state.metrics.rejected.load(Ordering::Relaxed), 1);
+		assert_eq!(state.metrics.queued.load(Ordering::Relaxed), 1);
+	}
+
+	#[tokio::test]
+	async fn test_request_size_limit() {
+		let ProofServer { state, queue: _queue } = ProofServer::new(config(1, 4)).unwrap();
+		let url = serve_on_free_port(state.clone()).await;
+
+		let response = post(format!("{url}prove-tx"), b"too large".to_vec()).await;
+		assert_eq!(response.status(), reqwest::StatusCode::PAYLOAD_TOO_LARGE);
+
+		// Chunked bodies are read up to the limit
+		let chunks = futures::stream::iter([Ok::<_, io::Error>("to"), Ok("o large")]);
+		let response = reqwest::Client::new()
+			.post(format!("{url}prove"))
+			.body(reqwest::Body::wrap_stream(chunks))
+			.send()
+			.await
+			.unwrap();
+		assert_eq!(response.status(), reqwest::StatusCode::PAYLOAD_TOO_LARGE);
+
+		let response = post(format!("{url}prove-tx"), b"bad".to_vec()).await;
+		assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+	}
+
+	#[tokio::test]
+	async fn test_read_body_limit() {
+		let err = read_body(Body::from("body"), 3).await.unwrap_err();
+		assert_eq!(err.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+		let body = read_body(Body::from("body"), 4).await.unwrap();
+		assert_eq!(body, b"body");
+	}
+
+	#[test]
+	fn test_metrics_render() {
+		let metrics = Metrics::default();
+		metrics.completed.fetch_add(3, Ordering::Relaxed);
+		let rendered = metrics.render();
+		assert!(rendered.contains("# TYPE proof_server_jobs_completed_total counter\n"));
+		assert!(rendered.contains("\nproof_server_jobs_completed_total 3\n"));
+		assert!(rendered.contains("\nproof_server_jobs_queued 0\n"));
+	}
+}
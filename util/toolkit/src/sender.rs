@@ -17,7 +17,7 @@ use std::{marker::PhantomData, sync::Arc};
 use subxt::{
 	OnlineClient, PolkadotConfig,
 	ext::{codec::Encode, subxt_core::config::Hash},
-	tx::{TxInBlock, TxProgress},
+	tx::{TxInBlock, TxProgress, ValidationResult},
 };
 use thiserror::Error;
 use tokio::sync::Semaphore;
@@ -110,6 +110,31 @@ where
 		}
 	}
 
+	/// Validates a transaction against the node's transaction pool without submitting it, and
+	/// prints the priority the pool assigns to it
+	pub async fn validate_tx(
+		&self,
+		tx: &SerdeTransaction<S, P, DefaultDB>,
+	) -> Result<(), SendToUrlError> {
+		let midnight_tx_hash = TxHashes::format_midnight_tx_hash(&tx.transaction_hash());
+		let tx_serialize = tx.serialize_inner().map_err(|e| self.error(e.into()))?;
+		let mn_tx = mn_meta::tx().midnight().send_mn_transaction(tx_serialize);
+
+		let unsigned_extrinsic =
+			self.api.tx().create_unsigned(&mn_tx).map_err(|e| self.error(e.into()))?;
+
+		match unsigned_extrinsic.validate().await.map_err(|e| self.error(e))? {
+			ValidationResult::Valid(valid) => println!(
+				"Dry-run: tx {midnight_tx_hash} is valid at {} with priority {}",
+				self.url, valid.priority
+			),
+			invalid => {
+				println!("Dry-run: tx {midnight_tx_hash} is not valid at {}: {invalid:?}", self.url)
+			},
+		}
+		Ok(())
+	}
+
 	async fn send_tx_no_wait(
 		&self,
 		tx: &SerdeTransaction<S, P, DefaultDB>,
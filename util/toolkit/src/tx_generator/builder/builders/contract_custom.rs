@@ -4,6 +4,7 @@ use crate::{
 		DeserializedTransactionsWithContext, IntentCustom, OfferInfo, ProofProvider, ProofType,
 		SignatureType, TransactionWithContext, Wallet, WalletSeed,
 	},
+	contract_registry,
 	serde_def::SourceTransactions,
 	toolkit_js::{EncodedOutputInfo, EncodedZswapLocalState},
 };
@@ -18,6 +19,8 @@ use std::{collections::HashMap, sync::Arc};
 
 #[derive(Debug, thiserror::Error)]
 pub enum CustomContractBuilderError {
+	#[error("failed to resolve contract artifacts: {0}")]
+	FailedResolvingContract(#[from] contract_registry::RegistryError),
 	#[error("failed to read zswap state file")]
 	FailedReadingZswapStateFile(std::io::Error),
 	#[error("failed to parse zswap state")]
@@ -35,7 +38,8 @@ pub enum CustomContractBuilderError {
 pub struct CustomContractBuilder {
 	funding_seed: String,
 	rng_seed: Option<[u8; 32]>,
-	artifact_dirs: Vec<String>,
+	compiled_contract_dirs: Vec<String>,
+	contracts: Vec<String>,
 	intent_files: Vec<String>,
 	utxo_inputs: Vec<UtxoId>,
 	zswap_state_file: Option<String>,
@@ -48,6 +52,7 @@ impl CustomContractBuilder {
 			funding_seed,
 			rng_seed,
 			compiled_contract_dirs,
+			contracts,
 			intent_files,
 			utxo_inputs,
 			zswap_state_file,
@@ -56,7 +61,8 @@ impl CustomContractBuilder {
 		Self {
 			funding_seed,
 			rng_seed,
-			artifact_dirs: compiled_contract_dirs,
+			compiled_contract_dirs,
+			contracts,
 			intent_files,
 			utxo_inputs,
 			zswap_state_file,
@@ -79,10 +85,12 @@ impl CustomContractBuilder {
 	fn build_intent(&self) -> Result<IntentCustom<DefaultDB>, CustomContractBuilderError> {
 		let mut rng = self.rng_seed.map(StdRng::from_seed).unwrap_or(StdRng::from_entropy());
 		println!("Create intent info for contract custom");
+		let mut artifact_dirs = self.compiled_contract_dirs.clone();
+		artifact_dirs.extend(contract_registry::resolve_artifact_dirs(&self.contracts)?);
 		// This is to satisfy the `&'static` need to update the context's resolver
 		// Data lives for the remainder of the program's life.
 		let boxed_resolver =
-			Box::new(IntentCustom::<DefaultDB>::get_resolver(&self.artifact_dirs).unwrap());
+			Box::new(IntentCustom::<DefaultDB>::get_resolver(&artifact_dirs).unwrap());
 		let static_ref_resolver = Box::leak(boxed_resolver);
 
 		let mut actions: Vec<ContractAction<ProofPreimageMarker, DefaultDB>> = vec![];
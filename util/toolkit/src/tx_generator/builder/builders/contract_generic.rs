@@ -0,0 +1,280 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+	builder::{
+		AlignedValue, BuildContractAction, BuildInput, BuildOutput, BuildTxs, BuildTxsExt,
+		CallDescription, CallDescriptionError, CallInfo, DefaultDB,
+		DeserializedTransactionsWithContext, EntryPointBuf, GenericContract, IntentCustom,
+		IntentInfo, OfferInfo, Op, ProofProvider, ProofType, ResultModeGather, SignatureType,
+		TransactionWithContext, Wallet, WalletSeed,
+	},
+	cli_parsers as cli, contract_registry,
+	serde_def::SourceTransactions,
+	tx_generator::builder::GenericContractCallArgs,
+};
+use async_trait::async_trait;
+use midnight_node_ledger_helpers::ContractAddress;
+use serde::Deserialize;
+use std::{marker::PhantomData, path::PathBuf, sync::Arc};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GenericContractCallBuilderError {
+	#[error("failed to resolve contract artifacts: {0}")]
+	FailedResolvingContract(#[from] contract_registry::RegistryError),
+	#[error("failed to read call file {0}")]
+	FailedReadingCallFile(PathBuf, std::io::Error),
+	#[error("failed to parse call file {0}: {1}")]
+	FailedParsingCallFile(PathBuf, serde_json::Error),
+	#[error("invalid {1} in call file {0}: {2}")]
+	InvalidCallValue(PathBuf, &'static str, String),
+	#[error("contract {0:?} is not present in the ledger state")]
+	ContractNotPresent(ContractAddress),
+	#[error("contract has no entry point {entry_point}; available: {available}")]
+	MissingEntryPoint { entry_point: String, available: String },
+	#[error("call to entry point {entry_point} can't be built: {error}")]
+	InvalidPublicTranscript { entry_point: String, error: CallDescriptionError },
+}
+
+/// JSON description of a contract call. Values are hex encoded untagged ledger serializations.
+///
+/// ```json
+/// {
+///   "entryPoint": "store",
+///   "input": "<AlignedValue>",
+///   "output": "<AlignedValue>",
+///   "privateTranscriptOutputs": ["<AlignedValue>"],
+///   "publicTranscript": ["<Op>"]
+/// }
+/// ```
+///
+/// The public transcript ops are in gather mode: reads carry no results. The program is run against
+/// the contract's current state when the call is built, so a call file stays valid as the state
+/// changes.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallFile {
+	pub entry_point: String,
+	pub input: String,
+	pub output: Option<String>,
+	#[serde(default)]
+	pub private_transcript_outputs: Vec<String>,
+	pub public_transcript: Vec<String>,
+}
+
+pub struct GenericContractCallBuilder {
+	funding_seed: String,
+	contract_address: ContractAddress,
+	call_files: Vec<PathBuf>,
+	compiled_contract_dirs: Vec<String>,
+	contracts: Vec<String>,
+	rng_seed: Option<[u8; 32]>,
+}
+
+impl GenericContractCallBuilder {
+	pub fn new(args: GenericContractCallArgs) -> Self {
+		Self {
+			funding_seed: args.funding_seed,
+			contract_address: args.contract_address,
+			call_files: args.call_files,
+			compiled_contract_dirs: args.compiled_contract_dirs,
+			contracts: args.contracts,
+			rng_seed: args.rng_seed,
+		}
+	}
+
+	fn read_call_file(
+		path: &PathBuf,
+	) -> Result<(String, CallDescription<DefaultDB>), GenericContractCallBuilderError> {
+		let bytes = std::fs::read(path)
+			.map_err(|e| GenericContractCallBuilderError::FailedReadingCallFile(path.clone(), e))?;
+		let call: CallFile = serde_json::from_slice(&bytes)
+			.map_err(|e| GenericContractCallBuilderError::FailedParsingCallFile(path.clone(), e))?;
+
+		let decode = |field: &'static str, value: &str| {
+			cli::hex_ledger_untagged_decode::<AlignedValue>(value).map_err(|e| {
+				GenericContractCallBuilderError::InvalidCallValue(
+					path.clone(),
+					field,
+					e.to_string(),
+				)
+			})
+		};
+		let output = match &call.output {
+			Some(output) => decode("output", output)?,
+			None => ().into(),
+		};
+		let description = CallDescription {
+			input: decode("input", &call.input)?,
+			output,
+			private_transcript_outputs: call
+				.private_transcript_outputs
+				.iter()
+				.map(|value| decode("private transcript output", value))
+				.collect::<Result<_, _>>()?,
+			program: call
+				.public_transcript
+				.iter()
+				.map(|op| {
+					cli::hex_ledger_untagged_decode::<Op<ResultModeGather, DefaultDB>>(op).map_err(
+						|e| {
+							GenericContractCallBuilderError::InvalidCallValue(
+								path.clone(),
+								"public transcript op",
+								e.to_string(),
+							)
+						},
+					)
+				})
+				.collect::<Result<_, _>>()?,
+		};
+		Ok((call.entry_point, description))
+	}
+}
+
+impl BuildTxsExt for GenericContractCallBuilder {
+	fn funding_seed(&self) -> WalletSeed {
+		Wallet::<DefaultDB>::wallet_seed_decode(&self.funding_seed)
+	}
+
+	fn rng_seed(&self) -> Option<[u8; 32]> {
+		self.rng_seed
+	}
+}
+
+#[async_trait]
+impl BuildTxs for GenericContractCallBuilder {
+	type Error = GenericContractCallBuilderError;
+
+	async fn build_txs_from(
+		&self,
+		received_tx: SourceTransactions<SignatureType, ProofType>,
+		prover_arc: Arc<dyn ProofProvider<DefaultDB>>,
+	) -> Result<DeserializedTransactionsWithContext<SignatureType, ProofType>, Self::Error> {
+		let mut artifact_dirs = self.compiled_contract_dirs.clone();
+		artifact_dirs.extend(contract_registry::resolve_artifact_dirs(&self.contracts)?);
+
+		// - LedgerContext and TransactionInfo
+		let (context, mut tx_info) = self.context_and_tx_info(received_tx, prover_arc);
+
+		let entry_points: Vec<String> = context.with_ledger_state(|ledger_state| {
+			let contract_state = ledger_state.index(self.contract_address).ok_or(
+				GenericContractCallBuilderError::ContractNotPresent(self.contract_address),
+			)?;
+			Ok(contract_state
+				.operations
+				.keys()
+				.map(|EntryPointBuf(name)| String::from_utf8_lossy(&name).to_string())
+				.collect())
+		})?;
+		println!("Contract entry points: {}", entry_points.join(", "));
+
+		// This is to satisfy the `&'static` need to update the context's resolver
+		// Data lives for the remainder of the program's life.
+		let resolver =
+			Box::leak(Box::new(IntentCustom::<DefaultDB>::get_resolver(&artifact_dirs).unwrap()));
+
+		// - Contract Calls
+		let mut actions: Vec<Box<dyn BuildContractAction<DefaultDB>>> = vec![];
+		for path in &self.call_files {
+			let (entry_point, description) = Self::read_call_file(path)?;
+			if !entry_points.contains(&entry_point) {
+				return Err(GenericContractCallBuilderError::MissingEntryPoint {
+					entry_point,
+					available: entry_points.join(", "),
+				});
+			}
+			// Run the public transcript now, so a call file that doesn't match the contract state
+			// fails here rather than while the transaction is built
+			context
+				.with_ledger_state(|ledger_state| {
+					description.verify_program(self.contract_address, ledger_state)
+				})
+				.map_err(|error| GenericContractCallBuilderError::InvalidPublicTranscript {
+					entry_point: entry_point.clone(),
+					error,
+				})?;
+			println!("Create call to entry point {entry_point}");
+
+			actions.push(Box::new(CallInfo {
+				type_: GenericContract::new(resolver),
+				address: self.contract_address,
+				key: Box::leak(entry_point.into_boxed_str()),
+				input: Box::new(description),
+				_marker: PhantomData,
+			}));
+		}
+
+		// - Intents
+		let intent_info = IntentInfo {
+			guaranteed_unshielded_offer: None,
+			fallible_unshielded_offer: None,
+			actions,
+		};
+		tx_info.add_intent(1, Box::new(intent_info));
+
+		//   - Input
+		let inputs_info: Vec<Box<dyn BuildInput<DefaultDB>>> = vec![];
+
+		//   - Output
+		let outputs_info: Vec<Box<dyn BuildOutput<DefaultDB>>> = vec![];
+
+		let offer_info =
+			OfferInfo { inputs: inputs_info, outputs: outputs_info, transients: vec![] };
+
+		tx_info.set_guaranteed_offer(offer_info);
+
+		tx_info.set_wallet_seeds(vec![self.funding_seed()]);
+		tx_info.use_mock_proofs_for_fees(false);
+
+		#[cfg(not(feature = "erase-proof"))]
+		let tx = tx_info.prove().await.expect("Balancing TX failed");
+
+		#[cfg(feature = "erase-proof")]
+		let tx = tx_info.erase_proof().await.expect("Balancing TX failed");
+
+		let tx_with_context = TransactionWithContext::new(tx, None);
+
+		Ok(DeserializedTransactionsWithContext { initial_tx: tx_with_context, batches: vec![] })
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_read_call_file() {
+		let dir = tempfile::tempdir().unwrap();
+		let path = dir.path().join("call.json");
+		let input: AlignedValue = 12u32.into();
+		let input_hex =
+			hex::encode(midnight_node_ledger_helpers::serialize_untagged(&input).unwrap());
+		let json =
+			format!(r#"{{"entryPoint": "store", "input": "{input_hex}", "publicTranscript": []}}"#);
+		std::fs::write(&path, json).unwrap();
+
+		let (entry_point, description) = GenericContractCallBuilder::read_call_file(&path).unwrap();
+		assert_eq!(entry_point, "store");
+		assert_eq!(description.input, input);
+		assert_eq!(description.output, AlignedValue::from(()));
+		assert!(description.program.is_empty());
+
+		std::fs::write(&path, r#"{"entryPoint": "store", "input": "zz", "publicTranscript": []}"#)
+			.unwrap();
+		assert!(matches!(
+			GenericContractCallBuilder::read_call_file(&path),
+			Err(GenericContractCallBuilderError::InvalidCallValue(_, "input", _))
+		));
+	}
+}
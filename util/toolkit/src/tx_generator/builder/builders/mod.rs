@@ -16,6 +16,7 @@ mod claim_rewards;
 mod contract_call;
 mod contract_custom;
 mod contract_deploy;
+mod contract_generic;
 mod contract_maintenance;
 mod do_nothing;
 mod register_dust_address;
@@ -27,6 +28,7 @@ pub use claim_rewards::*;
 pub use contract_call::*;
 pub use contract_custom::*;
 pub use contract_deploy::*;
+pub use contract_generic::*;
 pub use contract_maintenance::*;
 pub use do_nothing::*;
 pub use register_dust_address::*;
@@ -14,8 +14,8 @@
 use async_trait::async_trait;
 use builders::{
 	BatchesBuilder, ClaimRewardsBuilder, ContractCallBuilder, ContractDeployBuilder,
-	ContractMaintenanceBuilder, CustomContractBuilder, DoNothingBuilder, ReplaceInitialTxBuilder,
-	single_tx::SingleTxBuilder,
+	ContractMaintenanceBuilder, CustomContractBuilder, DoNothingBuilder,
+	GenericContractCallBuilder, ReplaceInitialTxBuilder, single_tx::SingleTxBuilder,
 };
 use clap::{Args, Subcommand};
 use midnight_node_ledger_helpers::*;
@@ -90,6 +90,10 @@ pub struct CustomContractArgs {
 	/// The directory containing directories with key files for the Resolver. Accepts multiple
 	#[arg(short, long = "compiled-contract-dir")]
 	pub compiled_contract_dirs: Vec<String>,
+	/// Name or untagged hex address of a contract in the local registry (see `contracts add`),
+	/// used like a compiled contract dir. Accepts multiple
+	#[arg(long = "contract")]
+	pub contracts: Vec<String>,
 	/// Intent file to include in the transaction. Accepts multiple
 	#[arg(long = "intent-file")]
 	pub intent_files: Vec<String>,
@@ -129,6 +133,35 @@ pub struct ContractCallArgs {
 	pub fee: u128,
 }
 
+#[derive(Args, Clone, Debug)]
+pub struct GenericContractCallArgs {
+	/// Seed for funding the transactions
+	#[arg(
+		long,
+		default_value = FUNDING_SEED
+	)]
+	pub funding_seed: String,
+	/// Address of the contract to call
+	#[arg(long, value_parser = cli::contract_address_decode)]
+	pub contract_address: ContractAddress,
+	/// JSON description of a call: its entry point, input, output, private transcript outputs and
+	/// public transcript program, as hex encoded untagged ledger values. Accepts multiple
+	#[arg(long = "call-file", required = true)]
+	pub call_files: Vec<PathBuf>,
+	/// The directory containing directories with key files for the Resolver. Accepts multiple
+	#[arg(short, long = "compiled-contract-dir")]
+	pub compiled_contract_dirs: Vec<String>,
+	/// Name or untagged hex address of a contract in the local registry (see `contracts add`),
+	/// used like a compiled contract dir. Accepts multiple
+	#[arg(long = "contract")]
+	pub contracts: Vec<String>,
+	#[arg(
+        long,
+        value_parser = cli::hex_str_decode::<[u8; 32]>,
+    )]
+	pub rng_seed: Option<[u8; 32]>,
+}
+
 #[derive(Args, Clone, Debug)]
 pub struct ContractMaintenanceArgs {
 	/// Seed for funding the transactions
@@ -281,6 +314,8 @@ pub enum Builder {
 	ContractSimple(ContractCall),
 	/// Construct txs from custom contract intents
 	ContractCustom(CustomContractArgs),
+	/// Call any entry point of a deployed contract, from JSON call descriptions
+	ContractGeneric(GenericContractCallArgs),
 	/// Claim rewards
 	ClaimRewards(ClaimRewardsArgs),
 	/// Send single transaction with one-or-many outputs
@@ -356,6 +391,7 @@ impl Builder {
 				ContractCall::Maintenance(args) => constr(ContractMaintenanceBuilder::new(args)),
 			},
 			Builder::ContractCustom(args) => constr(CustomContractBuilder::new(args)),
+			Builder::ContractGeneric(args) => constr(GenericContractCallBuilder::new(args)),
 			Builder::ClaimRewards(args) => constr(ClaimRewardsBuilder::new(args)),
 			Builder::SingleTx(args) => constr(SingleTxBuilder::new(args)),
 			Builder::RegisterDustAddress(args) => constr(RegisterDustAddressBuilder::new(args)),
@@ -14,6 +14,7 @@
 use async_trait::async_trait;
 use midnight_node_ledger_helpers::*;
 use std::{fs::File, io::Write, marker::PhantomData, sync::Arc, time::Duration};
+use subxt::{OnlineClient, PolkadotConfig};
 use tokio::sync::Semaphore;
 
 use crate::{
@@ -88,6 +89,19 @@ where
 	}
 }
 
+/// Validates transactions against a node without submitting them, for dry-runs
+pub struct ValidateTxsAtUrl<S, P> {
+	url: String,
+	_marker_p: PhantomData<P>,
+	_marker_s: PhantomData<S>,
+}
+
+impl<S, P> ValidateTxsAtUrl<S, P> {
+	pub fn new(url: String) -> Self {
+		Self { url, _marker_p: PhantomData, _marker_s: PhantomData }
+	}
+}
+
 #[async_trait]
 pub trait SendTxs<
 	S: SignatureKind<DefaultDB> + Tagged + Send + 'static,
@@ -180,3 +194,31 @@ where
 		Ok(())
 	}
 }
+
+#[async_trait]
+impl<
+	S: SignatureKind<DefaultDB> + Tagged + Send + Sync + 'static,
+	P: ProofKind<DefaultDB> + Send + Sync + 'static,
+> SendTxs<S, P> for ValidateTxsAtUrl<S, P>
+where
+	<P as ProofKind<DefaultDB>>::Pedersen: Send + Sync,
+	<P as ProofKind<DefaultDB>>::LatestProof: Send + Sync,
+	<P as ProofKind<DefaultDB>>::Proof: Send + Sync,
+	Transaction<S, P, PedersenRandomness, DefaultDB>: Tagged,
+{
+	async fn send_txs(
+		&self,
+		txs: &DeserializedTransactionsWithContext<S, P>,
+	) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+		let api = OnlineClient::<PolkadotConfig>::from_insecure_url(self.url.clone()).await?;
+		let sender = Sender::<S, P>::new(api, self.url.clone());
+
+		sender.validate_tx(&txs.initial_tx.tx).await?;
+		for batch in txs.batches.iter() {
+			for tx in batch.txs.iter() {
+				sender.validate_tx(&tx.tx).await?;
+			}
+		}
+		Ok(())
+	}
+}
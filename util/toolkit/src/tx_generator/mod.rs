@@ -30,7 +30,7 @@ pub mod destination;
 pub mod source;
 
 use builder::{BuildTxs, Builder, DynamicError};
-use destination::{Destination, SendTxs, SendTxsToFile, SendTxsToUrl};
+use destination::{Destination, SendTxs, SendTxsToFile, SendTxsToUrl, ValidateTxsAtUrl};
 use source::{GetTxs, GetTxsFromFile, GetTxsFromUrl, Source, SourceError};
 
 #[derive(Debug, Error)]
@@ -74,15 +74,24 @@ where
 		builder: Builder,
 		proof_server: Option<String>,
 		dry_run: bool,
+		validate_only: bool,
 	) -> Result<Self, TxGeneratorError> {
-		let source = Self::source(src, dry_run).await?;
-		let destinations = Self::destinations(dest, dry_run).await?;
+		// When only validating, the dry-run reads the source transactions and has the destination
+		// nodes validate them without submitting them, to show their transaction pool priority
+		let validate_only = dry_run && validate_only;
+		let source = Self::source(src, dry_run && !validate_only).await?;
+		let destinations = Self::destinations(dest, dry_run, validate_only).await?;
 		let builder = builder.to_builder(dry_run);
 		let prover = Self::prover(proof_server, dry_run);
 
 		Ok(Self { source, destinations, builder, prover })
 	}
 
+	/// Whether a dry-run with this builder can validate transactions against the destination nodes
+	pub fn validates_on_dry_run(builder: &Builder) -> bool {
+		matches!(builder, Builder::Send)
+	}
+
 	pub async fn source(src: Source, dry_run: bool) -> Result<Box<dyn GetTxs<S, P>>, SourceError> {
 		if let Some(ref src_files) = src.src_files {
 			if dry_run {
@@ -112,6 +121,7 @@ where
 	async fn destinations(
 		dest: Destination,
 		dry_run: bool,
+		validate_only: bool,
 	) -> Result<Vec<Box<dyn SendTxs<S, P>>>, DestinationError> {
 		if let Some(ref dest_file) = dest.dest_file {
 			if dry_run {
@@ -135,6 +145,9 @@ where
 			if dry_run {
 				println!("Dry-run: Destination RPC: {:?}", &url);
 				println!("Dry-run: Destination rate: {:?} TPS", &dest.rate);
+				if validate_only {
+					dests.push(Box::new(ValidateTxsAtUrl::new(url)));
+				}
 				continue;
 			}
 			let api = OnlineClient::<PolkadotConfig>::from_insecure_url(url.clone()).await?;
@@ -11,29 +11,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use subxt_signer::SecretUriError;
+use governance::error::GovernanceError;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum UpgraderError {
-	#[error("Secret URI parse error: {0}")]
-	UriParseFailed(#[from] SecretUriError),
-	#[error("Subxt signer error: {0}")]
-	SubxtSignerError(#[from] subxt_signer::sr25519::Error),
+	#[error("Governance error: {0}")]
+	GovernanceError(#[from] GovernanceError),
 	#[error("Subxt error: {0}")]
 	SubxtError(#[from] subxt::Error),
-	#[error("BIP error: {0}")]
-	BipError(#[from] bip39::Error),
+	#[error("Codec error: {0}")]
+	CodecError(#[from] parity_scale_codec::Error),
+	#[error("Verification error: {0}")]
+	VerifyError(String),
 	#[error("serialization error: {0}")]
 	SerializationError(std::io::Error),
 	#[error("deserialization error: {0}")]
 	DeserializationError(std::io::Error),
-	#[error("Code upgrade failed: Missing code updated event")]
-	CodeUpgradeFailed,
-	#[error("Proposal index not found in events")]
-	ProposalIndexNotFound,
-	#[error("Encoding error: {0}")]
-	EncodingError(String),
 }
 
 impl actix_web::ResponseError for UpgraderError {}
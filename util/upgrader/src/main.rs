@@ -13,13 +13,15 @@
 
 use actix_web::middleware::Logger;
 use actix_web::{App, HttpResponse, HttpServer, Responder, get, web};
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
+use governance::error::GovernanceError;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 use subxt_signer::sr25519::Keypair;
 use tokio::sync::Mutex;
 use upgrader::error::UpgraderError;
+use upgrader::verify::{TryRuntime, verify};
 use upgrader::{execute_upgrade, get_signer};
 
 #[derive(Parser, Clone)]
@@ -33,6 +35,14 @@ struct Cli {
 	#[arg(short, long, env, default_value = "//Alice")]
 	signer_key: String,
 
+	/// Seeds of the Council members approving the upgrade, comma separated
+	#[arg(long, env, value_delimiter = ',', default_value = "//Dave,//Eve")]
+	council_signers: Vec<String>,
+
+	/// Seeds of the Technical Committee members approving the upgrade, comma separated
+	#[arg(long, env, value_delimiter = ',', default_value = "//Alice,//Bob")]
+	technical_committee_signers: Vec<String>,
+
 	/// Activate upgrade after a timeout (seconds)
 	#[arg(short, long, env)]
 	timeout: Option<u64>,
@@ -44,12 +54,42 @@ struct Cli {
 	/// Listen for HTTP requests on this port
 	#[arg(short, long, default_value = "8080", env)]
 	port: u16,
+
+	#[command(subcommand)]
+	command: Option<Command>,
+}
+
+#[derive(Subcommand, Clone)]
+enum Command {
+	/// Check the upgrade is safe, without submitting any extrinsic
+	Verify(VerifyArgs),
+}
+
+#[derive(Args, Clone)]
+struct VerifyArgs {
+	/// The new runtime WASM built with the `try-runtime` feature, to run its migrations
+	#[arg(long, value_name = "FILE", env)]
+	try_runtime_path: Option<PathBuf>,
+
+	/// State snapshot to run the migrations against, created from the RPC node if it doesn't exist
+	#[arg(long, value_name = "FILE", default_value = "midnight.snap", env)]
+	snapshot_path: PathBuf,
+
+	/// The `try-runtime` CLI binary
+	#[arg(long, value_name = "FILE", default_value = "try-runtime", env)]
+	try_runtime_bin: PathBuf,
+
+	/// Block time in milliseconds, used to run multi-block migrations
+	#[arg(long, default_value = "6000")]
+	blocktime: u64,
 }
 
 #[derive(Clone)]
 struct AppData {
 	pub rpc_url: String,
 	pub signer: Keypair,
+	pub council: Vec<Keypair>,
+	pub technical_committee: Vec<Keypair>,
 	pub code: Vec<u8>,
 	pub already_executed: Arc<Mutex<bool>>,
 	pub busy: Arc<Mutex<bool>>,
@@ -61,12 +101,23 @@ async fn execute(data: web::Data<AppData>) -> Result<impl Responder, UpgraderErr
 		Ok(HttpResponse::Conflict().body("upgrade has already been executed"))
 	} else {
 		*data.busy.lock().await = true;
-		execute_upgrade(&data.rpc_url, &data.signer, &data.code).await?;
+		execute_upgrade(
+			&data.rpc_url,
+			data.council.clone(),
+			data.technical_committee.clone(),
+			&data.signer,
+			&data.code,
+		)
+		.await?;
 		*data.already_executed.lock().await = true;
 		Ok(HttpResponse::Ok().body("upgrade executed"))
 	}
 }
 
+fn get_signers(keys: &[String]) -> Result<Vec<Keypair>, GovernanceError> {
+	keys.iter().map(|key| get_signer(key)).collect()
+}
+
 #[get("/")]
 async fn health() -> impl Responder {
 	HttpResponse::Ok().body("ok")
@@ -79,13 +130,32 @@ async fn main() -> std::io::Result<()> {
 	env_logger::init_from_env(env_logger::Env::default().default_filter_or("info"));
 
 	let signer = get_signer(&cli.signer_key).expect("failed to get signer");
+	let council = get_signers(&cli.council_signers).expect("failed to get Council signers");
+	let technical_committee = get_signers(&cli.technical_committee_signers)
+		.expect("failed to get Technical Committee signers");
 	let code = std::fs::read(&cli.runtime_path)?;
 
 	log::info!("Loaded new runtime code from path: {}", cli.runtime_path.display());
+	if let Some(Command::Verify(args)) = cli.command {
+		let try_runtime = args.try_runtime_path.map(|runtime_path| TryRuntime {
+			bin: args.try_runtime_bin,
+			runtime_path,
+			snapshot_path: args.snapshot_path,
+			blocktime: args.blocktime,
+		});
+		let passed = verify(&cli.rpc_url, &code, try_runtime.as_ref())
+			.await
+			.map_err(std::io::Error::other)?;
+		return if passed {
+			Ok(())
+		} else {
+			Err(std::io::Error::other("upgrade verification failed"))
+		};
+	}
 	if let Some(timeout) = cli.timeout {
 		log::info!("Sleeping for {timeout} seconds...");
 		std::thread::sleep(Duration::from_secs(timeout));
-		execute_upgrade(&cli.rpc_url, &signer, &code)
+		execute_upgrade(&cli.rpc_url, council, technical_committee, &signer, &code)
 			.await
 			.expect("failed to execute upgrade");
 		Ok(())
@@ -94,6 +164,8 @@ async fn main() -> std::io::Result<()> {
 		let app_data = AppData {
 			rpc_url: cli.rpc_url,
 			signer,
+			council,
+			technical_committee,
 			code,
 			already_executed: Arc::new(Mutex::new(false)),
 			busy: Arc::new(Mutex::new(false)),
@@ -0,0 +1,380 @@
+// This file is part of midnight-node.
+// Copyright (C) 2025 Midnight Foundation
+// SPDX-License-Identifier: Apache-2.0
+// Licensed under the Apache License, Version 2.0 (the "License");
+// You may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+// http://www.apache.org/licenses/LICENSE-2.0
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pre-flight checks of a runtime upgrade, run before any extrinsic is submitted.
+//!
+//! The version and metadata of the new runtime are read by executing its WASM. Migrations are run
+//! with the `try-runtime` CLI against a state snapshot, using a build of the runtime with the
+//! `try-runtime` feature: it runs `Executive::try_runtime_upgrade`, with the runtime's `Migrations`
+//! such as `IncrementSudoSufficients`, then the multi-block migrations of `pallet_migrations`.
+
+use std::{path::PathBuf, process::Command};
+
+use check_report::{Report, Status};
+use parity_scale_codec::{Decode, Encode};
+use sc_executor::{RuntimeVersionOf, WasmExecutor};
+use sp_core::traits::{RuntimeCode, WrappedRuntimeCode};
+use sp_state_machine::BasicExternalities;
+use subxt::{Metadata, OnlineClient, SubstrateConfig};
+
+use crate::error::UpgraderError;
+
+/// Running the `try-runtime` CLI against a state snapshot
+pub struct TryRuntime {
+	/// The `try-runtime` CLI binary
+	pub bin: PathBuf,
+	/// The new runtime WASM, built with the `try-runtime` feature
+	pub runtime_path: PathBuf,
+	/// Snapshot of the chain state, created from the RPC node if it doesn't exist
+	pub snapshot_path: PathBuf,
+	/// Block time in milliseconds, used to run multi-block migrations
+	pub blocktime: u64,
+}
+
+/// Calls and storage entries that differ between two metadata
+#[derive(Default)]
+struct MetadataChanges {
+	added: Vec<String>,
+	changed: Vec<String>,
+	removed: Vec<String>,
+}
+
+/// Checks the upgrade to `code` is safe, prints a report, and returns whether no check failed
+pub async fn verify(
+	rpc_url: &str,
+	code: &[u8],
+	try_runtime: Option<&TryRuntime>,
+) -> Result<bool, UpgraderError> {
+	let mut report = Report::default();
+
+	let api = OnlineClient::<SubstrateConfig>::from_insecure_url(rpc_url).await?;
+	let chain_version = api.runtime_version();
+	let (new_version, new_metadata) = read_runtime(code)?;
+	report.add(
+		Status::Pass,
+		"Runtime",
+		format!(
+			"{} spec version {}, transaction version {}",
+			new_version.spec_name, new_version.spec_version, new_version.transaction_version
+		),
+	);
+
+	if new_version.spec_version > chain_version.spec_version {
+		report.add(
+			Status::Pass,
+			"Spec version",
+			format!("{} -> {}", chain_version.spec_version, new_version.spec_version),
+		);
+	} else {
+		report.add(
+			Status::Fail,
+			"Spec version",
+			format!(
+				"{} does not increase the chain's {}",
+				new_version.spec_version, chain_version.spec_version
+			),
+		);
+	}
+	if new_version.transaction_version != chain_version.transaction_version {
+		report.add(
+			Status::Warn,
+			"Transaction version",
+			format!(
+				"{} -> {}, transactions signed for the current runtime become invalid",
+				chain_version.transaction_version, new_version.transaction_version
+			),
+		);
+	}
+
+	let known_metadata =
+		Metadata::decode(&mut &midnight_node_metadata::MIDNIGHT_METADATA_LATEST[..])?;
+	let changes = metadata_changes(&known_metadata, &new_metadata);
+	for item in &changes.removed {
+		report.add(Status::Warn, "Metadata", format!("{item} removed"));
+	}
+	for item in &changes.changed {
+		report.add(Status::Warn, "Metadata", format!("{item} changed"));
+	}
+	if changes.removed.is_empty() && changes.changed.is_empty() {
+		report.add(
+			Status::Pass,
+			"Metadata",
+			format!(
+				"compatible with midnight-node-metadata, {} calls and storage entries added",
+				changes.added.len()
+			),
+		);
+	} else {
+		report.add(
+			Status::Warn,
+			"Metadata",
+			format!(
+				"{} removed, {} changed and {} added compared to midnight-node-metadata, \
+				 clients built with it may need to be updated",
+				changes.removed.len(),
+				changes.changed.len(),
+				changes.added.len()
+			),
+		);
+	}
+
+	match try_runtime {
+		Some(try_runtime) => {
+			let try_code = std::fs::read(&try_runtime.runtime_path).map_err(|e| {
+				UpgraderError::VerifyError(format!(
+					"failed to read {}: {e}",
+					try_runtime.runtime_path.display()
+				))
+			})?;
+			let (try_version, try_metadata) = read_runtime(&try_code)?;
+			let (status, detail) = compare_builds(
+				(code, &new_version, &new_metadata),
+				(&try_code, &try_version, &try_metadata),
+			);
+			report.add(status, "try-runtime build", detail);
+			if status == Status::Fail {
+				report.add(
+					Status::Skip,
+					"Migrations",
+					"the try-runtime build is not a build of the verified runtime",
+				);
+			} else {
+				match run_try_runtime(rpc_url, try_runtime) {
+					Ok(()) => report.add(
+						Status::Pass,
+						"Migrations",
+						format!("ran against {}", try_runtime.snapshot_path.display()),
+					),
+					Err(e) => report.add(Status::Fail, "Migrations", e),
+				}
+			}
+		},
+		None => report.add(
+			Status::Skip,
+			"Migrations",
+			"no runtime built with the `try-runtime` feature was given",
+		),
+	}
+
+	report.print();
+	Ok(report.count(Status::Fail) == 0)
+}
+
+#[derive(Debug, PartialEq)]
+struct RuntimeVersion {
+	spec_name: String,
+	spec_version: u32,
+	transaction_version: u32,
+}
+
+/// Checks the `try-runtime` build runs the migrations of the verified runtime: it is the same code,
+/// or a build with the same version, calls and storage
+fn compare_builds(
+	(code, version, metadata): (&[u8], &RuntimeVersion, &Metadata),
+	(try_code, try_version, try_metadata): (&[u8], &RuntimeVersion, &Metadata),
+) -> (Status, String) {
+	let hash = hex::encode(sp_crypto_hashing::blake2_256(code));
+	let try_hash = hex::encode(sp_crypto_hashing::blake2_256(try_code));
+	if hash == try_hash {
+		return (Status::Pass, format!("same code as the verified runtime, hash 0x{hash}"));
+	}
+	if try_version != version {
+		return (
+			Status::Fail,
+			format!(
+				"hash 0x{try_hash} is {} spec version {}, transaction version {}, but the verified \
+				 runtime 0x{hash} is {} spec version {}, transaction version {}",
+				try_version.spec_name,
+				try_version.spec_version,
+				try_version.transaction_version,
+				version.spec_name,
+				version.spec_version,
+				version.transaction_version
+			),
+		);
+	}
+	let changes = metadata_changes(metadata, try_metadata);
+	let differences = changes.removed.len() + changes.changed.len() + changes.added.len();
+	if differences > 0 {
+		return (
+			Status::Fail,
+			format!(
+				"hash 0x{try_hash} has {differences} calls and storage entries differing from the \
+				 verified runtime 0x{hash}"
+			),
+		);
+	}
+	(
+		Status::Warn,
+		format!(
+			"hash 0x{try_hash} differs from the verified runtime 0x{hash}, but has the same version, \
+			 calls and storage"
+		),
+	)
+}
+
+/// Reads the version and metadata of a runtime WASM, compressed or not
+fn read_runtime(code: &[u8]) -> Result<(RuntimeVersion, Metadata), UpgraderError> {
+	let executor = WasmExecutor::<sp_io::SubstrateHostFunctions>::builder()
+		// The ledger host functions aren't called when reading the version and metadata
+		.with_allow_missing_host_functions(true)
+		.build();
+
+	let runtime_code = RuntimeCode {
+		code_fetcher: &WrappedRuntimeCode(code.into()),
+		heap_pages: None,
+		hash: sp_crypto_hashing::blake2_256(code).to_vec(),
+	};
+	let version = executor
+		.runtime_version(&mut BasicExternalities::default(), &runtime_code)
+		.map_err(|e| UpgraderError::VerifyError(format!("failed to read version: {e}")))?;
+
+	let metadata =
+		sc_runtime_utilities::fetch_latest_metadata_from_code_blob(&executor, code.into())
+			.map_err(|e| UpgraderError::VerifyError(format!("failed to read metadata: {e}")))?;
+	let metadata = Metadata::decode(&mut &metadata.encode()[..])?;
+
+	let version = RuntimeVersion {
+		spec_name: version.spec_name.to_string(),
+		spec_version: version.spec_version,
+		transaction_version: version.transaction_version,
+	};
+	Ok((version, metadata))
+}
+
+fn metadata_changes(old: &Metadata, new: &Metadata) -> MetadataChanges {
+	let mut changes = MetadataChanges::default();
+	for pallet in old.pallets() {
+		let Some(new_pallet) = new.pallet_by_name(pallet.name()) else {
+			changes.removed.push(format!("pallet {}", pallet.name()));
+			continue;
+		};
+		for call in pallet.call_variants().unwrap_or_default() {
+			let item = format!("call {}::{}", pallet.name(), call.name);
+			match new_pallet.call_hash(&call.name) {
+				None => changes.removed.push(item),
+				Some(hash) if Some(hash) != pallet.call_hash(&call.name) => {
+					changes.changed.push(item)
+				},
+				Some(_) => {},
+			}
+		}
+		for entry in pallet.storage().map(|storage| storage.entries()).unwrap_or_default() {
+			let item = format!("storage {}::{}", pallet.name(), entry.name());
+			match new_pallet.storage_hash(entry.name()) {
+				None => changes.removed.push(item),
+				Some(hash) if Some(hash) != pallet.storage_hash(entry.name()) => {
+					changes.changed.push(item)
+				},
+				Some(_) => {},
+			}
+		}
+	}
+
+	for new_pallet in new.pallets() {
+		let pallet = old.pallet_by_name(new_pallet.name());
+		for call in new_pallet.call_variants().unwrap_or_default() {
+			if pallet.and_then(|pallet| pallet.call_hash(&call.name)).is_none() {
+				changes.added.push(format!("call {}::{}", new_pallet.name(), call.name));
+			}
+		}
+		for entry in new_pallet.storage().map(|storage| storage.entries()).unwrap_or_default() {
+			if pallet.and_then(|pallet| pallet.storage_hash(entry.name())).is_none() {
+				changes.added.push(format!("storage {}::{}", new_pallet.name(), entry.name()));
+			}
+		}
+	}
+	changes
+}
+
+/// Runs the migrations of the `try-runtime` build of the new runtime against a state snapshot
+fn run_try_runtime(rpc_url: &str, try_runtime: &TryRuntime) -> Result<(), String> {
+	if !try_runtime.snapshot_path.exists() {
+		log::info!("Creating state snapshot {}...", try_runtime.snapshot_path.display());
+		let status = Command::new(&try_runtime.bin)
+			.arg("create-snapshot")
+			.args(["--uri", rpc_url])
+			.arg(&try_runtime.snapshot_path)
+			.status()
+			.map_err(|e| format!("failed to run {}: {e}", try_runtime.bin.display()))?;
+		if !status.success() {
+			return Err(format!("creating the state snapshot failed with {status}"));
+		}
+	}
+
+	log::info!("Running migrations against {}...", try_runtime.snapshot_path.display());
+	let status = Command::new(&try_runtime.bin)
+		.arg("--runtime")
+		.arg(&try_runtime.runtime_path)
+		.arg("on-runtime-upgrade")
+		.args(["--checks", "all", "--blocktime", &try_runtime.blocktime.to_string()])
+		.args(["snap", "--path"])
+		.arg(&try_runtime.snapshot_path)
+		.status()
+		.map_err(|e| format!("failed to run {}: {e}", try_runtime.bin.display()))?;
+	if !status.success() {
+		return Err(format!("try-runtime failed with {status}, see its output above"));
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn metadata() -> Metadata {
+		Metadata::decode(&mut &midnight_node_metadata::MIDNIGHT_METADATA_LATEST[..]).unwrap()
+	}
+
+	fn version(spec_version: u32) -> RuntimeVersion {
+		RuntimeVersion { spec_name: "midnight".to_string(), spec_version, transaction_version: 2 }
+	}
+
+	#[test]
+	fn metadata_changes_of_same_metadata_are_empty() {
+		let changes = metadata_changes(&metadata(), &metadata());
+		assert!(changes.added.is_empty());
+		assert!(changes.changed.is_empty());
+		assert!(changes.removed.is_empty());
+	}
+
+	#[test]
+	fn compare_builds_passes_same_code() {
+		let metadata = metadata();
+		let (status, _) = compare_builds(
+			(&b"code"[..], &version(2), &metadata),
+			(&b"code"[..], &version(2), &metadata),
+		);
+		assert_eq!(status, Status::Pass);
+	}
+
+	#[test]
+	fn compare_builds_warns_on_other_build_of_same_runtime() {
+		let metadata = metadata();
+		let (status, detail) = compare_builds(
+			(&b"code"[..], &version(2), &metadata),
+			(&b"try-runtime code"[..], &version(2), &metadata),
+		);
+		assert_eq!(status, Status::Warn, "{detail}");
+	}
+
+	#[test]
+	fn compare_builds_fails_on_other_version() {
+		let metadata = metadata();
+		let (status, detail) = compare_builds(
+			(&b"code"[..], &version(2), &metadata),
+			(&b"try-runtime code"[..], &version(1), &metadata),
+		);
+		assert_eq!(status, Status::Fail, "{detail}");
+	}
+}